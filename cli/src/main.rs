@@ -0,0 +1,524 @@
+#![forbid(unsafe_code)]
+#![deny(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::too_many_lines)]
+
+use sleeping_journal_core::data::event::Event;
+use sleeping_journal_core::data::item::ItemKind;
+use sleeping_journal_core::data::ledger::Resource;
+use sleeping_journal_core::data::prerequisite::Prerequisite;
+use sleeping_journal_core::data::quest::QuestState;
+use sleeping_journal_core::data::vis::Vis;
+use sleeping_journal_core::game::{GameLanguage, LocationId, PackId, QuestId, QuestLocale};
+use sleeping_journal_core::journal::{Choice, Conflict, Journal, NoteId};
+use sleeping_journal_core::ser::csv::{MyError, Reason, Rejected};
+use sleeping_journal_core::ser::game_data_8::SerdeGameData8;
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::path::Path;
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: sleeping-journal-cli [--packs <key>,...] <command>
+
+commands:
+  convert <input> <output> [--language <language>]
+  validate <file>
+  stats <file>
+  language <input> <output> <language>
+  merge <first> <second> <output> [--notes first|second|both]
+
+Files ending in .csv are exports, all others are the JSON of the local storage.
+All packs are enabled unless --packs is given.";
+
+// A tool for campaign files outside of the browser, e.g. to keep them in git:
+// - `convert`: between the CSV export and the JSON of the local storage (any version)
+// - `validate`: prints every row (CSV) or id (JSON) which is skipped when loading the file
+// - `stats`: counts the quests, encounters, events, crew, totems and items, sums the ledger and the score
+// - `language`: writes an export in another game language
+// - `merge`: combines two files of the same campaign, see `Journal::merge`
+fn main() -> ExitCode {
+    match run(std::env::args().skip(1).collect()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(mut args: Vec<String>) -> Result<(), Box<dyn Error>> {
+    let packs = match take_option(&mut args, "--packs")? {
+        None => PackId::all().collect(),
+        Some(keys) => keys
+            .split(',')
+            .map(|key| PackId::try_from_key(key).ok_or_else(|| format!("unknown pack {key:?}")))
+            .collect::<Result<Vec<_>, _>>()?,
+    };
+    let language = take_option(&mut args, "--language")?
+        .map(|language| parse_language(&language))
+        .transpose()?;
+    let notes = take_option(&mut args, "--notes")?;
+
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+    match (args.as_slice(), notes.as_deref()) {
+        (["convert", input, output], None) => {
+            let mut campaign = Campaign::open(input, &packs)?;
+            if let Some(language) = language {
+                campaign.quest_locale.set_language(language);
+            }
+            campaign.save(output)
+        }
+        (["validate", file], None) if language.is_none() => validate(file, &packs),
+        (["stats", file], None) if language.is_none() => {
+            Campaign::open(file, &packs)?.stats();
+            Ok(())
+        }
+        (["language", input, output, language_name], None) if language.is_none() => {
+            if !is_csv(output) {
+                return Err("the game language is only stored in CSV files".into());
+            }
+            let mut campaign = Campaign::open(input, &packs)?;
+            campaign
+                .quest_locale
+                .set_language(parse_language(language_name)?);
+            campaign.save(output)
+        }
+        (["merge", first, second, output], notes) => {
+            let notes = match notes {
+                None | Some("both") => Choice::Both,
+                Some("first") => Choice::Own,
+                Some("second") => Choice::Other,
+                Some(notes) => return Err(format!("unknown option --notes {notes}").into()),
+            };
+            let mut campaign = Campaign::open(first, &packs)?;
+            if let Some(language) = language {
+                campaign.quest_locale.set_language(language);
+            }
+            campaign.merge(&Campaign::open(second, &packs)?, notes);
+            campaign.save(output)
+        }
+        _ => Err(USAGE.into()),
+    }
+}
+
+struct Campaign {
+    journal: Journal,
+    // the language of the file, English for JSON
+    quest_locale: QuestLocale,
+    rejected: Vec<Rejected>,
+}
+
+impl Campaign {
+    fn load(path: &str, packs: &[PackId]) -> Result<Self, Box<dyn Error>> {
+        let file = std::fs::read(path).map_err(|err| format!("{path}: {err}"))?;
+        let mut journal = Journal::default();
+        let mut quest_locale = QuestLocale::new();
+        quest_locale.set_packs(packs);
+        let rejected = if is_csv(path) {
+            journal
+                .load_csv(&file, packs, &mut quest_locale)
+                .map_err(|err| format!("{path}: {}", csv_error(&err)))?
+        } else {
+            std::str::from_utf8(&file)
+                .ok()
+                .and_then(|json| journal.load_any_game_data(json, packs).ok())
+                .ok_or_else(|| format!("{path}: not the game data of a campaign"))?;
+            Vec::new()
+        };
+        Ok(Self {
+            journal,
+            quest_locale,
+            rejected,
+        })
+    }
+
+    // loads the file, skipped rows are only warned about
+    fn open(path: &str, packs: &[PackId]) -> Result<Self, Box<dyn Error>> {
+        let result = Self::load(path, packs)?;
+        for rejected in &result.rejected {
+            eprintln!("{path}: skipped {}", describe(rejected));
+        }
+        Ok(result)
+    }
+
+    fn save(&self, path: &str) -> Result<(), Box<dyn Error>> {
+        let file = if is_csv(path) {
+            self.journal.save_csv(&self.quest_locale)
+        } else {
+            // pretty printed, thus changes are readable in a diff
+            let mut file = serde_json::to_vec_pretty(&self.journal.save_game_data())?;
+            file.push(b'\n');
+            file
+        };
+        std::fs::write(path, file).map_err(|err| format!("{path}: {err}").into())
+    }
+
+    // only the notes can be chosen, otherwise the value of the first file is kept
+    fn merge(&mut self, other: &Campaign, notes: Choice) {
+        let language = self.quest_locale.language();
+        for conflict in self.journal.merge(&other.journal) {
+            let (name, what, choice) = match &conflict {
+                Conflict::Note(NoteId::Quest(quest_id), _) => {
+                    (self.quest_locale.get(*quest_id).to_string(), "notes", notes)
+                }
+                Conflict::Note(NoteId::Location(location_id), _) => {
+                    (location_id.name(language).to_string(), "notes", notes)
+                }
+                Conflict::QuestState(quest_id, _) => (
+                    self.quest_locale.get(*quest_id).to_string(),
+                    "states",
+                    conflict.default_choice(),
+                ),
+                Conflict::Prerequisite(quest_id, location_id, encounter_type, _) => (
+                    format!(
+                        "{} at {} ({})",
+                        self.quest_locale.get(*quest_id),
+                        location_id.name(language),
+                        encounter_type.to_csv()
+                    ),
+                    "prerequisites",
+                    conflict.default_choice(),
+                ),
+                Conflict::Totem(totem) => (
+                    format!("totem {}", totem.name),
+                    "points, locations or sessions",
+                    conflict.default_choice(),
+                ),
+                Conflict::Item(item) => (
+                    format!("{} {}", item.kind.to_csv(), item.name),
+                    "holders or origins",
+                    conflict.default_choice(),
+                ),
+            };
+            eprintln!(
+                "{name}: the {what} differ, kept {}",
+                match choice {
+                    Choice::Own => "the first one",
+                    Choice::Other => "the second one",
+                    Choice::Both => "both",
+                }
+            );
+            self.journal.resolve(conflict, choice);
+        }
+    }
+
+    fn stats(&self) {
+        let journal = &self.journal;
+        println!("language: {}", self.quest_locale.language().as_str());
+
+        for state in [
+            QuestState::InGame,
+            QuestState::Completed,
+            QuestState::Lost,
+            QuestState::Removed,
+            QuestState::NotFound,
+        ] {
+            let count = journal
+                .quest
+                .values()
+                .filter(|quest| quest.state == state)
+                .count();
+            if count > 0 {
+                let name = match state {
+                    QuestState::NotFound => "not found",
+                    state => state.to_csv(),
+                };
+                println!("quests {name}: {count}");
+            }
+        }
+
+        let (mut encounters, mut hidden, mut ready) = (0, 0, 0);
+        for quest in journal.quest.values() {
+            for quest_location in quest.encounter.values() {
+                encounters += quest_location.len();
+                hidden += quest_location
+                    .values()
+                    .filter(|encounter| encounter.vis != Vis::Visible || quest.vis != Vis::Visible)
+                    .count();
+                ready += quest_location
+                    .get_active(quest, journal, false)
+                    .values()
+                    .filter(|ready| **ready)
+                    .count();
+            }
+        }
+        println!("encounters: {encounters} ({ready} ready, {hidden} hidden)");
+
+        let notes = journal
+            .location
+            .values()
+            .filter(|note| !note.is_empty())
+            .count()
+            + journal
+                .quest
+                .values()
+                .filter(|quest| !quest.note.is_empty())
+                .count();
+        println!("notes: {notes}");
+
+        if !journal.crew.is_empty() {
+            let injured = journal
+                .crew
+                .iter()
+                .filter(|crew_member| !crew_member.injuries.is_empty())
+                .count();
+            println!("crew: {} ({injured} injured)", journal.crew.len());
+        }
+
+        if !journal.ledger.entry.is_empty() {
+            let totals = Resource::iter()
+                .map(|resource| format!("{} {}", resource.to_csv(), journal.ledger.total(resource)))
+                .collect::<Vec<_>>();
+            println!("ledger: {}", totals.join(", "));
+        }
+        println!("totems: {}", journal.totem.len());
+        if !journal.item.is_empty() {
+            let kinds = ItemKind::iter()
+                .map(|kind| {
+                    let count = journal.item.iter().filter(|item| item.kind == kind).count();
+                    format!("{} {count}", kind.to_csv())
+                })
+                .collect::<Vec<_>>();
+            println!("items: {}", kinds.join(", "));
+        }
+        // the parts without points are left out
+        let score = journal.score();
+        let parts = score
+            .iter()
+            .filter(|line| line.rate.points != 0)
+            .map(|line| format!("{} {}", line.part.to_csv(), line.points))
+            .collect::<Vec<_>>();
+        let total = score
+            .iter()
+            .map(|line| line.points)
+            .fold(0, i32::saturating_add);
+        println!("score: {total} ({})", parts.join(", "));
+
+        let sessions = journal
+            .event
+            .iter()
+            .map(|event| event.session)
+            .collect::<BTreeSet<_>>();
+        println!(
+            "events: {} in {} sessions",
+            journal.event.len(),
+            sessions.len()
+        );
+        let dates = journal
+            .event
+            .iter()
+            .filter_map(Event::local_time)
+            .map(|time| time.format("%Y-%m-%d").to_string())
+            .collect::<BTreeSet<_>>();
+        if let (Some(first), Some(last)) = (dates.first(), dates.last()) {
+            println!("played: {first} to {last} ({} days)", dates.len());
+        }
+    }
+}
+
+fn validate(path: &str, packs: &[PackId]) -> Result<(), Box<dyn Error>> {
+    let mut problems = Vec::new();
+    if is_csv(path) {
+        for rejected in Campaign::load(path, packs)?.rejected {
+            problems.push(describe(&rejected));
+        }
+    } else {
+        let file = std::fs::read(path).map_err(|err| format!("{path}: {err}"))?;
+        if let Ok(game_data) = serde_json::from_slice::<SerdeGameData8>(&file) {
+            validate_game_data(&game_data, &mut problems);
+        } else if std::str::from_utf8(&file)
+            .is_ok_and(|json| Journal::default().load_any_game_data(json, packs).is_ok())
+        {
+            println!("{path}: an older version, convert it to check the ids");
+        } else {
+            return Err(format!("{path}: not the game data of a campaign").into());
+        }
+    }
+
+    for problem in &problems {
+        println!("{path}: {problem}");
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "{path}: {} problems, these are skipped when loading",
+            problems.len()
+        )
+        .into())
+    }
+}
+
+// the ids unknown to this version of the content
+fn validate_game_data(game_data: &SerdeGameData8, problems: &mut Vec<String>) {
+    for raw_location_id in game_data.locations.keys() {
+        if LocationId::from_raw(*raw_location_id).is_none() {
+            problems.push(format!("location {raw_location_id}: unknown id"));
+        }
+    }
+    for (raw_quest_id, quest) in &game_data.quests {
+        if QuestId::from_raw(*raw_quest_id).is_none() {
+            problems.push(format!("quest {raw_quest_id}: unknown id"));
+        }
+        for (raw_location_id, encounters) in &quest.1 {
+            if LocationId::from_raw(*raw_location_id).is_none() {
+                problems.push(format!(
+                    "quest {raw_quest_id}, location {raw_location_id}: unknown location id"
+                ));
+            }
+            for (raw_prerequisite, _) in encounters.0.values() {
+                if let Some(raw_prerequisite) = raw_prerequisite {
+                    if Prerequisite::try_from_storage(raw_prerequisite).is_none() {
+                        problems.push(format!(
+                            "quest {raw_quest_id}, location {raw_location_id}: invalid prerequisite {raw_prerequisite:?}"
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    for (pos, event) in game_data.events.iter().enumerate() {
+        if event.3.is_some_and(|raw| QuestId::from_raw(raw).is_none()) {
+            problems.push(format!("event {}: unknown quest id", pos + 1));
+        }
+        // the prologue is not a location which can be referenced
+        if event.4.is_some_and(|raw| {
+            raw != LocationId::prologue().raw() && LocationId::from_raw(raw).is_none()
+        }) {
+            problems.push(format!("event {}: unknown location id", pos + 1));
+        }
+    }
+    for (pos, entry) in game_data.ledger.iter().enumerate() {
+        if entry
+            .4
+            .is_some_and(|raw| LocationId::from_raw(raw).is_none())
+        {
+            problems.push(format!("ledger entry {}: unknown location id", pos + 1));
+        }
+    }
+    if game_data
+        .ship_location
+        .is_some_and(|raw| LocationId::from_raw(raw).is_none())
+    {
+        problems.push("ship: unknown location id".to_string());
+    }
+    for totem in &game_data.totems {
+        if totem
+            .2
+            .is_some_and(|raw| LocationId::from_raw(raw).is_none())
+        {
+            problems.push(format!("totem {:?}: unknown location id", totem.0));
+        }
+    }
+    for item in &game_data.items {
+        if item
+            .4
+            .is_some_and(|raw| LocationId::from_raw(raw).is_none())
+        {
+            problems.push(format!("item {:?}: unknown location id", item.2));
+        }
+        if item.5.is_some_and(|raw| QuestId::from_raw(raw).is_none()) {
+            problems.push(format!("item {:?}: unknown quest id", item.2));
+        }
+    }
+    for (pos, session) in game_data.sessions.iter().enumerate() {
+        let quests = session
+            .2
+            .iter()
+            .chain(&session.3)
+            .chain(&session.4)
+            .chain(session.5.iter().map(|encounter| &encounter.0))
+            .chain(&session.6);
+        if quests.copied().any(|raw| QuestId::from_raw(raw).is_none()) {
+            problems.push(format!("session {}: unknown quest id", pos + 1));
+        }
+        let locations = session
+            .5
+            .iter()
+            .map(|encounter| &encounter.1)
+            .chain(&session.7)
+            .chain(&session.8);
+        if locations
+            .copied()
+            .any(|raw| LocationId::from_raw(raw).is_none())
+        {
+            problems.push(format!("session {}: unknown location id", pos + 1));
+        }
+    }
+}
+
+fn is_csv(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("csv"))
+}
+
+// the name (e.g. "Deutsch") or the code (e.g. "de")
+fn parse_language(input: &str) -> Result<GameLanguage, Box<dyn Error>> {
+    GameLanguage::iter()
+        .find(|language| {
+            language.as_str().eq_ignore_ascii_case(input)
+                || serde_json::to_value(language)
+                    .ok()
+                    .is_some_and(|code| code == input)
+        })
+        .ok_or_else(|| format!("unknown game language {input:?}").into())
+}
+
+// removes `--name <value>` from the arguments
+fn take_option(args: &mut Vec<String>, name: &str) -> Result<Option<String>, Box<dyn Error>> {
+    let Some(pos) = args.iter().position(|arg| arg == name) else {
+        return Ok(None);
+    };
+    if pos + 1 >= args.len() {
+        return Err(format!("missing value of {name}").into());
+    }
+    args.remove(pos);
+    Ok(Some(args.remove(pos)))
+}
+
+fn csv_error(err: &MyError) -> &'static str {
+    match err {
+        MyError::CsvError => "not a valid CSV file",
+        MyError::Header => "not an export of the journal, the header is wrong",
+        MyError::Language => "unknown game language in the second row",
+    }
+}
+
+// e.g. `row 5, quest "RAD": unknown quest, did you mean "RAID"?`
+fn describe(rejected: &Rejected) -> String {
+    let suggestion = rejected
+        .suggestion
+        .as_ref()
+        .map(|suggestion| format!(", did you mean {suggestion:?}?"))
+        .unwrap_or_default();
+    format!(
+        "row {}, {} {:?}: {}{suggestion}",
+        rejected.row,
+        rejected.reason.column(),
+        rejected.value,
+        reason(rejected.reason)
+    )
+}
+
+fn reason(reason: Reason) -> &'static str {
+    match reason {
+        Reason::Type => "unknown type",
+        Reason::Location => "unknown location",
+        Reason::Quest => "unknown quest",
+        Reason::Status => "invalid status",
+        Reason::Prerequisite => "invalid prerequisite",
+        Reason::PrerequisiteQuest => "prerequisite on an unknown quest",
+        Reason::Visibility => "invalid visibility",
+        Reason::Time => "invalid time",
+        Reason::Session => "invalid session",
+        Reason::Health => "invalid health or above the max health",
+        Reason::MaxHealth => "invalid max health",
+        Reason::Fatigue => "invalid fatigue",
+        Reason::Level => "invalid level",
+        Reason::Amount => "invalid amount",
+        Reason::Card => "invalid card number",
+        Reason::MissingLocation => "the location is missing",
+        Reason::MissingQuest => "the quest is missing",
+    }
+}
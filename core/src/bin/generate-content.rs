@@ -0,0 +1,389 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+// the generated file is formatted like rustfmt would do it, lines stay below the max width
+const MAX_WIDTH: usize = 100;
+// characters with a meaning in prerequisite expressions
+const RESERVED: [char; 5] = ['&', '|', '(', ')', '!'];
+// the prefixes of a quest in prerequisite expressions
+const PREFIXES: [char; 3] = ['+', '-', '!'];
+
+struct Location {
+    id: u16,
+    name: Option<String>,
+    page: Option<String>,
+}
+
+struct Quest {
+    id: u16,
+    // one name per game language, in the order of `GameLanguage`
+    names: Vec<String>,
+    keyword: bool,
+}
+
+// retired ids and the ones replacing them
+#[derive(Default)]
+struct Remap {
+    locations: Vec<(u16, u16)>,
+    quests: Vec<(u16, u16)>,
+}
+
+// Generates `src/game/generated.rs` from the files in `content/`:
+// - `locations.csv`: `id,name,page`, the prologue is the only row without a name
+// - `quests.csv`: `id`, one column per game language (in the order of `GameLanguage`) and `keyword`
+// - `remap.csv`: `kind,from,to`, kind is `location` or `quest`
+//
+// The ids are stored in the saves, thus they must never change or be reused.
+// The order of the rows doesn't matter.
+//
+// With `--check` the file is only compared, for use in CI.
+fn main() -> Result<(), Box<dyn Error>> {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let check = match std::env::args().nth(1).as_deref() {
+        None => false,
+        Some("--check") => true,
+        Some(arg) => return Err(format!("unknown argument {arg:?}").into()),
+    };
+
+    let pages = read_pages(&root.join("src/game/map.rs"))?;
+    let locations = read_locations(&root.join("content/locations.csv"), &pages)?;
+    let quests = read_quests(&root.join("content/quests.csv"))?;
+    let remap = read_remap(&root.join("content/remap.csv"), &locations, &quests)?;
+    let generated = generate(&locations, &quests, &remap);
+
+    let path = root.join("src/game/generated.rs");
+    if check {
+        if std::fs::read_to_string(&path)? != generated {
+            return Err(format!("{} is not up to date", path.display()).into());
+        }
+    } else {
+        std::fs::write(&path, generated)?;
+    }
+
+    Ok(())
+}
+
+// all pages of the atlas, they are hand-written in `MAP`
+fn read_pages(path: &Path) -> Result<HashSet<String>, Box<dyn Error>> {
+    let source = std::fs::read_to_string(path)?;
+    let map = source
+        .split_once("pub(crate) const MAP:")
+        .ok_or_else(|| format!("{}: MAP not found", path.display()))?
+        .1;
+    // every second part is a string literal
+    Ok(map
+        .split('"')
+        .skip(1)
+        .step_by(2)
+        .filter(|page| !page.is_empty())
+        .map(ToString::to_string)
+        .collect())
+}
+
+fn read_locations(path: &Path, pages: &HashSet<String>) -> Result<Vec<Location>, Box<dyn Error>> {
+    let mut reader = csv::Reader::from_path(path)?;
+    if reader.headers()? != vec!["id", "name", "page"] {
+        return Err(format!("{}: the header must be id,name,page", path.display()).into());
+    }
+
+    let mut locations = Vec::new();
+    let mut ids = HashSet::new();
+    let mut names = HashSet::new();
+    for (row, record) in reader.records().enumerate() {
+        let record = record?;
+        let line = row + 2;
+        let location = Location {
+            id: read_id(path, line, &record[0], &mut ids)?,
+            name: Some(record[1].to_string()).filter(|name| !name.is_empty()),
+            page: Some(record[2].to_string()).filter(|page| !page.is_empty()),
+        };
+        match (row, &location.name, &location.page) {
+            (0, None, None) => (),
+            (0, _, _) => {
+                return Err(format!(
+                    "{}:{line}: the first row must be the (empty) prologue",
+                    path.display()
+                )
+                .into());
+            }
+            (_, Some(name), Some(page)) => {
+                if !names.insert(name.clone()) {
+                    return Err(
+                        format!("{}:{line}: duplicate location {name:?}", path.display()).into(),
+                    );
+                }
+                if !pages.contains(page) {
+                    return Err(format!(
+                        "{}:{line}: page {page:?} is not in the atlas",
+                        path.display()
+                    )
+                    .into());
+                }
+            }
+            (_, _, _) => {
+                return Err(
+                    format!("{}:{line}: name and page are required", path.display()).into(),
+                );
+            }
+        }
+        locations.push(location);
+    }
+    if locations.is_empty() {
+        return Err(format!("{}: no locations", path.display()).into());
+    }
+
+    Ok(locations)
+}
+
+fn read_quests(path: &Path) -> Result<Vec<Quest>, Box<dyn Error>> {
+    let mut reader = csv::Reader::from_path(path)?;
+    let headers = reader.headers()?.clone();
+    let languages = headers.len().saturating_sub(2);
+    if languages == 0 || &headers[0] != "id" || &headers[languages + 1] != "keyword" {
+        return Err(format!(
+            "{}: the header must be id, the languages and keyword",
+            path.display()
+        )
+        .into());
+    }
+
+    let mut quests = Vec::new();
+    let mut ids = HashSet::new();
+    let mut names = vec![HashSet::new(); languages];
+    for (row, record) in reader.records().enumerate() {
+        let record = record?;
+        let line = row + 2;
+        let quest = Quest {
+            id: read_id(path, line, &record[0], &mut ids)?,
+            names: record
+                .iter()
+                .skip(1)
+                .take(languages)
+                .map(ToString::to_string)
+                .collect(),
+            keyword: match &record[languages + 1] {
+                "" => false,
+                "yes" => true,
+                keyword => {
+                    return Err(format!(
+                        "{}:{line}: keyword must be empty or yes, not {keyword:?}",
+                        path.display()
+                    )
+                    .into());
+                }
+            },
+        };
+        for (language, name) in quest.names.iter().enumerate() {
+            if name.is_empty() || name.trim() != name {
+                return Err(format!(
+                    "{}:{line}: invalid {} name {name:?}",
+                    path.display(),
+                    &headers[language + 1]
+                )
+                .into());
+            }
+            if name.contains(RESERVED) {
+                return Err(format!(
+                    "{}:{line}: the {} name {name:?} must not contain any of {RESERVED:?}",
+                    path.display(),
+                    &headers[language + 1]
+                )
+                .into());
+            }
+            if name.starts_with(PREFIXES) {
+                return Err(format!(
+                    "{}:{line}: the {} name {name:?} must not start with any of {PREFIXES:?}",
+                    path.display(),
+                    &headers[language + 1]
+                )
+                .into());
+            }
+            if !names[language].insert(name.clone()) {
+                return Err(format!(
+                    "{}:{line}: duplicate {} quest {name:?}",
+                    path.display(),
+                    &headers[language + 1]
+                )
+                .into());
+            }
+        }
+        quests.push(quest);
+    }
+    if quests.is_empty() {
+        return Err(format!("{}: no quests", path.display()).into());
+    }
+
+    Ok(quests)
+}
+
+fn read_id(
+    path: &Path,
+    line: usize,
+    id: &str,
+    ids: &mut HashSet<u16>,
+) -> Result<u16, Box<dyn Error>> {
+    let id = id
+        .parse()
+        .map_err(|_| format!("{}:{line}: invalid id {id:?}", path.display()))?;
+    if !ids.insert(id) {
+        return Err(format!("{}:{line}: duplicate id {id}", path.display()).into());
+    }
+    Ok(id)
+}
+
+fn read_remap(
+    path: &Path,
+    locations: &[Location],
+    quests: &[Quest],
+) -> Result<Remap, Box<dyn Error>> {
+    let mut reader = csv::Reader::from_path(path)?;
+    if reader.headers()? != vec!["kind", "from", "to"] {
+        return Err(format!("{}: the header must be kind,from,to", path.display()).into());
+    }
+
+    let location_ids = locations.iter().map(|l| l.id).collect::<HashSet<_>>();
+    let quest_ids = quests.iter().map(|q| q.id).collect::<HashSet<_>>();
+    let mut remap = Remap::default();
+    let mut retired_locations = HashSet::new();
+    let mut retired_quests = HashSet::new();
+    for (row, record) in reader.records().enumerate() {
+        let record = record?;
+        let line = row + 2;
+        let (ids, retired, list) = match &record[0] {
+            "location" => (&location_ids, &mut retired_locations, &mut remap.locations),
+            "quest" => (&quest_ids, &mut retired_quests, &mut remap.quests),
+            kind => {
+                return Err(format!(
+                    "{}:{line}: kind must be location or quest, not {kind:?}",
+                    path.display()
+                )
+                .into());
+            }
+        };
+        let from = read_id(path, line, &record[1], retired)?;
+        let to = record[2]
+            .parse()
+            .map_err(|_| format!("{}:{line}: invalid id {:?}", path.display(), &record[2]))?;
+        if ids.contains(&from) {
+            return Err(format!("{}:{line}: the id {from} is still in use", path.display()).into());
+        }
+        if !ids.contains(&to) {
+            return Err(format!("{}:{line}: the id {to} doesn't exist", path.display()).into());
+        }
+        list.push((from, to));
+    }
+
+    Ok(remap)
+}
+
+fn generate(locations: &[Location], quests: &[Quest], remap: &Remap) -> String {
+    let mut out = String::new();
+    out.push_str("/*\n    This file is automatically generated.\n    Do not edit!\n    Also: Spoilers.\n*/\n\n");
+
+    let _ = writeln!(
+        out,
+        "pub(crate) const LOCATIONS_ROWS: usize = {};",
+        locations.len()
+    );
+    out.push_str("pub(crate) const LOCATIONS_COLUMNS: usize = 2;\n");
+    out.push_str(
+        "pub(crate) const LOCATIONS: [[Option<&str>; LOCATIONS_COLUMNS]; LOCATIONS_ROWS] = [\n",
+    );
+    for location in locations {
+        let _ = writeln!(
+            out,
+            "    [{}, {}],",
+            option(location.name.as_deref()),
+            option(location.page.as_deref())
+        );
+    }
+    out.push_str("];\n");
+    out.push_str("pub(crate) const LOCATIONS_IDS: [u16; LOCATIONS_ROWS] = [\n");
+    wrapped(&mut out, locations.iter().map(|l| l.id.to_string()));
+    out.push_str("];\n");
+
+    let languages = quests[0].names.len();
+    let _ = writeln!(out, "pub(crate) const QUESTS_ROWS: usize = {languages};");
+    let _ = writeln!(
+        out,
+        "pub(crate) const QUESTS_COLUMNS: usize = {};",
+        quests.len()
+    );
+    out.push_str("pub(crate) const QUESTS: [[&str; QUESTS_COLUMNS]; QUESTS_ROWS] = [\n");
+    for language in 0..languages {
+        out.push_str("    [\n");
+        for quest in quests {
+            let _ = writeln!(out, "        {:?},", quest.names[language]);
+        }
+        out.push_str("    ],\n");
+    }
+    out.push_str("];\n");
+
+    out.push_str("pub(crate) const QUESTS_KEYWORDS: [bool; QUESTS_COLUMNS] = [\n");
+    wrapped(&mut out, quests.iter().map(|q| q.keyword.to_string()));
+    out.push_str("];\n");
+    out.push_str("pub(crate) const QUESTS_IDS: [u16; QUESTS_COLUMNS] = [\n");
+    wrapped(&mut out, quests.iter().map(|q| q.id.to_string()));
+    out.push_str("];\n");
+
+    pairs(&mut out, "LOCATIONS_REMAP", &remap.locations);
+    pairs(&mut out, "QUESTS_REMAP", &remap.quests);
+
+    out
+}
+
+// many short items per line
+fn wrapped<I: Iterator<Item = String>>(out: &mut String, items: I) {
+    let mut line = String::new();
+    for item in items {
+        if !line.is_empty() && line.len() + 2 + item.len() >= MAX_WIDTH {
+            out.push_str(&line);
+            out.push('\n');
+            line.clear();
+        }
+        if line.is_empty() {
+            line.push_str("   ");
+        }
+        line.push(' ');
+        line.push_str(&item);
+        line.push(',');
+    }
+    out.push_str(&line);
+    out.push('\n');
+}
+
+// a single line if possible, else one pair per line
+fn pairs(out: &mut String, name: &str, pairs: &[(u16, u16)]) {
+    let items = pairs
+        .iter()
+        .map(|(from, to)| format!("({from}, {to})"))
+        .collect::<Vec<_>>();
+    let single = format!(
+        "pub(crate) const {name}: [(u16, u16); {}] = [{}];",
+        items.len(),
+        items.join(", ")
+    );
+    if single.len() <= MAX_WIDTH {
+        out.push_str(&single);
+        out.push('\n');
+    } else {
+        let _ = writeln!(
+            out,
+            "pub(crate) const {name}: [(u16, u16); {}] = [",
+            items.len()
+        );
+        for item in items {
+            let _ = writeln!(out, "    {item},");
+        }
+        out.push_str("];\n");
+    }
+}
+
+fn option(value: Option<&str>) -> String {
+    match value {
+        None => "None".to_string(),
+        Some(value) => format!("Some({value:?})"),
+    }
+}
@@ -0,0 +1,36 @@
+// a member of the crew with the values of the save sheet
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Eq, PartialEq)]
+pub struct CrewMember {
+    pub name: String,
+    pub health: u8,
+    pub max_health: u8,
+    pub fatigue: u8,
+    pub level: u8,
+    pub injuries: String,
+}
+
+impl CrewMember {
+    pub const DEFAULT_MAX_HEALTH: u8 = 6;
+    pub const START_LEVEL: u8 = 1;
+
+    #[must_use]
+    pub fn new(name: String, max_health: u8) -> Self {
+        Self {
+            name,
+            health: max_health,
+            max_health,
+            fatigue: 0,
+            level: Self::START_LEVEL,
+            injuries: String::new(),
+        }
+    }
+
+    // the values at the start of a campaign: full health, rested, the first level and no injuries
+    pub fn reset(&mut self) {
+        self.health = self.max_health;
+        self.fatigue = 0;
+        self.level = Self::START_LEVEL;
+        self.injuries.clear();
+    }
+}
@@ -0,0 +1,38 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy, Hash, Ord, PartialOrd, Eq, PartialEq, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum EncounterType {
+    Unless = 0,
+    Gain = 1,
+    When = 2,
+    Complete = 3,
+    Lose = 4,
+}
+
+impl EncounterType {
+    pub fn raw(self) -> u8 {
+        self as u8
+    }
+
+    pub fn to_csv(self) -> &'static str {
+        match self {
+            EncounterType::Unless => "unless",
+            EncounterType::Gain => "gain",
+            EncounterType::When => "when",
+            EncounterType::Complete => "complete",
+            EncounterType::Lose => "lose",
+        }
+    }
+    pub fn try_from_csv(input: &str) -> Option<Self> {
+        match input {
+            "unless" => Some(EncounterType::Unless),
+            "gain" => Some(EncounterType::Gain),
+            "when" => Some(EncounterType::When),
+            "complete" => Some(EncounterType::Complete),
+            "lose" => Some(EncounterType::Lose),
+            _ => None,
+        }
+    }
+}
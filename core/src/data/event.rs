@@ -0,0 +1,118 @@
+use crate::data::encounter_type::EncounterType;
+use crate::data::vis::Vis;
+use crate::game::{LocationId, QuestId};
+use crate::journal::Journal;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub struct Event {
+    pub time: i64,
+    pub session: u32,
+    pub action: EventAction,
+    pub quest_id: Option<QuestId>,
+    pub location_id: Option<LocationId>,
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum EventAction {
+    Perform(EncounterType),
+    Record(EncounterType),
+    EncounterVis(EncounterType, Vis),
+    QuestVis(Vis),
+    QuestNote,
+    Edit,
+    NewCampaign,
+}
+
+impl Event {
+    // a new session is started when nothing happened for this many seconds
+    const SESSION_GAP: i64 = 4 * 60 * 60;
+
+    #[must_use]
+    pub fn local_time(&self) -> Option<DateTime<Local>> {
+        DateTime::from_timestamp(self.time, 0).map(|time| time.with_timezone(&Local))
+    }
+}
+
+impl EventAction {
+    pub fn to_csv(self) -> String {
+        match self {
+            EventAction::Perform(encounter_type) => {
+                format!("perform:{}", encounter_type.to_csv())
+            }
+            EventAction::Record(encounter_type) => format!("record:{}", encounter_type.to_csv()),
+            EventAction::EncounterVis(encounter_type, _) => {
+                format!("visibility:{}", encounter_type.to_csv())
+            }
+            EventAction::QuestVis(_) => "quest-visibility".to_string(),
+            EventAction::QuestNote => "quest-note".to_string(),
+            EventAction::Edit => "edit".to_string(),
+            EventAction::NewCampaign => "new-campaign".to_string(),
+        }
+    }
+
+    pub fn try_from_csv(action: &str, vis: Vis) -> Option<EventAction> {
+        match action.split_once(':') {
+            Some(("perform", encounter_type)) => {
+                EncounterType::try_from_csv(encounter_type).map(EventAction::Perform)
+            }
+            Some(("record", encounter_type)) => {
+                EncounterType::try_from_csv(encounter_type).map(EventAction::Record)
+            }
+            Some(("visibility", encounter_type)) => EncounterType::try_from_csv(encounter_type)
+                .map(|encounter_type| EventAction::EncounterVis(encounter_type, vis)),
+            Some(_) => None,
+            None => match action {
+                "quest-visibility" => Some(EventAction::QuestVis(vis)),
+                "quest-note" => Some(EventAction::QuestNote),
+                "edit" => Some(EventAction::Edit),
+                "new-campaign" => Some(EventAction::NewCampaign),
+                _ => None,
+            },
+        }
+    }
+
+    pub fn vis(self) -> Vis {
+        match self {
+            EventAction::EncounterVis(_, vis) | EventAction::QuestVis(vis) => vis,
+            EventAction::Perform(_)
+            | EventAction::Record(_)
+            | EventAction::QuestNote
+            | EventAction::Edit
+            | EventAction::NewCampaign => Vis::Visible,
+        }
+    }
+}
+
+impl Journal {
+    pub fn record_event(
+        &mut self,
+        action: EventAction,
+        quest_id: Option<QuestId>,
+        location_id: Option<LocationId>,
+    ) {
+        let time = Local::now().timestamp();
+        self.event.push(Event {
+            time,
+            session: self.session_at(time),
+            action,
+            quest_id,
+            location_id,
+        });
+    }
+
+    // the session of something happening at `time`, a started session is never split
+    pub fn session_at(&self, time: i64) -> u32 {
+        let started = self.session_start.as_ref().map(|start| start.time);
+        match self.event.last() {
+            None => 1,
+            Some(last) if started.is_some_and(|started| last.time < started) => last.session + 1,
+            Some(last) if started.is_some() => last.session,
+            Some(last) if time - last.time < Event::SESSION_GAP => last.session,
+            Some(last) => last.session + 1,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::game::{LocationId, QuestId};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum ItemKind {
+    AdventureCard = 0,
+    Artifact = 1,
+}
+
+impl ItemKind {
+    pub fn iter() -> impl Iterator<Item = Self> {
+        [ItemKind::AdventureCard, ItemKind::Artifact].into_iter()
+    }
+
+    pub fn to_csv(self) -> &'static str {
+        match self {
+            ItemKind::AdventureCard => "adventure-card",
+            ItemKind::Artifact => "artifact",
+        }
+    }
+
+    pub fn try_from_csv(input: &str) -> Option<Self> {
+        Self::iter().find(|kind| kind.to_csv() == input)
+    }
+}
+
+// an adventure card or artifact of the inventory, it came from a location or quest
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Eq, PartialEq)]
+pub struct Item {
+    pub kind: ItemKind,
+    // as printed on the card, unknown if it was not written down
+    pub number: Option<u16>,
+    pub name: String,
+    // the name of the crew member, empty if it's on the ship
+    pub holder: String,
+    pub location_id: Option<LocationId>,
+    pub quest_id: Option<QuestId>,
+}
+
+impl Item {
+    #[must_use]
+    pub fn new(kind: ItemKind) -> Self {
+        Self {
+            kind,
+            number: None,
+            name: String::new(),
+            holder: String::new(),
+            location_id: None,
+            quest_id: None,
+        }
+    }
+
+    // the same card, even if it's held by someone else or the origin was changed
+    #[must_use]
+    pub fn is_same(&self, other: &Item) -> bool {
+        self.kind == other.kind && self.number == other.number && self.name == other.name
+    }
+
+    // the search is a card number or a part of the name, ignoring the case
+    #[must_use]
+    pub fn matches(&self, search: &str) -> bool {
+        let search = search.trim();
+        if search.is_empty() {
+            return true;
+        }
+        if let Ok(number) = search.parse::<u16>() {
+            return self.number == Some(number);
+        }
+        self.name.to_lowercase().contains(&search.to_lowercase())
+    }
+}
@@ -0,0 +1,92 @@
+use crate::game::LocationId;
+use chrono::{DateTime, Local};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+// the values of the save sheet which are changed by adding or subtracting
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum Resource {
+    ShipDamage = 0,
+    Coins = 1,
+    Food = 2,
+}
+
+impl Resource {
+    pub fn iter() -> impl Iterator<Item = Self> {
+        [Resource::ShipDamage, Resource::Coins, Resource::Food].into_iter()
+    }
+
+    pub fn to_csv(self) -> &'static str {
+        match self {
+            Resource::ShipDamage => "ship-damage",
+            Resource::Coins => "coins",
+            Resource::Food => "food",
+        }
+    }
+
+    pub fn try_from_csv(input: &str) -> Option<Self> {
+        Self::iter().find(|resource| resource.to_csv() == input)
+    }
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Eq, PartialEq)]
+pub struct LedgerEntry {
+    pub time: i64,
+    pub resource: Resource,
+    pub amount: i32,
+    pub reason: String,
+    pub location_id: Option<LocationId>,
+}
+
+// every change of the resources, in the order they happened, and where the ship is
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Default, Eq, PartialEq)]
+pub struct Ledger {
+    pub entry: Vec<LedgerEntry>,
+    pub location_id: Option<LocationId>,
+}
+
+impl LedgerEntry {
+    #[must_use]
+    pub fn local_time(&self) -> Option<DateTime<Local>> {
+        DateTime::from_timestamp(self.time, 0).map(|time| time.with_timezone(&Local))
+    }
+}
+
+impl Ledger {
+    // the entry is at the current location of the ship
+    pub fn record(&mut self, resource: Resource, amount: i32, reason: String) {
+        self.entry.push(LedgerEntry {
+            time: Local::now().timestamp(),
+            resource,
+            amount,
+            reason,
+            location_id: self.location_id,
+        });
+    }
+
+    pub fn total(&self, resource: Resource) -> i32 {
+        self.entry
+            .iter()
+            .filter(|entry| entry.resource == resource)
+            .map(|entry| entry.amount)
+            .fold(0, i32::saturating_add)
+    }
+
+    // every entry with the total of its resource after it
+    pub fn running(&self) -> impl Iterator<Item = (&LedgerEntry, i32)> {
+        let mut totals = [0; 3];
+        self.entry.iter().map(move |entry| {
+            let total = &mut totals[entry.resource as usize];
+            *total = i32::saturating_add(*total, entry.amount);
+            (entry, *total)
+        })
+    }
+
+    pub fn clear(&mut self) {
+        self.entry.clear();
+        self.location_id = None;
+    }
+}
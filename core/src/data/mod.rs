@@ -0,0 +1,13 @@
+pub mod crew;
+pub mod encounter_type;
+pub mod event;
+pub mod item;
+pub mod ledger;
+pub mod note;
+pub mod prerequisite;
+pub mod quest;
+pub mod quest_location;
+pub mod score;
+pub mod session;
+pub mod totem;
+pub mod vis;
@@ -0,0 +1,29 @@
+use std::fmt::{Display, Formatter};
+use std::ops::Deref;
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Default)]
+pub struct Note(String);
+
+impl Deref for Note {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<String> for Note {
+    #[inline]
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl Display for Note {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -0,0 +1,270 @@
+use crate::data::quest::QuestState;
+use crate::game::{QuestId, QuestLocale};
+use crate::journal::Journal;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+// A prerequisite expression, e.g. "A & (+B | !C)":
+// - a quest has to be completed or lost (this was the only option in older versions)
+// - "+" / "-": a quest has to be completed / lost
+// - "!": a quest must not have been gained yet
+// - "&": all of the terms must be met
+// - "|": any of the terms must be met ("&" binds stronger)
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Eq, PartialEq)]
+pub enum Prerequisite {
+    Quest(QuestId),
+    Completed(QuestId),
+    Lost(QuestId),
+    NotGained(QuestId),
+    AllOf(Vec<Prerequisite>),
+    AnyOf(Vec<Prerequisite>),
+}
+
+impl Prerequisite {
+    fn all_of(mut terms: Vec<Prerequisite>) -> Self {
+        if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            Prerequisite::AllOf(terms)
+        }
+    }
+
+    fn any_of(mut terms: Vec<Prerequisite>) -> Self {
+        if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            Prerequisite::AnyOf(terms)
+        }
+    }
+
+    #[must_use]
+    pub fn term(prefix: &str, quest_id: QuestId) -> Option<Self> {
+        match prefix {
+            "" => Some(Prerequisite::Quest(quest_id)),
+            "+" => Some(Prerequisite::Completed(quest_id)),
+            "-" => Some(Prerequisite::Lost(quest_id)),
+            "!" => Some(Prerequisite::NotGained(quest_id)),
+            _ => None,
+        }
+    }
+
+    // the quest and the prefix of a single term
+    #[must_use]
+    pub fn as_term(&self) -> Option<(QuestId, &'static str)> {
+        match self {
+            Prerequisite::Quest(quest_id) => Some((*quest_id, "")),
+            Prerequisite::Completed(quest_id) => Some((*quest_id, "+")),
+            Prerequisite::Lost(quest_id) => Some((*quest_id, "-")),
+            Prerequisite::NotGained(quest_id) => Some((*quest_id, "!")),
+            Prerequisite::AllOf(_) | Prerequisite::AnyOf(_) => None,
+        }
+    }
+
+    #[must_use]
+    pub fn is_met(&self, journal: &Journal) -> bool {
+        match self {
+            Prerequisite::Quest(quest_id) => journal
+                .quest
+                .get(quest_id)
+                .is_some_and(|q| q.state.is_done()),
+            Prerequisite::Completed(quest_id) => journal
+                .quest
+                .get(quest_id)
+                .is_some_and(|q| q.state == QuestState::Completed),
+            Prerequisite::Lost(quest_id) => journal
+                .quest
+                .get(quest_id)
+                .is_some_and(|q| q.state == QuestState::Lost),
+            Prerequisite::NotGained(quest_id) => journal
+                .quest
+                .get(quest_id)
+                .is_none_or(|q| q.state == QuestState::NotFound),
+            Prerequisite::AllOf(terms) => terms.iter().all(|term| term.is_met(journal)),
+            Prerequisite::AnyOf(terms) => terms.iter().any(|term| term.is_met(journal)),
+        }
+    }
+
+    // all quests which have to be completed or lost in some way
+    #[must_use]
+    pub fn required(&self) -> Vec<QuestId> {
+        let mut result = Vec::new();
+        self.required_into(&mut result);
+        result
+    }
+
+    fn required_into(&self, result: &mut Vec<QuestId>) {
+        match self {
+            Prerequisite::Quest(quest_id)
+            | Prerequisite::Completed(quest_id)
+            | Prerequisite::Lost(quest_id) => result.push(*quest_id),
+            Prerequisite::NotGained(_) => (),
+            Prerequisite::AllOf(terms) | Prerequisite::AnyOf(terms) => {
+                for term in terms {
+                    term.required_into(result);
+                }
+            }
+        }
+    }
+
+    fn format<F, S>(&self, name: &F, nested: bool, result: &mut String)
+    where
+        F: Fn(QuestId) -> S,
+        S: AsRef<str>,
+    {
+        match self {
+            Prerequisite::Quest(quest_id)
+            | Prerequisite::Completed(quest_id)
+            | Prerequisite::Lost(quest_id)
+            | Prerequisite::NotGained(quest_id) => {
+                if let Some((_, prefix)) = self.as_term() {
+                    result.push_str(prefix);
+                }
+                result.push_str(name(*quest_id).as_ref());
+            }
+            Prerequisite::AllOf(terms) | Prerequisite::AnyOf(terms) => {
+                let separator = if matches!(self, Prerequisite::AllOf(_)) {
+                    " & "
+                } else {
+                    " | "
+                };
+                if nested {
+                    result.push('(');
+                }
+                for (pos, term) in terms.iter().enumerate() {
+                    if pos > 0 {
+                        result.push_str(separator);
+                    }
+                    term.format(name, true, result);
+                }
+                if nested {
+                    result.push(')');
+                }
+            }
+        }
+    }
+
+    fn parse<F>(input: &str, lookup: F) -> Option<Self>
+    where
+        F: Fn(&str) -> Option<QuestId>,
+    {
+        let mut parser = Parser {
+            input: input.trim(),
+            lookup,
+        };
+        let result = parser.any_of()?;
+        if parser.input.is_empty() {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    pub fn to_csv(&self, quest_locale: &QuestLocale) -> String {
+        let mut result = String::new();
+        self.format(&|quest_id| quest_locale.get(quest_id), false, &mut result);
+        result
+    }
+
+    pub fn try_from_csv(input: &str, quests: &HashMap<&str, QuestId>) -> Option<Self> {
+        Self::parse(input, |name| quests.get(name).copied())
+    }
+
+    // the first unknown quest of an expression, which is valid otherwise
+    pub fn unknown_csv_quest(input: &str, quests: &HashMap<&str, QuestId>) -> Option<String> {
+        let unknown = RefCell::new(None);
+        Self::parse(input, |name| {
+            let quest_id = quests.get(name).copied();
+            if quest_id.is_none() && !name.is_empty() {
+                unknown.borrow_mut().get_or_insert_with(|| name.to_string());
+                return Some(QuestId::cottage());
+            }
+            quest_id
+        })?;
+        unknown.into_inner()
+    }
+
+    // the same syntax as the csv, but with raw ids instead of the translated names
+    pub fn to_storage(&self) -> String {
+        let mut result = String::new();
+        self.format(&|quest_id| quest_id.raw().to_string(), false, &mut result);
+        result
+    }
+
+    pub fn try_from_storage(input: &str) -> Option<Self> {
+        Self::parse(input, |raw| {
+            usize::from_str(raw).ok().and_then(QuestId::from_raw)
+        })
+    }
+
+    // the term at the path, each element is the position within a group
+    pub fn get_mut(&mut self, path: &[usize]) -> Option<&mut Prerequisite> {
+        match path.split_first() {
+            None => Some(self),
+            Some((pos, rest)) => match self {
+                Prerequisite::AllOf(terms) | Prerequisite::AnyOf(terms) => {
+                    terms.get_mut(*pos)?.get_mut(rest)
+                }
+                Prerequisite::Quest(_)
+                | Prerequisite::Completed(_)
+                | Prerequisite::Lost(_)
+                | Prerequisite::NotGained(_) => None,
+            },
+        }
+    }
+}
+
+struct Parser<'a, F> {
+    input: &'a str,
+    lookup: F,
+}
+
+impl<F> Parser<'_, F>
+where
+    F: Fn(&str) -> Option<QuestId>,
+{
+    fn eat(&mut self, c: char) -> bool {
+        if let Some(rest) = self.input.strip_prefix(c) {
+            self.input = rest.trim_start();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn any_of(&mut self) -> Option<Prerequisite> {
+        let mut terms = vec![self.all_of()?];
+        while self.eat('|') {
+            terms.push(self.all_of()?);
+        }
+        Some(Prerequisite::any_of(terms))
+    }
+
+    fn all_of(&mut self) -> Option<Prerequisite> {
+        let mut terms = vec![self.term()?];
+        while self.eat('&') {
+            terms.push(self.term()?);
+        }
+        Some(Prerequisite::all_of(terms))
+    }
+
+    fn term(&mut self) -> Option<Prerequisite> {
+        if self.eat('(') {
+            let result = self.any_of()?;
+            return self.eat(')').then_some(result);
+        }
+        let prefix = ["+", "-", "!"]
+            .into_iter()
+            .find(|prefix| self.input.starts_with(prefix))
+            .unwrap_or_default();
+        self.input = self.input[prefix.len()..].trim_start();
+        let end = self
+            .input
+            .find(['&', '|', '(', ')', '!'])
+            .unwrap_or(self.input.len());
+        let quest_id = (self.lookup)(self.input[..end].trim())?;
+        self.input = &self.input[end..];
+        Prerequisite::term(prefix, quest_id)
+    }
+}
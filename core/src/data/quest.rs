@@ -0,0 +1,94 @@
+use crate::data::encounter_type::EncounterType;
+use crate::data::note::Note;
+use crate::data::quest_location::QuestLocation;
+use crate::data::vis::Vis;
+use crate::game::{LocationId, QuestId};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::collections::BTreeMap;
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Default, Clone)]
+pub struct Quest {
+    pub state: QuestState,
+    pub encounter: BTreeMap<LocationId, QuestLocation>,
+    pub vis: Vis,
+    pub note: Note,
+}
+
+impl Quest {
+    #[must_use]
+    pub fn is_keyword(&self, quest_id: QuestId) -> Option<bool> {
+        if self
+            .encounter
+            .values()
+            .any(|e| e.contains_key(EncounterType::Gain))
+        {
+            Some(quest_id.is_keyword_raw())
+        } else {
+            None
+        }
+    }
+
+    pub fn contains_visible_encounter_type(&self, encounter_type: EncounterType) -> bool {
+        self.encounter.iter().any(|(_, ql)| {
+            ql.iter()
+                .any(|(et, qle)| *et == encounter_type && qle.vis == Vis::Visible)
+        })
+    }
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone, Default, Eq, PartialEq, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum QuestState {
+    #[default]
+    NotFound = 0,
+    InGame = 1,
+    // completed or lost, from before these were distinguished
+    Removed = 2,
+    Completed = 3,
+    Lost = 4,
+}
+
+impl QuestState {
+    #[inline]
+    #[must_use]
+    pub fn is_done(self) -> bool {
+        matches!(
+            self,
+            QuestState::Removed | QuestState::Completed | QuestState::Lost
+        )
+    }
+
+    // how far the quest is, removed is the older form of completed or lost
+    #[inline]
+    #[must_use]
+    pub fn progress(self) -> u8 {
+        match self {
+            QuestState::NotFound => 0,
+            QuestState::InGame => 1,
+            QuestState::Removed => 2,
+            QuestState::Completed | QuestState::Lost => 3,
+        }
+    }
+
+    pub fn to_csv(self) -> &'static str {
+        match self {
+            QuestState::NotFound => "",
+            QuestState::InGame => "in-game",
+            QuestState::Removed => "removed",
+            QuestState::Completed => "completed",
+            QuestState::Lost => "lost",
+        }
+    }
+    pub fn try_from_csv(input: &str) -> Option<Self> {
+        match input {
+            "" => Some(QuestState::NotFound),
+            "in-game" => Some(QuestState::InGame),
+            "removed" => Some(QuestState::Removed),
+            "completed" => Some(QuestState::Completed),
+            "lost" => Some(QuestState::Lost),
+            _ => None,
+        }
+    }
+}
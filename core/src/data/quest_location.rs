@@ -1,8 +1,8 @@
 use crate::data::encounter_type::EncounterType;
+use crate::data::prerequisite::Prerequisite;
 use crate::data::quest::{Quest, QuestState};
 use crate::data::vis::Vis;
-use crate::game::QuestId;
-use crate::global::data::Data;
+use crate::journal::Journal;
 use std::collections::BTreeMap;
 use std::collections::btree_map::{Iter, IterMut};
 use std::ops::{Deref, DerefMut};
@@ -10,73 +10,66 @@ use std::ops::{Deref, DerefMut};
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Default, Clone)]
 #[repr(transparent)]
-pub(crate) struct QuestLocation(BTreeMap<EncounterType, QuestLocationEncounter>);
+pub struct QuestLocation(BTreeMap<EncounterType, QuestLocationEncounter>);
 
 impl QuestLocation {
     // shortcuts, which does not require a reference
     #[inline]
-    pub(crate) fn contains_key(&self, encounter_type: EncounterType) -> bool {
+    pub fn contains_key(&self, encounter_type: EncounterType) -> bool {
         self.0.contains_key(&encounter_type)
     }
 
     #[inline]
-    pub(crate) fn remove(&mut self, key: EncounterType) -> Option<QuestLocationEncounter> {
+    pub fn remove(&mut self, key: EncounterType) -> Option<QuestLocationEncounter> {
         self.0.remove(&key)
     }
 
     #[inline]
-    pub(crate) fn get_mut(&mut self, key: EncounterType) -> Option<&mut QuestLocationEncounter> {
+    pub fn get_mut(&mut self, key: EncounterType) -> Option<&mut QuestLocationEncounter> {
         self.0.get_mut(&key)
     }
 
     // shortcut to create a QuestLocationEncounter and insert it
     #[inline]
-    pub(crate) fn insert(
+    pub fn insert(
         &mut self,
         key: EncounterType,
-        prerequisite: Option<QuestId>,
+        prerequisite: Option<Prerequisite>,
         vis: Vis,
     ) -> Option<QuestLocationEncounter> {
         self.0
             .insert(key, QuestLocationEncounter { prerequisite, vis })
     }
 
-    pub(crate) fn get_active(
+    pub fn get_active(
         &self,
         quest: &Quest,
-        data: &Data,
+        journal: &Journal,
         ignore_visibility: bool,
     ) -> BTreeMap<EncounterType, bool> {
         let mut result = BTreeMap::new();
         if quest.vis == Vis::Visible || ignore_visibility {
             for (et, qle) in &self.0 {
                 if qle.vis == Vis::Visible || ignore_visibility {
+                    let ready = match quest.state {
+                        QuestState::NotFound => match et {
+                            EncounterType::Unless | EncounterType::Gain => true,
+                            EncounterType::When | EncounterType::Complete | EncounterType::Lose => {
+                                false
+                            }
+                        },
+                        QuestState::InGame => match et {
+                            EncounterType::When | EncounterType::Complete | EncounterType::Lose => {
+                                true
+                            }
+                            EncounterType::Unless | EncounterType::Gain => false,
+                        },
+                        QuestState::Removed | QuestState::Completed | QuestState::Lost => false,
+                    };
+                    // without a prerequisite it's always ready
                     result.insert(
                         *et,
-                        match quest.state {
-                            QuestState::NotFound => match et {
-                                EncounterType::Unless => true,
-                                EncounterType::Gain => {
-                                    match qle.prerequisite {
-                                        None => true, // always ready to pick up
-                                        Some(prerequisite) => data
-                                            .quest
-                                            .get(&prerequisite)
-                                            .is_some_and(|q| q.state == QuestState::Removed),
-                                    }
-                                }
-                                EncounterType::When
-                                | EncounterType::Complete
-                                | EncounterType::Lose => false,
-                            },
-                            QuestState::InGame => match et {
-                                EncounterType::When
-                                | EncounterType::Complete
-                                | EncounterType::Lose => true,
-                                EncounterType::Unless | EncounterType::Gain => false,
-                            },
-                            QuestState::Removed => false,
-                        },
+                        ready && qle.prerequisite.as_ref().is_none_or(|p| p.is_met(journal)),
                     );
                 }
             }
@@ -119,7 +112,7 @@ impl<'a> IntoIterator for &'a mut QuestLocation {
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
-pub(crate) struct QuestLocationEncounter {
-    pub(crate) prerequisite: Option<QuestId>,
-    pub(crate) vis: Vis,
+pub struct QuestLocationEncounter {
+    pub prerequisite: Option<Prerequisite>,
+    pub vis: Vis,
 }
@@ -0,0 +1,155 @@
+use crate::data::crew::CrewMember;
+use crate::data::ledger::Resource;
+use crate::data::quest::QuestState;
+use crate::journal::Journal;
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+// a part of the end-of-campaign score
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum ScorePart {
+    Totems = 0,
+    CompletedQuests = 1,
+    Coins = 2,
+    Food = 3,
+    ShipDamage = 4,
+    CrewLevels = 5,
+}
+
+impl ScorePart {
+    pub fn iter() -> impl Iterator<Item = Self> {
+        [
+            ScorePart::Totems,
+            ScorePart::CompletedQuests,
+            ScorePart::Coins,
+            ScorePart::Food,
+            ScorePart::ShipDamage,
+            ScorePart::CrewLevels,
+        ]
+        .into_iter()
+    }
+
+    pub fn to_csv(self) -> &'static str {
+        match self {
+            ScorePart::Totems => "totems",
+            ScorePart::CompletedQuests => "completed-quests",
+            ScorePart::Coins => "coins",
+            ScorePart::Food => "food",
+            ScorePart::ShipDamage => "ship-damage",
+            ScorePart::CrewLevels => "crew-levels",
+        }
+    }
+
+    pub fn try_from_csv(input: &str) -> Option<Self> {
+        Self::iter().find(|part| part.to_csv() == input)
+    }
+}
+
+// the points for every `per` counted
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct ScoreRate {
+    pub points: i32,
+    pub per: i32,
+}
+
+impl ScoreRate {
+    // the points printed on the totem cards are counted as they are, the other parts are only
+    // scored if the rates of the rules in use are entered
+    #[must_use]
+    pub fn default_for(part: ScorePart) -> Self {
+        match part {
+            ScorePart::Totems => Self { points: 1, per: 1 },
+            ScorePart::CompletedQuests
+            | ScorePart::Coins
+            | ScorePart::Food
+            | ScorePart::ShipDamage
+            | ScorePart::CrewLevels => Self { points: 0, per: 1 },
+        }
+    }
+
+    pub fn points(self, count: i32) -> i32 {
+        (count.max(0) / self.per.max(1)).saturating_mul(self.points)
+    }
+
+    // "points" or "points/per"
+    pub fn to_csv(self) -> String {
+        if self.per == 1 {
+            self.points.to_string()
+        } else {
+            format!("{}/{}", self.points, self.per)
+        }
+    }
+
+    pub fn try_from_csv(input: &str) -> Option<Self> {
+        let (points, per) = input.split_once('/').unwrap_or((input, "1"));
+        let per = per.parse().ok().filter(|per| *per > 0)?;
+        Some(Self {
+            points: points.parse().ok()?,
+            per,
+        })
+    }
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct ScoreLine {
+    pub part: ScorePart,
+    pub count: i32,
+    pub rate: ScoreRate,
+    pub points: i32,
+}
+
+impl Journal {
+    // the rate entered for the campaign or the default one
+    #[must_use]
+    pub fn score_rate(&self, part: ScorePart) -> ScoreRate {
+        self.score_rate
+            .get(&part)
+            .copied()
+            .unwrap_or_else(|| ScoreRate::default_for(part))
+    }
+
+    // every part of the score, their points summed are the score
+    pub fn score(&self) -> Vec<ScoreLine> {
+        ScorePart::iter()
+            .map(|part| {
+                let count = match part {
+                    ScorePart::Totems => self
+                        .totem
+                        .iter()
+                        .map(|totem| i32::from(totem.points))
+                        .fold(0, i32::saturating_add),
+                    ScorePart::CompletedQuests => count(
+                        self.quest
+                            .values()
+                            .filter(|quest| quest.state == QuestState::Completed)
+                            .count(),
+                    ),
+                    ScorePart::Coins => self.ledger.total(Resource::Coins),
+                    ScorePart::Food => self.ledger.total(Resource::Food),
+                    ScorePart::ShipDamage => self.ledger.total(Resource::ShipDamage),
+                    ScorePart::CrewLevels => self
+                        .crew
+                        .iter()
+                        .map(|crew_member| {
+                            i32::from(crew_member.level.saturating_sub(CrewMember::START_LEVEL))
+                        })
+                        .fold(0, i32::saturating_add),
+                };
+                let rate = self.score_rate(part);
+                ScoreLine {
+                    part,
+                    count,
+                    rate,
+                    points: rate.points(count),
+                }
+            })
+            .collect()
+    }
+}
+
+fn count(len: usize) -> i32 {
+    i32::try_from(len).unwrap_or(i32::MAX)
+}
@@ -0,0 +1,130 @@
+use crate::data::encounter_type::EncounterType;
+use crate::data::event::EventAction;
+use crate::data::note::Note;
+use crate::data::quest::{Quest, QuestState};
+use crate::game::{LocationId, QuestId};
+use crate::journal::{Difference, Journal, NoteId};
+use chrono::{DateTime, Local};
+use std::collections::HashMap;
+
+// the quests and notes when the session was started, the summary is the difference to them
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub struct SessionStart {
+    pub time: i64,
+    pub quest: HashMap<QuestId, Quest>,
+    pub location: HashMap<LocationId, Note>,
+}
+
+// what happened in a session which was ended
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Eq, PartialEq)]
+pub struct SessionSummary {
+    pub start: i64,
+    pub end: i64,
+    pub quest_gained: Vec<QuestId>,
+    pub quest_completed: Vec<QuestId>,
+    pub quest_lost: Vec<QuestId>,
+    pub encounter: Vec<(QuestId, LocationId, EncounterType)>,
+    pub note: Vec<NoteId>,
+    // in the order of the first visit
+    pub location: Vec<LocationId>,
+}
+
+impl SessionSummary {
+    #[must_use]
+    pub fn local_start(&self) -> Option<DateTime<Local>> {
+        DateTime::from_timestamp(self.start, 0).map(|time| time.with_timezone(&Local))
+    }
+
+    #[must_use]
+    pub fn local_end(&self) -> Option<DateTime<Local>> {
+        DateTime::from_timestamp(self.end, 0).map(|time| time.with_timezone(&Local))
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.quest_gained.is_empty()
+            && self.quest_completed.is_empty()
+            && self.quest_lost.is_empty()
+            && self.encounter.is_empty()
+            && self.note.is_empty()
+            && self.location.is_empty()
+    }
+}
+
+impl Journal {
+    // a running session is started anew
+    pub fn start_session(&mut self) {
+        self.session_start = Some(SessionStart {
+            time: Local::now().timestamp(),
+            quest: self.quest.clone(),
+            location: self.location.clone(),
+        });
+    }
+
+    // the summary of the running session is added to the past ones
+    pub fn end_session(&mut self) -> Option<&SessionSummary> {
+        let start = self.session_start.take()?;
+        let summary = self.summarize(start, Local::now().timestamp());
+        self.session.push(summary);
+        self.session.last()
+    }
+
+    #[must_use]
+    pub fn summarize(&self, start: SessionStart, end: i64) -> SessionSummary {
+        let mut summary = SessionSummary {
+            start: start.time,
+            end,
+            quest_gained: Vec::new(),
+            quest_completed: Vec::new(),
+            quest_lost: Vec::new(),
+            encounter: Vec::new(),
+            note: Vec::new(),
+            location: Vec::new(),
+        };
+
+        let before = Journal {
+            quest: start.quest,
+            location: start.location,
+            ..Journal::default()
+        };
+        for difference in before.diff(self) {
+            match difference {
+                // a completed or lost quest which is in the game again was not gained
+                Difference::QuestState(quest_id, QuestState::NotFound, QuestState::InGame) => {
+                    summary.quest_gained.push(quest_id);
+                }
+                Difference::QuestState(quest_id, _, QuestState::Completed) => {
+                    summary.quest_completed.push(quest_id);
+                }
+                Difference::QuestState(quest_id, _, QuestState::Lost | QuestState::Removed) => {
+                    summary.quest_lost.push(quest_id);
+                }
+                Difference::EncounterAdded(quest_id, location_id, encounter_type) => {
+                    summary
+                        .encounter
+                        .push((quest_id, location_id, encounter_type));
+                }
+                Difference::Note(note_id) => summary.note.push(note_id),
+                _ => (),
+            }
+        }
+
+        for event in &self.event {
+            if event.time < start.time || event.time > end {
+                continue;
+            }
+            if let (EventAction::Perform(_) | EventAction::Record(_), Some(location_id)) =
+                (event.action, event.location_id)
+            {
+                if location_id != LocationId::prologue() && !summary.location.contains(&location_id)
+                {
+                    summary.location.push(location_id);
+                }
+            }
+        }
+
+        summary
+    }
+}
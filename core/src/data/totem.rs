@@ -0,0 +1,25 @@
+use crate::game::LocationId;
+use crate::journal::Journal;
+use chrono::Local;
+
+// an acquired totem, the name and the points are as printed on its card
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Eq, PartialEq)]
+pub struct Totem {
+    pub name: String,
+    pub points: u8,
+    pub location_id: Option<LocationId>,
+    pub session: u32,
+}
+
+impl Journal {
+    // the totem is acquired now, at the current location of the ship
+    pub fn acquire_totem(&mut self, name: String, points: u8) {
+        self.totem.push(Totem {
+            name,
+            points,
+            location_id: self.ledger.location_id,
+            session: self.session_at(Local::now().timestamp()),
+        });
+    }
+}
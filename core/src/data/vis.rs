@@ -0,0 +1,32 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(
+    Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Serialize_repr, Deserialize_repr,
+)]
+#[repr(u8)]
+pub enum Vis {
+    #[default]
+    Visible = 0,
+    HiddenThisCampaign = 1,
+    HiddenForever = 2,
+}
+
+impl Vis {
+    pub fn to_csv(self) -> &'static str {
+        match self {
+            Vis::Visible => "",
+            Vis::HiddenThisCampaign => "hidden-this-campaign",
+            Vis::HiddenForever => "hidden-forever",
+        }
+    }
+
+    pub fn try_from_csv(input: &str) -> Option<Self> {
+        match input {
+            "" => Some(Vis::Visible),
+            "hidden-this-campaign" => Some(Vis::HiddenThisCampaign),
+            "hidden-forever" => Some(Vis::HiddenForever),
+            _ => None,
+        }
+    }
+}
@@ -14,7 +14,7 @@
 #[enum_tools(as_str, iter, names)]
 #[typed_i18n(filename = "game.lrc")]
 #[typed_i18n(builder = "mixed_str")]
-pub(crate) enum GameLanguage {
+pub enum GameLanguage {
     #[enum_tools(rename = "Deutsch")]
     #[serde(rename = "de")]
     #[typed_i18n(name = "de")]
@@ -0,0 +1,78 @@
+use crate::game::pack::index_of;
+use crate::game::{GameLanguage, PackId};
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct LocationId {
+    pack: PackId,
+    index: u16,
+}
+
+impl LocationId {
+    #[inline]
+    #[must_use]
+    pub fn raw(self) -> usize {
+        self.pack
+            .join_raw(self.pack.pack().locations_ids[self.index as usize])
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_raw(raw: usize) -> Option<Self> {
+        let (pack, id) = PackId::split_raw(raw)?;
+        let content = pack.pack();
+        let index = index_of(content.locations_ids, content.locations_remap, id)?;
+        // the prologue is the only location without name
+        if content.locations[index as usize][0].is_some() {
+            Some(Self { pack, index })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn all() -> impl Iterator<Item = Self> {
+        PackId::all().flat_map(Self::all_of)
+    }
+
+    #[inline]
+    pub fn all_of(pack: PackId) -> impl Iterator<Item = Self> {
+        #[allow(clippy::cast_possible_truncation)]
+        (0..(pack.pack().locations.len() as u16)).map(move |index| Self { pack, index })
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn pack(self) -> PackId {
+        self.pack
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn name(self, locale: GameLanguage) -> &'static str {
+        self.pack.pack().locations[self.index as usize][0].unwrap_or_else(|| locale.prologue())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn try_from_name(name: &str) -> Option<Self> {
+        Self::all().find(|location_id| {
+            location_id.pack.pack().locations[location_id.index as usize][0] == Some(name)
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn page(self) -> Option<&'static str> {
+        self.pack.pack().locations[self.index as usize][1]
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn prologue() -> Self {
+        LocationId {
+            pack: PackId::base(),
+            index: 0,
+        }
+    }
+}
@@ -0,0 +1,13 @@
+pub use crate::game::game_language::GameLanguage;
+pub use crate::game::location_id::LocationId;
+pub use crate::game::pack::{ContentPack, PackId};
+pub use crate::game::quest_id::QuestId;
+pub use crate::game::quest_locale::QuestLocale;
+
+mod game_language;
+mod generated;
+mod location_id;
+mod map;
+mod pack;
+mod quest_id;
+mod quest_locale;
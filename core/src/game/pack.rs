@@ -0,0 +1,129 @@
+use crate::game::generated::{
+    LOCATIONS, LOCATIONS_IDS, LOCATIONS_REMAP, QUESTS, QUESTS_IDS, QUESTS_KEYWORDS, QUESTS_REMAP,
+    QUESTS_ROWS,
+};
+use crate::game::map::{MAP, MAP_DEFAULT_POSITION};
+use std::iter::Map;
+use std::ops::Range;
+
+// raw ids are namespaced: the pack is stored above the stable id within the pack,
+// the base game is pack 0, thus its raw ids are the same as before packs existed.
+// The stable ids of the base game are the table indexes stored up to game data version 7.
+const RAW_SHIFT: usize = 16;
+const RAW_MASK: usize = (1 << RAW_SHIFT) - 1;
+
+pub struct ContentPack {
+    // stored with the campaign, must never change
+    pub key: &'static str,
+    pub name: &'static str,
+    // one list of quest names per game language
+    pub quests: [&'static [&'static str]; QUESTS_ROWS],
+    pub quests_keywords: &'static [bool],
+    // stored instead of the table index, thus the table can be reordered
+    pub quests_ids: &'static [u16],
+    // retired ids and their replacements
+    pub quests_remap: &'static [(u16, u16)],
+    // name and atlas page, a location without name is the prologue
+    pub locations: &'static [[Option<&'static str>; 2]],
+    pub locations_ids: &'static [u16],
+    pub locations_remap: &'static [(u16, u16)],
+    // atlas pages (left, right), displayed in rows of 3
+    pub map: &'static [Option<(&'static str, &'static str)>],
+    pub map_default_position: usize,
+    // ids of the quests every campaign starts with, gained in the prologue
+    pub built_in: &'static [u16],
+}
+
+// the position is part of the raw ids, thus new packs are only appended
+pub static PACKS: [ContentPack; 1] = [ContentPack {
+    key: "base",
+    name: "Sleeping Gods",
+    quests: [&QUESTS[0], &QUESTS[1]],
+    quests_keywords: &QUESTS_KEYWORDS,
+    quests_ids: &QUESTS_IDS,
+    quests_remap: &QUESTS_REMAP,
+    locations: &LOCATIONS,
+    locations_ids: &LOCATIONS_IDS,
+    locations_remap: &LOCATIONS_REMAP,
+    map: &MAP,
+    map_default_position: MAP_DEFAULT_POSITION,
+    built_in: &[0, 1],
+}];
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[repr(transparent)]
+pub struct PackId(u8);
+
+impl PackId {
+    #[inline]
+    #[must_use]
+    pub const fn base() -> Self {
+        PackId(0)
+    }
+
+    #[inline]
+    pub fn all() -> Map<Range<u8>, fn(u8) -> PackId> {
+        #[allow(clippy::cast_possible_truncation)]
+        (0..(PACKS.len() as u8)).map(PackId)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn pack(self) -> &'static ContentPack {
+        &PACKS[self.0 as usize]
+    }
+
+    #[must_use]
+    pub fn try_from_key(key: &str) -> Option<Self> {
+        Self::all().find(|pack_id| pack_id.pack().key == key)
+    }
+
+    // splits a namespaced raw id into the pack and the stable id, the pack must exist
+    #[inline]
+    #[must_use]
+    pub(super) fn split_raw(raw: usize) -> Option<(Self, usize)> {
+        let pack = raw >> RAW_SHIFT;
+        if pack < PACKS.len() {
+            #[allow(clippy::cast_possible_truncation)]
+            Some((PackId(pack as u8), raw & RAW_MASK))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub(super) fn join_raw(self, id: u16) -> usize {
+        (usize::from(self.0) << RAW_SHIFT) | usize::from(id)
+    }
+}
+
+// the table index of a stable id, a retired id is replaced first
+#[must_use]
+pub(super) fn index_of(ids: &[u16], remap: &[(u16, u16)], id: usize) -> Option<u16> {
+    let id = remap
+        .iter()
+        .find(|(from, _)| usize::from(*from) == id)
+        .map_or(id, |(_, to)| usize::from(*to));
+    #[allow(clippy::cast_possible_truncation)]
+    ids.iter()
+        .position(|i| usize::from(*i) == id)
+        .map(|index| index as u16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::index_of;
+
+    // the base game has no retired ids yet, thus the remap is tested with its own tables
+    #[test]
+    fn retired_ids_are_remapped() {
+        let ids = [0, 1, 3];
+        let remap = [(2, 3)];
+        assert_eq!(index_of(&ids, &remap, 1), Some(1));
+        assert_eq!(index_of(&ids, &remap, 2), Some(2));
+        assert_eq!(index_of(&ids, &remap, 3), Some(2));
+        assert_eq!(index_of(&ids, &remap, 4), None);
+    }
+}
@@ -0,0 +1,70 @@
+use crate::game::pack::index_of;
+use crate::game::{GameLanguage, PackId};
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct QuestId {
+    pack: PackId,
+    index: u16,
+}
+
+impl QuestId {
+    #[inline]
+    #[must_use]
+    pub fn raw(self) -> usize {
+        self.pack
+            .join_raw(self.pack.pack().quests_ids[self.index as usize])
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_raw(raw: usize) -> Option<Self> {
+        let (pack, id) = PackId::split_raw(raw)?;
+        let content = pack.pack();
+        index_of(content.quests_ids, content.quests_remap, id).map(|index| Self { pack, index })
+    }
+
+    #[inline]
+    pub fn all_of(pack: PackId) -> impl Iterator<Item = Self> {
+        #[allow(clippy::cast_possible_truncation)]
+        (0..(pack.pack().quests_keywords.len() as u16)).map(move |index| Self { pack, index })
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn name(self, language: GameLanguage) -> &'static str {
+        self.pack.pack().quests[language as usize][self.index as usize]
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_keyword_raw(self) -> bool {
+        self.pack.pack().quests_keywords[self.index as usize]
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_built_in(self) -> bool {
+        let content = self.pack.pack();
+        content
+            .built_in
+            .contains(&content.quests_ids[self.index as usize])
+    }
+
+    #[inline]
+    pub fn built_in(pack: PackId) -> impl Iterator<Item = Self> {
+        pack.pack()
+            .built_in
+            .iter()
+            .filter_map(move |id| Self::from_raw(pack.join_raw(*id)))
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn cottage() -> Self {
+        Self {
+            pack: PackId::base(),
+            index: 1,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::game::{GameLanguage, PackId, QuestId};
+use std::iter::Copied;
+use std::slice::Iter;
+
+#[derive(Clone)]
+pub struct QuestLocale {
+    language: GameLanguage,
+    // only the quests of the enabled packs
+    translation: Vec<(QuestId, &'static str)>,
+}
+
+impl QuestLocale {
+    #[must_use]
+    pub fn new() -> Self {
+        let mut result = Self {
+            language: GameLanguage::default(),
+            translation: Vec::new(),
+        };
+        result.set_packs(&[PackId::base()]);
+        result
+    }
+
+    #[inline]
+    pub fn all_str(&self) -> Copied<Iter<'_, (QuestId, &'static str)>> {
+        self.translation.iter().copied()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn language(&self) -> GameLanguage {
+        self.language
+    }
+
+    pub fn set_language(&mut self, language: GameLanguage) {
+        self.language = language;
+        for (q, n) in &mut self.translation {
+            *n = q.name(language);
+        }
+        self.translation.sort_by_key(|(_, n)| *n);
+    }
+
+    pub fn set_packs(&mut self, packs: &[PackId]) {
+        self.translation = packs
+            .iter()
+            .flat_map(|pack| QuestId::all_of(*pack))
+            .map(|q| (q, q.name(self.language)))
+            .collect();
+        self.translation.sort_by_key(|(_, n)| *n);
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn get(&self, q: QuestId) -> &'static str {
+        q.name(self.language)
+    }
+
+    // the current game language first, then all others
+    #[must_use]
+    pub fn try_get(&self, name: &str) -> Option<QuestId> {
+        self.all_str()
+            .find(|(_, n)| *n == name)
+            .or_else(|| {
+                self.all_str()
+                    .find(|(q, _)| GameLanguage::iter().any(|l| q.name(l) == name))
+            })
+            .map(|(q, _)| q)
+    }
+}
+
+impl Default for QuestLocale {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
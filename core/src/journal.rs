@@ -0,0 +1,503 @@
+use crate::data::crew::CrewMember;
+use crate::data::encounter_type::EncounterType;
+use crate::data::event::{Event, EventAction};
+use crate::data::item::{Item, ItemKind};
+use crate::data::ledger::Ledger;
+use crate::data::note::Note;
+use crate::data::prerequisite::Prerequisite;
+use crate::data::quest::{Quest, QuestState};
+use crate::data::score::{ScorePart, ScoreRate};
+use crate::data::session::{SessionStart, SessionSummary};
+use crate::data::totem::Totem;
+use crate::data::vis::Vis;
+use crate::game::{LocationId, PackId, QuestId, QuestLocale};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+// the game data of a campaign
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Default)]
+pub struct Journal {
+    pub quest: HashMap<QuestId, Quest>,
+    pub location: HashMap<LocationId, Note>,
+    pub event: Vec<Event>,
+    pub crew: Vec<CrewMember>,
+    pub ledger: Ledger,
+    pub totem: Vec<Totem>,
+    pub item: Vec<Item>,
+    // only the rates which differ from `ScoreRate::default_for`
+    pub score_rate: BTreeMap<ScorePart, ScoreRate>,
+    pub session_start: Option<SessionStart>,
+    pub session: Vec<SessionSummary>,
+}
+
+impl Journal {
+    pub fn reset(&mut self, packs: &[PackId]) {
+        self.quest.clear();
+        self.location.clear();
+        self.event.clear();
+        self.crew.clear();
+        self.ledger.clear();
+        self.totem.clear();
+        self.item.clear();
+        self.score_rate.clear();
+        self.session_start = None;
+        self.session.clear();
+        self.built_in(packs);
+    }
+
+    // the quests, crew and ship start anew, the encounters and notes are kept for the next
+    // campaign, the totems of this one don't count anymore and its items are returned
+    pub fn new_campaign(&mut self) {
+        // ended before, otherwise it's compared to the quests of the last campaign
+        self.end_session();
+        for (quest_id, quest) in &mut self.quest {
+            if quest_id.is_built_in() {
+                quest.state = QuestState::InGame;
+            } else {
+                quest.state = QuestState::NotFound;
+            }
+            if quest.vis == Vis::HiddenThisCampaign {
+                quest.vis = Vis::Visible;
+            }
+            for quest_location in quest.encounter.values_mut() {
+                for (_, e) in quest_location {
+                    if e.vis == Vis::HiddenThisCampaign {
+                        e.vis = Vis::Visible;
+                    }
+                }
+            }
+        }
+        for crew_member in &mut self.crew {
+            crew_member.reset();
+        }
+        // the coins, food, damage and location of the ship start anew
+        self.ledger.clear();
+        self.totem.clear();
+        self.item.clear();
+        self.record_event(EventAction::NewCampaign, None, None);
+    }
+
+    pub fn cleanup(&mut self) {
+        self.quest.retain(|_, quest| {
+            quest
+                .encounter
+                .retain(|_, quest_location| !quest_location.is_empty());
+            !quest.encounter.is_empty() || !quest.note.is_empty()
+        });
+    }
+
+    // must be called after the enabled packs changed
+    pub fn built_in(&mut self, packs: &[PackId]) {
+        for quest_id in packs.iter().flat_map(|pack| QuestId::built_in(*pack)) {
+            let quest = self.quest.entry(quest_id).or_default();
+            if !quest.state.is_done() {
+                quest.state = QuestState::InGame;
+            }
+            let loc_pro = quest.encounter.entry(LocationId::prologue()).or_default();
+            loc_pro.clear();
+            loc_pro.insert(EncounterType::Gain, None, Vis::Visible);
+        }
+    }
+
+    // adds another journal of the same campaign: the encounters are united, the more advanced
+    // state and the stricter visibility are kept, the events are combined by their time.
+    // The crew of `other` is only taken if there is none.
+    // The ledger entries are combined like the events, the location of the ship is only taken
+    // from `other` if there is none.
+    // The totems are matched by their name.
+    // The items are matched by their kind, number and name.
+    // The score rates of `other` are only taken for the parts without one.
+    // The sessions are combined like the events, the running session of `other` is only taken if
+    // there is none.
+    // Differing notes, prerequisites, totems, items and states which are as advanced (completed
+    // and lost) are not merged, they are returned together with the value of `other`.
+    pub fn merge(&mut self, other: &Journal) -> Vec<Conflict> {
+        let mut conflicts = Vec::new();
+
+        for (quest_id, other_quest) in &other.quest {
+            let quest = self.quest.entry(*quest_id).or_default();
+            match other_quest.state.progress().cmp(&quest.state.progress()) {
+                Ordering::Greater => quest.state = other_quest.state,
+                Ordering::Equal if other_quest.state != quest.state => {
+                    conflicts.push(Conflict::QuestState(*quest_id, other_quest.state));
+                }
+                Ordering::Equal | Ordering::Less => (),
+            }
+            quest.vis = quest.vis.max(other_quest.vis);
+            for (location_id, other_quest_location) in &other_quest.encounter {
+                let quest_location = quest.encounter.entry(*location_id).or_default();
+                for (encounter_type, other_encounter) in other_quest_location {
+                    if let Some(encounter) = quest_location.get_mut(*encounter_type) {
+                        encounter.vis = encounter.vis.max(other_encounter.vis);
+                        if encounter.prerequisite.is_none() {
+                            encounter
+                                .prerequisite
+                                .clone_from(&other_encounter.prerequisite);
+                        } else if let Some(other_prerequisite) = &other_encounter.prerequisite {
+                            if encounter.prerequisite.as_ref() != Some(other_prerequisite) {
+                                conflicts.push(Conflict::Prerequisite(
+                                    *quest_id,
+                                    *location_id,
+                                    *encounter_type,
+                                    other_prerequisite.clone(),
+                                ));
+                            }
+                        }
+                    } else {
+                        quest_location.insert(
+                            *encounter_type,
+                            other_encounter.prerequisite.clone(),
+                            other_encounter.vis,
+                        );
+                    }
+                }
+            }
+            if let Some(note) = merge_note(&mut quest.note, &other_quest.note) {
+                conflicts.push(Conflict::Note(NoteId::Quest(*quest_id), note));
+            }
+        }
+
+        for (location_id, other_note) in &other.location {
+            let note = self.location.entry(*location_id).or_default();
+            if let Some(note) = merge_note(note, other_note) {
+                conflicts.push(Conflict::Note(NoteId::Location(*location_id), note));
+            }
+        }
+
+        for other_event in &other.event {
+            if !self.event.iter().any(|event| {
+                event.time == other_event.time
+                    && event.action == other_event.action
+                    && event.quest_id == other_event.quest_id
+                    && event.location_id == other_event.location_id
+            }) {
+                self.event.push(other_event.clone());
+            }
+        }
+        self.event.sort_by_key(|event| event.time);
+
+        if self.crew.is_empty() {
+            self.crew.clone_from(&other.crew);
+        }
+
+        for other_entry in &other.ledger.entry {
+            if !self.ledger.entry.contains(other_entry) {
+                self.ledger.entry.push(other_entry.clone());
+            }
+        }
+        self.ledger.entry.sort_by_key(|entry| entry.time);
+        if self.ledger.location_id.is_none() {
+            self.ledger.location_id = other.ledger.location_id;
+        }
+
+        for other_totem in &other.totem {
+            match self
+                .totem
+                .iter()
+                .find(|totem| totem.name == other_totem.name)
+            {
+                None => self.totem.push(other_totem.clone()),
+                Some(totem) if totem != other_totem => {
+                    conflicts.push(Conflict::Totem(other_totem.clone()));
+                }
+                Some(_) => (),
+            }
+        }
+
+        for other_item in &other.item {
+            match self.item.iter().find(|item| item.is_same(other_item)) {
+                None => self.item.push(other_item.clone()),
+                Some(item) if item != other_item => {
+                    conflicts.push(Conflict::Item(other_item.clone()));
+                }
+                Some(_) => (),
+            }
+        }
+
+        for (part, rate) in &other.score_rate {
+            self.score_rate.entry(*part).or_insert(*rate);
+        }
+
+        for other_session in &other.session {
+            if !self.session.contains(other_session) {
+                self.session.push(other_session.clone());
+            }
+        }
+        self.session.sort_by_key(|session| session.start);
+        if self.session_start.is_none() {
+            self.session_start.clone_from(&other.session_start);
+        }
+
+        self.cleanup();
+        conflicts
+    }
+
+    // what changes when this journal is replaced by `other`, ordered by the quests and locations
+    pub fn diff(&self, other: &Journal) -> Vec<Difference> {
+        let mut result = Vec::new();
+
+        let quests = self
+            .quest
+            .keys()
+            .chain(other.quest.keys())
+            .copied()
+            .collect::<BTreeSet<_>>();
+        for quest_id in quests {
+            let quest = self.quest.get(&quest_id);
+            let other_quest = other.quest.get(&quest_id);
+            let state = quest.map(|quest| quest.state).unwrap_or_default();
+            let other_state = other_quest.map(|quest| quest.state).unwrap_or_default();
+            if state != other_state {
+                result.push(Difference::QuestState(quest_id, state, other_state));
+            }
+
+            let encounters = |quest: Option<&Quest>| {
+                quest
+                    .into_iter()
+                    .flat_map(|quest| &quest.encounter)
+                    .flat_map(|(location_id, quest_location)| {
+                        quest_location
+                            .keys()
+                            .map(move |encounter_type| (*location_id, *encounter_type))
+                    })
+                    .collect::<BTreeSet<_>>()
+            };
+            let encounters_before = encounters(quest);
+            let encounters_after = encounters(other_quest);
+            for (location_id, encounter_type) in encounters_before.difference(&encounters_after) {
+                result.push(Difference::EncounterRemoved(
+                    quest_id,
+                    *location_id,
+                    *encounter_type,
+                ));
+            }
+            for (location_id, encounter_type) in encounters_after.difference(&encounters_before) {
+                result.push(Difference::EncounterAdded(
+                    quest_id,
+                    *location_id,
+                    *encounter_type,
+                ));
+            }
+
+            if self.note(NoteId::Quest(quest_id)) != other.note(NoteId::Quest(quest_id)) {
+                result.push(Difference::Note(NoteId::Quest(quest_id)));
+            }
+        }
+
+        let locations = self
+            .location
+            .keys()
+            .chain(other.location.keys())
+            .copied()
+            .collect::<BTreeSet<_>>();
+        for location_id in locations {
+            if self.note(NoteId::Location(location_id)) != other.note(NoteId::Location(location_id))
+            {
+                result.push(Difference::Note(NoteId::Location(location_id)));
+            }
+        }
+
+        if self.crew != other.crew {
+            result.push(Difference::Crew);
+        }
+        if self.ledger != other.ledger {
+            result.push(Difference::Ledger);
+        }
+        if self.totem != other.totem {
+            result.push(Difference::Totems);
+        }
+        if self.item != other.item {
+            result.push(Difference::Items);
+        }
+        if self.score_rate != other.score_rate {
+            result.push(Difference::ScoreRates);
+        }
+        if self.session != other.session {
+            result.push(Difference::Sessions);
+        }
+
+        result
+    }
+
+    // applies the choice for a conflict from `merge`
+    pub fn resolve(&mut self, conflict: Conflict, choice: Choice) {
+        match (conflict, choice) {
+            (Conflict::Totem(other), Choice::Other) => {
+                if let Some(totem) = self.totem.iter_mut().find(|totem| totem.name == other.name) {
+                    *totem = other;
+                }
+            }
+            (Conflict::Item(other), Choice::Other) => {
+                if let Some(item) = self.item.iter_mut().find(|item| item.is_same(&other)) {
+                    *item = other;
+                }
+            }
+            (Conflict::Totem(other), Choice::Both) => self.totem.push(other),
+            (Conflict::Item(other), Choice::Both) => self.item.push(other),
+            // there is only one state and prerequisite, keeping both keeps the own one
+            (_, Choice::Own)
+            | (Conflict::QuestState(..) | Conflict::Prerequisite(..), Choice::Both) => (),
+            (Conflict::Note(note_id, other), Choice::Other) => self.set_note(note_id, other),
+            (Conflict::Note(note_id, other), Choice::Both) => {
+                let note = format!("{}\n\n{}", self.note(note_id), other);
+                self.set_note(note_id, note.into());
+            }
+            (Conflict::QuestState(quest_id, state), Choice::Other) => {
+                self.quest.entry(quest_id).or_default().state = state;
+            }
+            (
+                Conflict::Prerequisite(quest_id, location_id, encounter_type, prerequisite),
+                Choice::Other,
+            ) => {
+                if let Some(encounter) = self
+                    .quest
+                    .get_mut(&quest_id)
+                    .and_then(|quest| quest.encounter.get_mut(&location_id))
+                    .and_then(|quest_location| quest_location.get_mut(encounter_type))
+                {
+                    encounter.prerequisite = Some(prerequisite);
+                }
+            }
+        }
+    }
+
+    // the note of a quest or location, empty if there is none
+    #[must_use]
+    pub fn note(&self, id: NoteId) -> &str {
+        match id {
+            NoteId::Quest(quest_id) => self.quest.get(&quest_id).map(|quest| &quest.note),
+            NoteId::Location(location_id) => self.location.get(&location_id),
+        }
+        .map_or("", |note| note)
+    }
+
+    pub fn set_note(&mut self, id: NoteId, note: Note) {
+        match id {
+            NoteId::Quest(quest_id) => self.quest.entry(quest_id).or_default().note = note,
+            NoteId::Location(location_id) => {
+                self.location.insert(location_id, note);
+            }
+        }
+    }
+
+    // all quests with data, sorted by their name
+    pub fn quest_iter<'a>(
+        &'a self,
+        quest_locale: &'a QuestLocale,
+    ) -> impl Iterator<Item = (QuestId, &'a Quest, &'static str)> {
+        quest_locale
+            .all_str()
+            .filter_map(move |(quest_id, quest_name)| {
+                self.quest
+                    .get(&quest_id)
+                    .map(move |quest| (quest_id, quest, quest_name))
+            })
+    }
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum Difference {
+    // before and after
+    QuestState(QuestId, QuestState, QuestState),
+    EncounterAdded(QuestId, LocationId, EncounterType),
+    EncounterRemoved(QuestId, LocationId, EncounterType),
+    Note(NoteId),
+    Crew,
+    Ledger,
+    Totems,
+    Items,
+    ScoreRates,
+    Sessions,
+}
+
+// something which is not the same in both merged journals, with the value of the other journal
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub enum Conflict {
+    Note(NoteId, Note),
+    // completed and lost are as advanced
+    QuestState(QuestId, QuestState),
+    Prerequisite(QuestId, LocationId, EncounterType, Prerequisite),
+    // the same name, but other points, another location or session
+    Totem(Totem),
+    // the same card, but another holder or origin
+    Item(Item),
+}
+
+impl Conflict {
+    #[must_use]
+    pub fn id(&self) -> ConflictId {
+        match self {
+            Conflict::Note(note_id, _) => ConflictId::Note(*note_id),
+            Conflict::QuestState(quest_id, _) => ConflictId::QuestState(*quest_id),
+            Conflict::Prerequisite(quest_id, location_id, encounter_type, _) => {
+                ConflictId::Prerequisite(*quest_id, *location_id, *encounter_type)
+            }
+            Conflict::Totem(totem) => ConflictId::Totem(totem.name.clone()),
+            Conflict::Item(item) => ConflictId::Item(item.kind, item.number, item.name.clone()),
+        }
+    }
+
+    // what can be kept, notes are joined and totems and items are kept twice
+    #[must_use]
+    pub fn choices(&self) -> &'static [Choice] {
+        match self {
+            Conflict::Note(..) | Conflict::Totem(..) | Conflict::Item(..) => {
+                &[Choice::Own, Choice::Other, Choice::Both]
+            }
+            Conflict::QuestState(..) | Conflict::Prerequisite(..) => &[Choice::Own, Choice::Other],
+        }
+    }
+
+    // the choice if none was made: both notes are joined, otherwise the own value is kept
+    #[must_use]
+    pub fn default_choice(&self) -> Choice {
+        match self {
+            Conflict::Note(..) => Choice::Both,
+            Conflict::QuestState(..)
+            | Conflict::Prerequisite(..)
+            | Conflict::Totem(..)
+            | Conflict::Item(..) => Choice::Own,
+        }
+    }
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub enum ConflictId {
+    Note(NoteId),
+    QuestState(QuestId),
+    Prerequisite(QuestId, LocationId, EncounterType),
+    Totem(String),
+    Item(ItemKind, Option<u16>, String),
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub enum NoteId {
+    Quest(QuestId),
+    Location(LocationId),
+}
+
+// which value of a conflict is kept, both notes are joined by an empty line
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum Choice {
+    Own,
+    Other,
+    Both,
+}
+
+// an empty note takes the other one, the other one is returned if both differ
+fn merge_note(note: &mut Note, other: &Note) -> Option<Note> {
+    if other.is_empty() || **note == **other {
+        None
+    } else if note.is_empty() {
+        note.clone_from(other);
+        None
+    } else {
+        Some(other.clone())
+    }
+}
@@ -0,0 +1,21 @@
+#![forbid(unsafe_code)]
+#![deny(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::missing_errors_doc)]
+#![allow(clippy::module_name_repetitions)]
+#![allow(clippy::must_use_candidate)]
+#![allow(clippy::similar_names)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::unsafe_derive_deserialize)]
+
+// The campaign model of the journal: the game content, the quests with their encounters,
+// the rules which encounters are active and the storage formats. Without any UI.
+
+// the dev-dependency on this crate only enables the debug feature for the tests
+#[cfg(test)]
+use sleeping_journal_core as _;
+
+pub mod data;
+pub mod game;
+pub mod journal;
+pub mod ser;
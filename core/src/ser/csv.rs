@@ -0,0 +1,774 @@
+use crate::data::crew::CrewMember;
+use crate::data::encounter_type::EncounterType;
+use crate::data::event::{Event, EventAction};
+use crate::data::item::{Item, ItemKind};
+use crate::data::ledger::{LedgerEntry, Resource};
+use crate::data::prerequisite::Prerequisite;
+use crate::data::quest::QuestState;
+use crate::data::score::{ScorePart, ScoreRate};
+use crate::data::totem::Totem;
+use crate::data::vis::Vis;
+use crate::game::{GameLanguage, LocationId, PackId, QuestId, QuestLocale};
+use crate::journal::Journal;
+use chrono::DateTime;
+use csv::StringRecord;
+use std::collections::HashMap;
+
+const HEADER: [&str; 16] = [
+    "type",
+    "location",
+    "quest",
+    "status",
+    "prerequisite",
+    "visibility",
+    "note",
+    "time",
+    "session",
+    "name",
+    "health",
+    "max health",
+    "fatigue",
+    "level",
+    "amount",
+    "card",
+];
+
+// files from before the event log only have these columns
+const HEADER_REQUIRED: usize = 7;
+
+const TYPES: [(Type, &str); 10] = [
+    (Type::Quest, "quest"),
+    (Type::Location, "location"),
+    (Type::Encounter, "encounter"),
+    (Type::Event, "event"),
+    (Type::Crew, "crew"),
+    (Type::Ledger, "ledger"),
+    (Type::Ship, "ship"),
+    (Type::Totem, "totem"),
+    (Type::Item, "item"),
+    (Type::Score, "score"),
+];
+
+const ENCOUNTER_TYPES: [EncounterType; 5] = [
+    EncounterType::Unless,
+    EncounterType::Gain,
+    EncounterType::When,
+    EncounterType::Complete,
+    EncounterType::Lose,
+];
+
+impl Journal {
+    pub fn save_csv(&self, quest_locale: &QuestLocale) -> Vec<u8> {
+        let mut result = Vec::with_capacity(8 * 1024);
+        let mut writer = csv::Writer::from_writer(&mut result);
+
+        let _ = writer.write_record(HEADER);
+
+        let _ = writer.write_record([
+            "language",
+            "",
+            "",
+            quest_locale.language().as_str(),
+            "",
+            "",
+            "This file is in UTF-8 😀",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+        ]);
+        for (_, quest, quest_name) in self.quest_iter(quest_locale) {
+            if quest.state != QuestState::NotFound
+                || !quest.note.is_empty()
+                || quest.vis != Vis::Visible
+            {
+                let _ = writer.write_record([
+                    "quest",
+                    "",
+                    quest_name,
+                    quest.state.to_csv(),
+                    "",
+                    quest.vis.to_csv(),
+                    &quest.note,
+                    "",
+                    "",
+                    "",
+                    "",
+                    "",
+                    "",
+                    "",
+                    "",
+                    "",
+                ]);
+            }
+        }
+        for location_id in LocationId::all() {
+            if location_id != LocationId::prologue() {
+                if let Some(note) = self.location.get(&location_id) {
+                    if !note.is_empty() {
+                        let _ = writer.write_record([
+                            "location",
+                            location_id.name(quest_locale.language()), // language does not anyway matter as this is never prologue
+                            "",
+                            "",
+                            "",
+                            "",
+                            note,
+                            "",
+                            "",
+                            "",
+                            "",
+                            "",
+                            "",
+                            "",
+                            "",
+                            "",
+                        ]);
+                    }
+                }
+                for (_, quest, quest_name) in self.quest_iter(quest_locale) {
+                    if let Some(quest_location) = quest.encounter.get(&location_id) {
+                        for (encounter_type, encounter) in quest_location {
+                            let _ = writer.write_record([
+                                "encounter",
+                                location_id.name(quest_locale.language()), // language does not matter anyway as this is never prologue
+                                quest_name,
+                                encounter_type.to_csv(),
+                                &encounter
+                                    .prerequisite
+                                    .as_ref()
+                                    .map(|prerequisite| prerequisite.to_csv(quest_locale))
+                                    .unwrap_or_default(),
+                                encounter.vis.to_csv(),
+                                "",
+                                "",
+                                "",
+                                "",
+                                "",
+                                "",
+                                "",
+                                "",
+                                "",
+                                "",
+                            ]);
+                        }
+                    }
+                }
+            }
+        }
+        for event in &self.event {
+            let _ = writer.write_record([
+                "event",
+                event
+                    .location_id
+                    .map_or("", |location_id| location_id.name(quest_locale.language())),
+                event
+                    .quest_id
+                    .map_or("", |quest_id| quest_locale.get(quest_id)),
+                &event.action.to_csv(),
+                "",
+                event.action.vis().to_csv(),
+                "",
+                &DateTime::from_timestamp(event.time, 0)
+                    .unwrap_or_default()
+                    .to_rfc3339(),
+                &event.session.to_string(),
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+            ]);
+        }
+        for crew_member in &self.crew {
+            let _ = writer.write_record([
+                "crew",
+                "",
+                "",
+                "",
+                "",
+                "",
+                &crew_member.injuries,
+                "",
+                "",
+                &crew_member.name,
+                &crew_member.health.to_string(),
+                &crew_member.max_health.to_string(),
+                &crew_member.fatigue.to_string(),
+                &crew_member.level.to_string(),
+                "",
+                "",
+            ]);
+        }
+        for entry in &self.ledger.entry {
+            let _ = writer.write_record([
+                "ledger",
+                entry
+                    .location_id
+                    .map_or("", |location_id| location_id.name(quest_locale.language())),
+                "",
+                entry.resource.to_csv(),
+                "",
+                "",
+                &entry.reason,
+                &DateTime::from_timestamp(entry.time, 0)
+                    .unwrap_or_default()
+                    .to_rfc3339(),
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                &entry.amount.to_string(),
+                "",
+            ]);
+        }
+        for totem in &self.totem {
+            let _ = writer.write_record([
+                "totem",
+                totem
+                    .location_id
+                    .map_or("", |location_id| location_id.name(quest_locale.language())),
+                "",
+                "",
+                "",
+                "",
+                &totem.name,
+                "",
+                &totem.session.to_string(),
+                "",
+                "",
+                "",
+                "",
+                "",
+                &totem.points.to_string(),
+                "",
+            ]);
+        }
+        for item in &self.item {
+            let _ = writer.write_record([
+                "item",
+                item.location_id
+                    .map_or("", |location_id| location_id.name(quest_locale.language())),
+                item.quest_id
+                    .map_or("", |quest_id| quest_locale.get(quest_id)),
+                item.kind.to_csv(),
+                "",
+                "",
+                &item.name,
+                "",
+                "",
+                &item.holder,
+                "",
+                "",
+                "",
+                "",
+                "",
+                &item
+                    .number
+                    .map(|number| number.to_string())
+                    .unwrap_or_default(),
+            ]);
+        }
+        for (part, rate) in &self.score_rate {
+            let _ = writer.write_record([
+                "score",
+                "",
+                "",
+                part.to_csv(),
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                &rate.to_csv(),
+                "",
+            ]);
+        }
+        if let Some(location_id) = self.ledger.location_id {
+            let _ = writer.write_record([
+                "ship",
+                location_id.name(quest_locale.language()),
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+            ]);
+        }
+
+        drop(writer);
+        result
+    }
+
+    // the language of the file is set in the quest locale, invalid rows are skipped and returned
+    pub fn load_csv(
+        &mut self,
+        mut file: &[u8],
+        packs: &[PackId],
+        quest_locale: &mut QuestLocale,
+    ) -> Result<Vec<Rejected>, MyError> {
+        // remove UTF-8 BOM (this *should* never be used, but Microsoft products often do)
+        if let Some(f) = file.strip_prefix(&[0xef, 0xbb, 0xbf]) {
+            file = f;
+        }
+        let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(file);
+
+        // check header
+        let headers = reader.headers()?;
+        for (pos, field) in HEADER.iter().take(HEADER_REQUIRED).enumerate() {
+            if headers.get(pos).unwrap_or_default() != *field {
+                return Err(MyError::Header);
+            }
+        }
+
+        let mut line = StringRecord::new();
+
+        // check language
+        reader.read_record(&mut line)?;
+        if line.get(0).unwrap_or_default() != "language" {
+            return Err(MyError::Language);
+        }
+        let requested_language = line.get(3).unwrap_or_default();
+        let game_language = GameLanguage::iter()
+            .zip(GameLanguage::names())
+            .find(|(_, name)| *name == requested_language)
+            .ok_or(MyError::Language)?
+            .0;
+
+        // reset everything and use the correct language
+        self.reset(packs);
+        quest_locale.set_language(game_language);
+
+        let mut rejected = Vec::new();
+
+        let mut locations = HashMap::with_capacity(LocationId::all().count());
+        for location_id in LocationId::all() {
+            if location_id != LocationId::prologue() {
+                locations.insert(
+                    location_id.name(quest_locale.language()), // language does not matter anyway as this is never prologue
+                    location_id,
+                );
+            }
+        }
+
+        let quests = quest_locale.all_str().map(|(a, b)| (b, a)).collect();
+
+        while reader.read_record(&mut line)? {
+            if let Err(mut err) = self.load_csv3(&line, game_language, &locations, &quests) {
+                if let Some(position) = line.position() {
+                    err.row = position.record() + 1;
+                    rejected.push(err);
+                }
+            }
+        }
+
+        self.cleanup();
+
+        Ok(rejected)
+    }
+
+    fn load_csv3(
+        &mut self,
+        line: &StringRecord,
+        game_language: GameLanguage,
+        locations: &HashMap<&str, LocationId>,
+        quests: &HashMap<&str, QuestId>,
+    ) -> Result<(), Rejected> {
+        let type_ = TYPES
+            .iter()
+            .find(|(_, n)| *n == line.get(0).unwrap_or_default())
+            .ok_or_else(|| reject(line, Reason::Type, TYPES.iter().map(|(_, n)| *n)))?
+            .0;
+
+        let location_name = line.get(1).unwrap_or_default();
+        let location_id = if type_ == Type::Event
+            && location_name == LocationId::prologue().name(game_language)
+        {
+            Some(LocationId::prologue())
+        } else {
+            read(line, 1, locations)
+                .ok_or_else(|| reject(line, Reason::Location, locations.keys().copied()))?
+        };
+        let quest_id = read(line, 2, quests)
+            .ok_or_else(|| reject(line, Reason::Quest, quests.keys().copied()))?;
+        let prerequisite = match line.get(4).unwrap_or_default() {
+            "" => None,
+            prerequisite => Some(Prerequisite::try_from_csv(prerequisite, quests).ok_or_else(
+                || match Prerequisite::unknown_csv_quest(prerequisite, quests) {
+                    Some(name) => Rejected {
+                        suggestion: suggest(&name, quests.keys().copied()),
+                        value: name,
+                        ..reject(line, Reason::PrerequisiteQuest, [])
+                    },
+                    None => reject(line, Reason::Prerequisite, []),
+                },
+            )?),
+        };
+        let vis = Vis::try_from_csv(line.get(5).unwrap_or_default()).ok_or_else(|| {
+            reject(
+                line,
+                Reason::Visibility,
+                [Vis::HiddenThisCampaign, Vis::HiddenForever].map(Vis::to_csv),
+            )
+        })?;
+        let note = line.get(6).unwrap_or_default();
+        match type_ {
+            Type::Quest => {
+                let quest_id = quest_id.ok_or_else(|| reject(line, Reason::MissingQuest, []))?;
+                let state =
+                    QuestState::try_from_csv(line.get(3).unwrap_or_default()).ok_or_else(|| {
+                        reject(
+                            line,
+                            Reason::Status,
+                            [
+                                QuestState::InGame,
+                                QuestState::Completed,
+                                QuestState::Lost,
+                                QuestState::Removed,
+                            ]
+                            .map(QuestState::to_csv),
+                        )
+                    })?;
+                let quest = self.quest.entry(quest_id).or_default();
+                quest.state = state;
+                quest.vis = vis;
+                quest.note = note.to_string().into();
+            }
+            Type::Location => {
+                let location_id =
+                    location_id.ok_or_else(|| reject(line, Reason::MissingLocation, []))?;
+                let location = self.location.entry(location_id).or_default();
+                *location = note.to_string().into();
+            }
+            Type::Encounter => {
+                let location_id =
+                    location_id.ok_or_else(|| reject(line, Reason::MissingLocation, []))?;
+                let quest_id = quest_id.ok_or_else(|| reject(line, Reason::MissingQuest, []))?;
+                let encounter_type = EncounterType::try_from_csv(line.get(3).unwrap_or_default())
+                    .ok_or_else(|| {
+                    reject(
+                        line,
+                        Reason::Status,
+                        ENCOUNTER_TYPES.map(EncounterType::to_csv),
+                    )
+                })?;
+                let quest = self.quest.entry(quest_id).or_default();
+                let quest_location = quest.encounter.entry(location_id).or_default();
+                quest_location.insert(encounter_type, prerequisite, vis);
+            }
+            Type::Event => {
+                let action = EventAction::try_from_csv(line.get(3).unwrap_or_default(), vis)
+                    .ok_or_else(|| {
+                        let actions = ENCOUNTER_TYPES
+                            .iter()
+                            .flat_map(|encounter_type| {
+                                [
+                                    EventAction::Perform(*encounter_type),
+                                    EventAction::Record(*encounter_type),
+                                    EventAction::EncounterVis(*encounter_type, vis),
+                                ]
+                            })
+                            .chain([
+                                EventAction::QuestVis(vis),
+                                EventAction::QuestNote,
+                                EventAction::Edit,
+                                EventAction::NewCampaign,
+                            ])
+                            .map(EventAction::to_csv)
+                            .collect::<Vec<_>>();
+                        reject(line, Reason::Status, actions.iter().map(String::as_str))
+                    })?;
+                let time = DateTime::parse_from_rfc3339(line.get(7).unwrap_or_default())
+                    .map_err(|_| reject(line, Reason::Time, []))?
+                    .timestamp();
+                let session = line
+                    .get(8)
+                    .unwrap_or_default()
+                    .parse()
+                    .map_err(|_| reject(line, Reason::Session, []))?;
+                self.event.push(Event {
+                    time,
+                    session,
+                    action,
+                    quest_id,
+                    location_id,
+                });
+            }
+            Type::Crew => {
+                let number = |reason: Reason| {
+                    line.get(reason.position())
+                        .unwrap_or_default()
+                        .parse()
+                        .map_err(|_| reject(line, reason, []))
+                };
+                let max_health = number(Reason::MaxHealth)?;
+                let health = number(Reason::Health)?;
+                if health > max_health {
+                    return Err(reject(line, Reason::Health, []));
+                }
+                self.crew.push(CrewMember {
+                    name: line.get(9).unwrap_or_default().to_string(),
+                    health,
+                    max_health,
+                    fatigue: number(Reason::Fatigue)?,
+                    level: number(Reason::Level)?,
+                    injuries: note.to_string(),
+                });
+            }
+            Type::Ledger => {
+                let resource =
+                    Resource::try_from_csv(line.get(3).unwrap_or_default()).ok_or_else(|| {
+                        reject(line, Reason::Status, Resource::iter().map(Resource::to_csv))
+                    })?;
+                let time = DateTime::parse_from_rfc3339(line.get(7).unwrap_or_default())
+                    .map_err(|_| reject(line, Reason::Time, []))?
+                    .timestamp();
+                let amount = line
+                    .get(14)
+                    .unwrap_or_default()
+                    .parse()
+                    .map_err(|_| reject(line, Reason::Amount, []))?;
+                self.ledger.entry.push(LedgerEntry {
+                    time,
+                    resource,
+                    amount,
+                    reason: note.to_string(),
+                    location_id,
+                });
+            }
+            Type::Totem => {
+                let session = line
+                    .get(8)
+                    .unwrap_or_default()
+                    .parse()
+                    .map_err(|_| reject(line, Reason::Session, []))?;
+                // files from before the points have none
+                let points = match line.get(14).unwrap_or_default() {
+                    "" => 0,
+                    points => points
+                        .parse()
+                        .map_err(|_| reject(line, Reason::Amount, []))?,
+                };
+                self.totem.push(Totem {
+                    name: note.to_string(),
+                    points,
+                    location_id,
+                    session,
+                });
+            }
+            Type::Item => {
+                let kind =
+                    ItemKind::try_from_csv(line.get(3).unwrap_or_default()).ok_or_else(|| {
+                        reject(line, Reason::Status, ItemKind::iter().map(ItemKind::to_csv))
+                    })?;
+                let number = match line.get(15).unwrap_or_default() {
+                    "" => None,
+                    number => Some(number.parse().map_err(|_| reject(line, Reason::Card, []))?),
+                };
+                self.item.push(Item {
+                    kind,
+                    number,
+                    name: note.to_string(),
+                    holder: line.get(9).unwrap_or_default().to_string(),
+                    location_id,
+                    quest_id,
+                });
+            }
+            Type::Score => {
+                let part =
+                    ScorePart::try_from_csv(line.get(3).unwrap_or_default()).ok_or_else(|| {
+                        reject(
+                            line,
+                            Reason::Status,
+                            ScorePart::iter().map(ScorePart::to_csv),
+                        )
+                    })?;
+                let rate = ScoreRate::try_from_csv(line.get(14).unwrap_or_default())
+                    .ok_or_else(|| reject(line, Reason::Amount, []))?;
+                self.score_rate.insert(part, rate);
+            }
+            Type::Ship => {
+                self.ledger.location_id =
+                    Some(location_id.ok_or_else(|| reject(line, Reason::MissingLocation, []))?);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// a skipped row of the file, the row is counted from 1 and includes the header
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub struct Rejected {
+    pub row: u64,
+    pub reason: Reason,
+    // the content of the cell, for a prerequisite the unknown quest if there is one
+    pub value: String,
+    // the most similar valid content, if there is one close enough
+    pub suggestion: Option<String>,
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum Reason {
+    // the first column
+    Type,
+    Location,
+    Quest,
+    // the status, encounter type or event action, depending on the type
+    Status,
+    // the syntax of the expression is wrong
+    Prerequisite,
+    // the expression is valid, but contains an unknown quest
+    PrerequisiteQuest,
+    Visibility,
+    Time,
+    Session,
+    // the crew values, a number and the health at most the max health
+    Health,
+    MaxHealth,
+    Fatigue,
+    Level,
+    Amount,
+    // the number of an item
+    Card,
+    // the type requires a location or quest, but it's empty
+    MissingLocation,
+    MissingQuest,
+}
+
+impl Reason {
+    // the name of the column in the header
+    pub fn column(self) -> &'static str {
+        HEADER[self.position()]
+    }
+
+    fn position(self) -> usize {
+        match self {
+            Reason::Type => 0,
+            Reason::Location | Reason::MissingLocation => 1,
+            Reason::Quest | Reason::MissingQuest => 2,
+            Reason::Status => 3,
+            Reason::Prerequisite | Reason::PrerequisiteQuest => 4,
+            Reason::Visibility => 5,
+            Reason::Time => 7,
+            Reason::Session => 8,
+            Reason::Health => 10,
+            Reason::MaxHealth => 11,
+            Reason::Fatigue => 12,
+            Reason::Level => 13,
+            Reason::Amount => 14,
+            Reason::Card => 15,
+        }
+    }
+}
+
+pub enum MyError {
+    CsvError,
+    Header,
+    Language,
+}
+
+impl From<csv::Error> for MyError {
+    fn from(_: csv::Error) -> Self {
+        MyError::CsvError
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Type {
+    Quest,
+    Location,
+    Encounter,
+    Event,
+    Crew,
+    Ledger,
+    Ship,
+    Totem,
+    Item,
+    Score,
+}
+
+#[allow(clippy::option_option)]
+fn read<T>(line: &StringRecord, column: usize, map: &HashMap<&str, T>) -> Option<Option<T>>
+where
+    T: Copy,
+{
+    let input = line.get(column).unwrap_or_default();
+    if input.is_empty() {
+        return Some(None);
+    }
+    map.get(input).copied().map(Some)
+}
+
+fn reject<'a, I>(line: &StringRecord, reason: Reason, candidates: I) -> Rejected
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let value = line.get(reason.position()).unwrap_or_default().to_string();
+    Rejected {
+        row: 0,
+        reason,
+        suggestion: suggest(&value, candidates),
+        value,
+    }
+}
+
+// the candidate with the smallest edit distance, ignoring the case.
+// Short inputs only match without the case, longer ones may have a typo in every third character.
+fn suggest<'a, I>(input: &str, candidates: I) -> Option<String>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let input = input.trim().to_lowercase().chars().collect::<Vec<_>>();
+    let max = input.len() / 3;
+    candidates
+        .into_iter()
+        .filter(|candidate| !candidate.is_empty())
+        .map(|candidate| (distance(&input, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max)
+        .min()
+        .map(|(_, candidate)| candidate.to_string())
+}
+
+// the Levenshtein distance
+fn distance(a: &[char], b: &str) -> usize {
+    let b = b.to_lowercase().chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = (diagonal + usize::from(ca != cb))
+                .min(row[j] + 1)
+                .min(above + 1);
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
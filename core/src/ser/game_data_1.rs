@@ -1,7 +1,8 @@
 use crate::data::encounter_type::EncounterType;
 use crate::data::quest::QuestState;
 use crate::data::vis::Vis;
-use crate::global::data::Data;
+use crate::game::PackId;
+use crate::journal::Journal;
 use crate::ser::game_data_3::{SerdeEncounter3, SerdeGameData3, SerdeLocation3, SerdeQuest3};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
@@ -18,15 +19,15 @@ struct SerdeEncounter(BTreeMap<EncounterType, (Option<usize>, Vis)>);
 
 #[derive(Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
-pub(crate) struct SerdeGameData1<'a> {
+pub struct SerdeGameData1<'a> {
     version_1: (),
     quests: BTreeMap<usize, SerdeQuest<'a>>,
     locations: BTreeMap<usize, SerdeLocation<'a>>,
     game_language: String,
 }
 
-impl Data {
-    pub(crate) fn load_game_data_1(&mut self, game_data: SerdeGameData1) {
+impl Journal {
+    pub fn load_game_data_1(&mut self, game_data: SerdeGameData1, packs: &[PackId]) {
         let SerdeGameData1 {
             quests, locations, ..
         } = game_data;
@@ -55,6 +56,6 @@ impl Data {
                 .collect(),
         };
 
-        self.load_game_data_3(game_data);
+        self.load_game_data_3(game_data, packs);
     }
 }
@@ -1,7 +1,8 @@
 use crate::data::encounter_type::EncounterType;
 use crate::data::quest::QuestState;
 use crate::data::vis::Vis;
-use crate::global::data::Data;
+use crate::game::PackId;
+use crate::journal::Journal;
 use crate::ser::game_data_3::{SerdeEncounter3, SerdeGameData3, SerdeLocation3, SerdeQuest3};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
@@ -23,15 +24,15 @@ struct SerdeEncounter(BTreeMap<EncounterType, (Option<usize>, Vis)>);
 
 #[derive(Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
-pub(crate) struct SerdeGameData2<'a> {
+pub struct SerdeGameData2<'a> {
     version_2: (),
     quests: BTreeMap<usize, SerdeQuest<'a>>,
     locations: BTreeMap<usize, SerdeLocation<'a>>,
     game_language: String,
 }
 
-impl Data {
-    pub(crate) fn load_game_data_2(&mut self, game_data: SerdeGameData2) {
+impl Journal {
+    pub fn load_game_data_2(&mut self, game_data: SerdeGameData2, packs: &[PackId]) {
         let SerdeGameData2 {
             quests, locations, ..
         } = game_data;
@@ -59,6 +60,6 @@ impl Data {
                 .collect(),
         };
 
-        self.load_game_data_3(game_data);
+        self.load_game_data_3(game_data, packs);
     }
 }
@@ -0,0 +1,65 @@
+use crate::data::encounter_type::EncounterType;
+use crate::data::quest::QuestState;
+use crate::data::vis::Vis;
+use crate::game::PackId;
+use crate::journal::Journal;
+use crate::ser::game_data_4::{SerdeEncounter4, SerdeGameData4, SerdeLocation4, SerdeQuest4};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+#[derive(Serialize, Deserialize)]
+pub struct SerdeQuest3<'a>(
+    pub QuestState,
+    pub BTreeMap<usize, SerdeEncounter3>,
+    pub Cow<'a, str>,
+    pub Vis,
+);
+
+#[derive(Serialize, Deserialize)]
+pub struct SerdeLocation3<'a>(pub Cow<'a, str>);
+
+#[derive(Serialize, Deserialize)]
+pub struct SerdeEncounter3(pub BTreeMap<EncounterType, (Option<usize>, Vis)>);
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SerdeGameData3<'a> {
+    pub version_3: (),
+    pub quests: BTreeMap<usize, SerdeQuest3<'a>>,
+    pub locations: BTreeMap<usize, SerdeLocation3<'a>>,
+}
+
+impl Journal {
+    pub fn load_game_data_3(&mut self, game_data: SerdeGameData3, packs: &[PackId]) {
+        let SerdeGameData3 {
+            quests, locations, ..
+        } = game_data;
+        let game_data = SerdeGameData4 {
+            version_4: (),
+            quests: quests
+                .into_iter()
+                .map(|(k, v)| {
+                    (
+                        k,
+                        SerdeQuest4(
+                            v.0,
+                            v.1.into_iter()
+                                .map(|(k2, v2)| (k2, SerdeEncounter4(v2.0)))
+                                .collect(),
+                            v.2,
+                            v.3,
+                        ),
+                    )
+                })
+                .collect(),
+            locations: locations
+                .into_iter()
+                .map(|(a, b)| (a, SerdeLocation4(b.0)))
+                .collect(),
+            events: Vec::new(),
+        };
+
+        self.load_game_data_4(game_data, packs);
+    }
+}
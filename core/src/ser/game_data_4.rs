@@ -0,0 +1,96 @@
+use crate::data::encounter_type::EncounterType;
+use crate::data::event::EventAction;
+use crate::data::quest::QuestState;
+use crate::data::vis::Vis;
+use crate::game::PackId;
+use crate::journal::Journal;
+use crate::ser::game_data_5::{
+    SerdeEncounter5, SerdeEvent5, SerdeGameData5, SerdeLocation5, SerdeQuest5,
+};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+#[derive(Serialize, Deserialize)]
+pub struct SerdeQuest4<'a>(
+    pub QuestState,
+    pub BTreeMap<usize, SerdeEncounter4>,
+    pub Cow<'a, str>,
+    pub Vis,
+);
+
+#[derive(Serialize, Deserialize)]
+pub struct SerdeLocation4<'a>(pub Cow<'a, str>);
+
+#[derive(Serialize, Deserialize)]
+pub struct SerdeEncounter4(pub BTreeMap<EncounterType, (Option<usize>, Vis)>);
+
+#[derive(Serialize, Deserialize)]
+pub struct SerdeEvent4(
+    pub i64,
+    pub u32,
+    pub EventAction,
+    pub Option<usize>,
+    pub Option<usize>,
+);
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SerdeGameData4<'a> {
+    pub version_4: (),
+    pub quests: BTreeMap<usize, SerdeQuest4<'a>>,
+    pub locations: BTreeMap<usize, SerdeLocation4<'a>>,
+    pub events: Vec<SerdeEvent4>,
+}
+
+impl Journal {
+    pub fn load_game_data_4(&mut self, game_data: SerdeGameData4, packs: &[PackId]) {
+        let SerdeGameData4 {
+            quests,
+            locations,
+            events,
+            ..
+        } = game_data;
+        let game_data = SerdeGameData5 {
+            version_5: (),
+            quests: quests
+                .into_iter()
+                .map(|(k, v)| {
+                    (
+                        k,
+                        SerdeQuest5(
+                            v.0,
+                            v.1.into_iter()
+                                .map(|(k2, v2)| {
+                                    (
+                                        k2,
+                                        // a single prerequisite is a valid expression with the raw id
+                                        SerdeEncounter5(
+                                            v2.0.into_iter()
+                                                .map(|(et, (p, vis))| {
+                                                    (et, (p.map(|p| p.to_string()), vis))
+                                                })
+                                                .collect(),
+                                        ),
+                                    )
+                                })
+                                .collect(),
+                            v.2,
+                            v.3,
+                        ),
+                    )
+                })
+                .collect(),
+            locations: locations
+                .into_iter()
+                .map(|(a, b)| (a, SerdeLocation5(b.0)))
+                .collect(),
+            events: events
+                .into_iter()
+                .map(|e| SerdeEvent5(e.0, e.1, e.2, e.3, e.4))
+                .collect(),
+        };
+
+        self.load_game_data_5(game_data, packs);
+    }
+}
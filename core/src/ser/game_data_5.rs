@@ -0,0 +1,102 @@
+use crate::data::encounter_type::EncounterType;
+use crate::data::event::EventAction;
+use crate::data::quest::QuestState;
+use crate::data::vis::Vis;
+use crate::game::PackId;
+use crate::journal::Journal;
+use crate::ser::game_data_6::{
+    SerdeEncounter6, SerdeEvent6, SerdeGameData6, SerdeLocation6, SerdeQuest6,
+};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+#[derive(Serialize, Deserialize)]
+pub struct SerdeQuest5<'a>(
+    pub QuestState,
+    pub BTreeMap<usize, SerdeEncounter5>,
+    pub Cow<'a, str>,
+    pub Vis,
+);
+
+#[derive(Serialize, Deserialize)]
+pub struct SerdeLocation5<'a>(pub Cow<'a, str>);
+
+#[derive(Serialize, Deserialize)]
+pub struct SerdeEncounter5(pub BTreeMap<EncounterType, (Option<String>, Vis)>);
+
+#[derive(Serialize, Deserialize)]
+pub struct SerdeEvent5(
+    pub i64,
+    pub u32,
+    pub EventAction,
+    pub Option<usize>,
+    pub Option<usize>,
+);
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SerdeGameData5<'a> {
+    pub version_5: (),
+    pub quests: BTreeMap<usize, SerdeQuest5<'a>>,
+    pub locations: BTreeMap<usize, SerdeLocation5<'a>>,
+    pub events: Vec<SerdeEvent5>,
+}
+
+impl Journal {
+    pub fn load_game_data_5(&mut self, game_data: SerdeGameData5, packs: &[PackId]) {
+        let SerdeGameData5 {
+            quests,
+            locations,
+            events,
+            ..
+        } = game_data;
+        let game_data = SerdeGameData6 {
+            version_6: (),
+            quests: quests
+                .into_iter()
+                .map(|(k, v)| {
+                    (
+                        k,
+                        SerdeQuest6(
+                            v.0,
+                            v.1.into_iter()
+                                .map(|(k2, v2)| {
+                                    (
+                                        k2,
+                                        // up to this version only prerequisites of gains were used
+                                        SerdeEncounter6(
+                                            v2.0.into_iter()
+                                                .map(|(et, (p, vis))| {
+                                                    (
+                                                        et,
+                                                        (
+                                                            p.filter(|_| et == EncounterType::Gain),
+                                                            vis,
+                                                        ),
+                                                    )
+                                                })
+                                                .collect(),
+                                        ),
+                                    )
+                                })
+                                .collect(),
+                            v.2,
+                            v.3,
+                        ),
+                    )
+                })
+                .collect(),
+            locations: locations
+                .into_iter()
+                .map(|(a, b)| (a, SerdeLocation6(b.0)))
+                .collect(),
+            events: events
+                .into_iter()
+                .map(|e| SerdeEvent6(e.0, e.1, e.2, e.3, e.4))
+                .collect(),
+        };
+
+        self.load_game_data_6(game_data, packs);
+    }
+}
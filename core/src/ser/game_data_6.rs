@@ -0,0 +1,109 @@
+use crate::data::encounter_type::EncounterType;
+use crate::data::event::EventAction;
+use crate::data::quest::QuestState;
+use crate::data::vis::Vis;
+use crate::game::PackId;
+use crate::journal::Journal;
+use crate::ser::game_data_7::{
+    SerdeEncounter7, SerdeEvent7, SerdeGameData7, SerdeLocation7, SerdeQuest7,
+};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+#[derive(Serialize, Deserialize)]
+pub struct SerdeQuest6<'a>(
+    pub QuestState,
+    pub BTreeMap<usize, SerdeEncounter6>,
+    pub Cow<'a, str>,
+    pub Vis,
+);
+
+#[derive(Serialize, Deserialize)]
+pub struct SerdeLocation6<'a>(pub Cow<'a, str>);
+
+#[derive(Serialize, Deserialize)]
+pub struct SerdeEncounter6(pub BTreeMap<EncounterType, (Option<String>, Vis)>);
+
+#[derive(Serialize, Deserialize)]
+pub struct SerdeEvent6(
+    pub i64,
+    pub u32,
+    pub EventAction,
+    pub Option<usize>,
+    pub Option<usize>,
+);
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SerdeGameData6<'a> {
+    pub version_6: (),
+    pub quests: BTreeMap<usize, SerdeQuest6<'a>>,
+    pub locations: BTreeMap<usize, SerdeLocation6<'a>>,
+    pub events: Vec<SerdeEvent6>,
+}
+
+impl Journal {
+    pub fn load_game_data_6(&mut self, game_data: SerdeGameData6, packs: &[PackId]) {
+        let SerdeGameData6 {
+            quests,
+            locations,
+            events,
+            ..
+        } = game_data;
+
+        // up to this version completed and lost quests were just removed,
+        // the last completion or loss performed in the event log tells which one it was,
+        // a recorded encounter was only added to the quest
+        let mut done = BTreeMap::new();
+        for SerdeEvent6(_, _, action, raw_quest_id, _) in &events {
+            if let (EventAction::Perform(encounter_type), Some(raw_quest_id)) =
+                (action, raw_quest_id)
+            {
+                match encounter_type {
+                    EncounterType::Complete => {
+                        done.insert(*raw_quest_id, QuestState::Completed);
+                    }
+                    EncounterType::Lose => {
+                        done.insert(*raw_quest_id, QuestState::Lost);
+                    }
+                    EncounterType::Gain | EncounterType::When | EncounterType::Unless => (),
+                }
+            }
+        }
+
+        let game_data = SerdeGameData7 {
+            version_7: (),
+            quests: quests
+                .into_iter()
+                .map(|(k, v)| {
+                    let state = match v.0 {
+                        QuestState::Removed => done.get(&k).copied().unwrap_or(QuestState::Removed),
+                        state => state,
+                    };
+                    (
+                        k,
+                        SerdeQuest7(
+                            state,
+                            v.1.into_iter()
+                                .map(|(k2, v2)| (k2, SerdeEncounter7(v2.0)))
+                                .collect(),
+                            v.2,
+                            v.3,
+                        ),
+                    )
+                })
+                .collect(),
+            locations: locations
+                .into_iter()
+                .map(|(a, b)| (a, SerdeLocation7(b.0)))
+                .collect(),
+            events: events
+                .into_iter()
+                .map(|e| SerdeEvent7(e.0, e.1, e.2, e.3, e.4))
+                .collect(),
+        };
+
+        self.load_game_data_7(game_data, packs);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::data::encounter_type::EncounterType;
+use crate::data::event::EventAction;
+use crate::data::quest::QuestState;
+use crate::data::vis::Vis;
+use crate::game::PackId;
+use crate::journal::Journal;
+use crate::ser::game_data_8::{
+    SerdeEncounter8, SerdeEvent8, SerdeGameData8, SerdeLocation8, SerdeQuest8,
+};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+#[derive(Serialize, Deserialize)]
+pub struct SerdeQuest7<'a>(
+    pub QuestState,
+    pub BTreeMap<usize, SerdeEncounter7>,
+    pub Cow<'a, str>,
+    pub Vis,
+);
+
+#[derive(Serialize, Deserialize)]
+pub struct SerdeLocation7<'a>(pub Cow<'a, str>);
+
+#[derive(Serialize, Deserialize)]
+pub struct SerdeEncounter7(pub BTreeMap<EncounterType, (Option<String>, Vis)>);
+
+#[derive(Serialize, Deserialize)]
+pub struct SerdeEvent7(
+    pub i64,
+    pub u32,
+    pub EventAction,
+    pub Option<usize>,
+    pub Option<usize>,
+);
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SerdeGameData7<'a> {
+    pub version_7: (),
+    pub quests: BTreeMap<usize, SerdeQuest7<'a>>,
+    pub locations: BTreeMap<usize, SerdeLocation7<'a>>,
+    pub events: Vec<SerdeEvent7>,
+}
+
+impl Journal {
+    pub fn load_game_data_7(&mut self, game_data: SerdeGameData7, packs: &[PackId]) {
+        // up to this version there was no crew, ledger, totems, items, score rates or sessions
+        let game_data = SerdeGameData8 {
+            version_8: (),
+            quests: game_data
+                .quests
+                .into_iter()
+                .map(|(k, v)| {
+                    (
+                        k,
+                        SerdeQuest8(
+                            v.0,
+                            v.1.into_iter()
+                                .map(|(k2, v2)| (k2, SerdeEncounter8(v2.0)))
+                                .collect(),
+                            v.2,
+                            v.3,
+                        ),
+                    )
+                })
+                .collect(),
+            locations: game_data
+                .locations
+                .into_iter()
+                .map(|(a, b)| (a, SerdeLocation8(b.0)))
+                .collect(),
+            events: game_data
+                .events
+                .into_iter()
+                .map(|e| SerdeEvent8(e.0, e.1, e.2, e.3, e.4))
+                .collect(),
+            crew: Vec::new(),
+            ledger: Vec::new(),
+            ship_location: None,
+            totems: Vec::new(),
+            items: Vec::new(),
+            score_rates: Vec::new(),
+            session_start: None,
+            sessions: Vec::new(),
+        };
+
+        self.load_game_data_8(game_data, packs);
+    }
+}
@@ -0,0 +1,566 @@
+use crate::data::crew::CrewMember;
+use crate::data::encounter_type::EncounterType;
+use crate::data::event::{Event, EventAction};
+use crate::data::item::{Item, ItemKind};
+use crate::data::ledger::{Ledger, LedgerEntry, Resource};
+use crate::data::note::Note;
+use crate::data::prerequisite::Prerequisite;
+use crate::data::quest::{Quest, QuestState};
+use crate::data::quest_location::QuestLocation;
+use crate::data::score::{ScorePart, ScoreRate};
+use crate::data::session::{SessionStart, SessionSummary};
+use crate::data::totem::Totem;
+use crate::data::vis::Vis;
+use crate::game::{LocationId, PackId, QuestId};
+use crate::journal::{Journal, NoteId};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SerdeQuest8<'a>(
+    pub QuestState,
+    pub BTreeMap<usize, SerdeEncounter8>,
+    pub Cow<'a, str>,
+    pub Vis,
+);
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SerdeLocation8<'a>(pub Cow<'a, str>);
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SerdeEncounter8(pub BTreeMap<EncounterType, (Option<String>, Vis)>);
+
+// name, health, max health, fatigue, level and injuries
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SerdeCrew8<'a>(
+    pub Cow<'a, str>,
+    pub u8,
+    pub u8,
+    pub u8,
+    pub u8,
+    pub Cow<'a, str>,
+);
+
+// time, resource, amount, reason and location
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SerdeLedger8<'a>(
+    pub i64,
+    pub Resource,
+    pub i32,
+    pub Cow<'a, str>,
+    pub Option<usize>,
+);
+
+// name, points, location and session
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SerdeTotem8<'a>(pub Cow<'a, str>, pub u8, pub Option<usize>, pub u32);
+
+// kind, number, name, holder, location and quest
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SerdeItem8<'a>(
+    pub ItemKind,
+    pub Option<u16>,
+    pub Cow<'a, str>,
+    pub Cow<'a, str>,
+    pub Option<usize>,
+    pub Option<usize>,
+);
+
+// part, points and per
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SerdeScoreRate8(pub ScorePart, pub i32, pub i32);
+
+// time, quests and locations
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SerdeSessionStart8<'a>(
+    pub i64,
+    pub BTreeMap<usize, SerdeQuest8<'a>>,
+    pub BTreeMap<usize, SerdeLocation8<'a>>,
+);
+
+// start, end, quests gained, completed and lost, encounters, quest notes, location notes and
+// locations visited
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SerdeSession8(
+    pub i64,
+    pub i64,
+    pub Vec<usize>,
+    pub Vec<usize>,
+    pub Vec<usize>,
+    pub Vec<(usize, usize, EncounterType)>,
+    pub Vec<usize>,
+    pub Vec<usize>,
+    pub Vec<usize>,
+);
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SerdeEvent8(
+    pub i64,
+    pub u32,
+    pub EventAction,
+    pub Option<usize>,
+    pub Option<usize>,
+);
+
+// quests and locations are stored by their stable raw id, not by their table index
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SerdeGameData8<'a> {
+    pub version_8: (),
+    pub quests: BTreeMap<usize, SerdeQuest8<'a>>,
+    pub locations: BTreeMap<usize, SerdeLocation8<'a>>,
+    pub events: Vec<SerdeEvent8>,
+    pub crew: Vec<SerdeCrew8<'a>>,
+    // the following fields were added to this version later, older saves don't have them
+    #[serde(default)]
+    pub ledger: Vec<SerdeLedger8<'a>>,
+    #[serde(default)]
+    pub ship_location: Option<usize>,
+    #[serde(default)]
+    pub totems: Vec<SerdeTotem8<'a>>,
+    #[serde(default)]
+    pub items: Vec<SerdeItem8<'a>>,
+    #[serde(default)]
+    pub score_rates: Vec<SerdeScoreRate8>,
+    #[serde(default)]
+    pub session_start: Option<SerdeSessionStart8<'a>>,
+    #[serde(default)]
+    pub sessions: Vec<SerdeSession8>,
+}
+
+impl SerdeGameData8<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> SerdeGameData8<'static> {
+        SerdeGameData8 {
+            version_8: (),
+            quests: owned_quests(self.quests),
+            locations: owned_locations(self.locations),
+            events: self.events,
+            crew: self
+                .crew
+                .into_iter()
+                .map(|v| {
+                    SerdeCrew8(
+                        Cow::Owned(v.0.into_owned()),
+                        v.1,
+                        v.2,
+                        v.3,
+                        v.4,
+                        Cow::Owned(v.5.into_owned()),
+                    )
+                })
+                .collect(),
+            ledger: self
+                .ledger
+                .into_iter()
+                .map(|v| SerdeLedger8(v.0, v.1, v.2, Cow::Owned(v.3.into_owned()), v.4))
+                .collect(),
+            ship_location: self.ship_location,
+            totems: self
+                .totems
+                .into_iter()
+                .map(|v| SerdeTotem8(Cow::Owned(v.0.into_owned()), v.1, v.2, v.3))
+                .collect(),
+            items: self
+                .items
+                .into_iter()
+                .map(|v| {
+                    SerdeItem8(
+                        v.0,
+                        v.1,
+                        Cow::Owned(v.2.into_owned()),
+                        Cow::Owned(v.3.into_owned()),
+                        v.4,
+                        v.5,
+                    )
+                })
+                .collect(),
+            score_rates: self.score_rates,
+            session_start: self
+                .session_start
+                .map(|v| SerdeSessionStart8(v.0, owned_quests(v.1), owned_locations(v.2))),
+            sessions: self.sessions,
+        }
+    }
+}
+
+fn owned_quests(quests: BTreeMap<usize, SerdeQuest8>) -> BTreeMap<usize, SerdeQuest8<'static>> {
+    quests
+        .into_iter()
+        .map(|(k, v)| (k, SerdeQuest8(v.0, v.1, Cow::Owned(v.2.into_owned()), v.3)))
+        .collect()
+}
+
+fn owned_locations(
+    locations: BTreeMap<usize, SerdeLocation8>,
+) -> BTreeMap<usize, SerdeLocation8<'static>> {
+    locations
+        .into_iter()
+        .map(|(k, v)| (k, SerdeLocation8(Cow::Owned(v.0.into_owned()))))
+        .collect()
+}
+
+impl Journal {
+    fn save_encounter(encounters: &QuestLocation) -> SerdeEncounter8 {
+        SerdeEncounter8(
+            encounters
+                .iter()
+                .map(|(et, quest_location_encounter)| {
+                    (
+                        *et,
+                        (
+                            quest_location_encounter
+                                .prerequisite
+                                .as_ref()
+                                .map(Prerequisite::to_storage),
+                            quest_location_encounter.vis,
+                        ),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    fn save_quest(quest: &Quest) -> SerdeQuest8<'_> {
+        SerdeQuest8(
+            quest.state,
+            quest
+                .encounter
+                .iter()
+                .filter(|(location_id, _)| **location_id != LocationId::prologue())
+                .map(|(location_id, encounters)| {
+                    (location_id.raw(), Self::save_encounter(encounters))
+                })
+                .collect(),
+            Cow::Borrowed(&quest.note),
+            quest.vis,
+        )
+    }
+
+    fn save_quests(quest: &HashMap<QuestId, Quest>) -> BTreeMap<usize, SerdeQuest8<'_>> {
+        quest
+            .iter()
+            .map(|(quest_id, quest)| (quest_id.raw(), Self::save_quest(quest)))
+            .collect()
+    }
+
+    fn save_locations(location: &HashMap<LocationId, Note>) -> BTreeMap<usize, SerdeLocation8<'_>> {
+        location
+            .iter()
+            .map(|(l, n)| (l.raw(), SerdeLocation8(Cow::Borrowed(n))))
+            .collect()
+    }
+
+    fn save_session(summary: &SessionSummary) -> SerdeSession8 {
+        let raw = |quest_ids: &[QuestId]| quest_ids.iter().map(|quest_id| quest_id.raw()).collect();
+        SerdeSession8(
+            summary.start,
+            summary.end,
+            raw(&summary.quest_gained),
+            raw(&summary.quest_completed),
+            raw(&summary.quest_lost),
+            summary
+                .encounter
+                .iter()
+                .map(|(quest_id, location_id, encounter_type)| {
+                    (quest_id.raw(), location_id.raw(), *encounter_type)
+                })
+                .collect(),
+            summary
+                .note
+                .iter()
+                .filter_map(|note_id| match note_id {
+                    NoteId::Quest(quest_id) => Some(quest_id.raw()),
+                    NoteId::Location(_) => None,
+                })
+                .collect(),
+            summary
+                .note
+                .iter()
+                .filter_map(|note_id| match note_id {
+                    NoteId::Quest(_) => None,
+                    NoteId::Location(location_id) => Some(location_id.raw()),
+                })
+                .collect(),
+            summary
+                .location
+                .iter()
+                .map(|location_id| location_id.raw())
+                .collect(),
+        )
+    }
+
+    pub fn save_game_data(&self) -> SerdeGameData8<'_> {
+        SerdeGameData8 {
+            version_8: (),
+            quests: Self::save_quests(&self.quest),
+            locations: Self::save_locations(&self.location),
+            events: self
+                .event
+                .iter()
+                .map(|event| {
+                    SerdeEvent8(
+                        event.time,
+                        event.session,
+                        event.action,
+                        event.quest_id.map(QuestId::raw),
+                        event.location_id.map(LocationId::raw),
+                    )
+                })
+                .collect(),
+            crew: self
+                .crew
+                .iter()
+                .map(|crew_member| {
+                    SerdeCrew8(
+                        Cow::Borrowed(&crew_member.name),
+                        crew_member.health,
+                        crew_member.max_health,
+                        crew_member.fatigue,
+                        crew_member.level,
+                        Cow::Borrowed(&crew_member.injuries),
+                    )
+                })
+                .collect(),
+            ledger: self
+                .ledger
+                .entry
+                .iter()
+                .map(|entry| {
+                    SerdeLedger8(
+                        entry.time,
+                        entry.resource,
+                        entry.amount,
+                        Cow::Borrowed(&entry.reason),
+                        entry.location_id.map(LocationId::raw),
+                    )
+                })
+                .collect(),
+            ship_location: self.ledger.location_id.map(LocationId::raw),
+            totems: self
+                .totem
+                .iter()
+                .map(|totem| {
+                    SerdeTotem8(
+                        Cow::Borrowed(&totem.name),
+                        totem.points,
+                        totem.location_id.map(LocationId::raw),
+                        totem.session,
+                    )
+                })
+                .collect(),
+            items: self
+                .item
+                .iter()
+                .map(|item| {
+                    SerdeItem8(
+                        item.kind,
+                        item.number,
+                        Cow::Borrowed(&item.name),
+                        Cow::Borrowed(&item.holder),
+                        item.location_id.map(LocationId::raw),
+                        item.quest_id.map(QuestId::raw),
+                    )
+                })
+                .collect(),
+            score_rates: self
+                .score_rate
+                .iter()
+                .map(|(part, rate)| SerdeScoreRate8(*part, rate.points, rate.per))
+                .collect(),
+            session_start: self.session_start.as_ref().map(|start| {
+                SerdeSessionStart8(
+                    start.time,
+                    Self::save_quests(&start.quest),
+                    Self::save_locations(&start.location),
+                )
+            }),
+            sessions: self.session.iter().map(Self::save_session).collect(),
+        }
+    }
+
+    fn load_locations(
+        location: &mut HashMap<LocationId, Note>,
+        locations: BTreeMap<usize, SerdeLocation8>,
+    ) {
+        for (raw_location_id, data) in locations {
+            if let Some(location_id) = LocationId::from_raw(raw_location_id) {
+                location.insert(location_id, data.0.to_string().into());
+            }
+        }
+    }
+
+    fn load_quests(quest: &mut HashMap<QuestId, Quest>, quests: BTreeMap<usize, SerdeQuest8>) {
+        for (raw_quest_id, data) in quests {
+            if let Some(quest_id) = QuestId::from_raw(raw_quest_id) {
+                let quest = quest.entry(quest_id).or_default();
+                quest.state = data.0;
+                for (raw_location_id, e_data) in data.1 {
+                    if let Some(location_id) = LocationId::from_raw(raw_location_id) {
+                        let encounters = quest.encounter.entry(location_id).or_default();
+                        for (encounter_type, (raw_prerequisite, vis)) in e_data.0 {
+                            // an unknown expression (e.g. from a newer version) is dropped
+                            let prerequisite = raw_prerequisite
+                                .as_deref()
+                                .and_then(Prerequisite::try_from_storage);
+                            encounters.insert(encounter_type, prerequisite, vis);
+                        }
+                    }
+                }
+                quest.note = data.2.to_string().into();
+                quest.vis = data.3;
+            }
+        }
+    }
+
+    // unknown ids are dropped
+    fn load_session(session: SerdeSession8) -> SessionSummary {
+        let quests = |raw: Vec<usize>| raw.into_iter().filter_map(QuestId::from_raw).collect();
+        SessionSummary {
+            start: session.0,
+            end: session.1,
+            quest_gained: quests(session.2),
+            quest_completed: quests(session.3),
+            quest_lost: quests(session.4),
+            encounter: session
+                .5
+                .into_iter()
+                .filter_map(|(raw_quest_id, raw_location_id, encounter_type)| {
+                    Some((
+                        QuestId::from_raw(raw_quest_id)?,
+                        LocationId::from_raw(raw_location_id)?,
+                        encounter_type,
+                    ))
+                })
+                .collect(),
+            note: session
+                .6
+                .into_iter()
+                .filter_map(QuestId::from_raw)
+                .map(NoteId::Quest)
+                .chain(
+                    session
+                        .7
+                        .into_iter()
+                        .filter_map(LocationId::from_raw)
+                        .map(NoteId::Location),
+                )
+                .collect(),
+            location: session
+                .8
+                .into_iter()
+                .filter_map(LocationId::from_raw)
+                .collect(),
+        }
+    }
+
+    pub fn load_game_data_8(&mut self, game_data: SerdeGameData8, packs: &[PackId]) {
+        self.reset(packs);
+
+        Self::load_locations(&mut self.location, game_data.locations);
+        Self::load_quests(&mut self.quest, game_data.quests);
+
+        self.event = game_data
+            .events
+            .into_iter()
+            .map(
+                |SerdeEvent8(time, session, action, raw_quest_id, raw_location_id)| Event {
+                    time,
+                    session,
+                    action,
+                    quest_id: raw_quest_id.and_then(QuestId::from_raw),
+                    location_id: raw_location_id.and_then(LocationId::from_raw),
+                },
+            )
+            .collect();
+
+        self.crew = game_data
+            .crew
+            .into_iter()
+            .map(
+                |SerdeCrew8(name, health, max_health, fatigue, level, injuries)| CrewMember {
+                    name: name.into_owned(),
+                    health,
+                    max_health,
+                    fatigue,
+                    level,
+                    injuries: injuries.into_owned(),
+                },
+            )
+            .collect();
+
+        self.ledger = Ledger {
+            entry: game_data
+                .ledger
+                .into_iter()
+                .map(
+                    |SerdeLedger8(time, resource, amount, reason, raw_location_id)| LedgerEntry {
+                        time,
+                        resource,
+                        amount,
+                        reason: reason.into_owned(),
+                        location_id: raw_location_id.and_then(LocationId::from_raw),
+                    },
+                )
+                .collect(),
+            location_id: game_data.ship_location.and_then(LocationId::from_raw),
+        };
+
+        self.totem = game_data
+            .totems
+            .into_iter()
+            .map(
+                |SerdeTotem8(name, points, raw_location_id, session)| Totem {
+                    name: name.into_owned(),
+                    points,
+                    location_id: raw_location_id.and_then(LocationId::from_raw),
+                    session,
+                },
+            )
+            .collect();
+
+        self.item = game_data
+            .items
+            .into_iter()
+            .map(
+                |SerdeItem8(kind, number, name, holder, raw_location_id, raw_quest_id)| Item {
+                    kind,
+                    number,
+                    name: name.into_owned(),
+                    holder: holder.into_owned(),
+                    location_id: raw_location_id.and_then(LocationId::from_raw),
+                    quest_id: raw_quest_id.and_then(QuestId::from_raw),
+                },
+            )
+            .collect();
+
+        self.score_rate = game_data
+            .score_rates
+            .into_iter()
+            .filter(|SerdeScoreRate8(_, _, per)| *per > 0)
+            .map(|SerdeScoreRate8(part, points, per)| (part, ScoreRate { points, per }))
+            .collect();
+
+        // loaded like the journal, thus the same quests are compared
+        if let Some(SerdeSessionStart8(time, quests, locations)) = game_data.session_start {
+            let mut start = Journal::default();
+            start.reset(packs);
+            Self::load_quests(&mut start.quest, quests);
+            Self::load_locations(&mut start.location, locations);
+            start.cleanup();
+            self.session_start = Some(SessionStart {
+                time,
+                quest: start.quest,
+                location: start.location,
+            });
+        }
+        self.session = game_data
+            .sessions
+            .into_iter()
+            .map(Self::load_session)
+            .collect();
+
+        self.cleanup();
+    }
+}
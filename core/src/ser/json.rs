@@ -0,0 +1,42 @@
+use crate::game::PackId;
+use crate::journal::Journal;
+use crate::ser::game_data_1::SerdeGameData1;
+use crate::ser::game_data_2::SerdeGameData2;
+use crate::ser::game_data_3::SerdeGameData3;
+use crate::ser::game_data_4::SerdeGameData4;
+use crate::ser::game_data_5::SerdeGameData5;
+use crate::ser::game_data_6::SerdeGameData6;
+use crate::ser::game_data_7::SerdeGameData7;
+use crate::ser::game_data_8::SerdeGameData8;
+
+impl Journal {
+    // the game data of the local storage or a file in any version, from the newest to the oldest;
+    // if none matches, the error is the one of the newest version
+    pub fn load_any_game_data(&mut self, json: &str, packs: &[PackId]) -> serde_json::Result<()> {
+        let err = match serde_json::from_str::<SerdeGameData8>(json) {
+            Ok(game_data) => {
+                self.load_game_data_8(game_data, packs);
+                return Ok(());
+            }
+            Err(err) => err,
+        };
+        if let Ok(game_data) = serde_json::from_str::<SerdeGameData7>(json) {
+            self.load_game_data_7(game_data, packs);
+        } else if let Ok(game_data) = serde_json::from_str::<SerdeGameData6>(json) {
+            self.load_game_data_6(game_data, packs);
+        } else if let Ok(game_data) = serde_json::from_str::<SerdeGameData5>(json) {
+            self.load_game_data_5(game_data, packs);
+        } else if let Ok(game_data) = serde_json::from_str::<SerdeGameData4>(json) {
+            self.load_game_data_4(game_data, packs);
+        } else if let Ok(game_data) = serde_json::from_str::<SerdeGameData3>(json) {
+            self.load_game_data_3(game_data, packs);
+        } else if let Ok(game_data) = serde_json::from_str::<SerdeGameData2>(json) {
+            self.load_game_data_2(game_data, packs);
+        } else if let Ok(game_data) = serde_json::from_str::<SerdeGameData1>(json) {
+            self.load_game_data_1(game_data, packs);
+        } else {
+            return Err(err);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+pub mod csv;
+pub mod game_data_1;
+pub mod game_data_2;
+pub mod game_data_3;
+pub mod game_data_4;
+pub mod game_data_5;
+pub mod game_data_6;
+pub mod game_data_7;
+pub mod game_data_8;
+pub mod json;
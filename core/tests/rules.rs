@@ -0,0 +1,550 @@
+use sleeping_journal_core::data::crew::CrewMember;
+use sleeping_journal_core::data::encounter_type::EncounterType;
+use sleeping_journal_core::data::event::EventAction;
+use sleeping_journal_core::data::item::{Item, ItemKind};
+use sleeping_journal_core::data::ledger::Resource;
+use sleeping_journal_core::data::prerequisite::Prerequisite;
+use sleeping_journal_core::data::quest::QuestState;
+use sleeping_journal_core::data::score::{ScorePart, ScoreRate};
+use sleeping_journal_core::data::vis::Vis;
+use sleeping_journal_core::game::{LocationId, PackId, QuestId};
+use sleeping_journal_core::journal::{Choice, Conflict, ConflictId, Difference, Journal, NoteId};
+
+fn quests() -> Vec<QuestId> {
+    QuestId::all_of(PackId::base())
+        .filter(|quest_id| !quest_id.is_built_in())
+        .collect()
+}
+
+fn location() -> LocationId {
+    LocationId::all_of(PackId::base())
+        .find(|location_id| *location_id != LocationId::prologue())
+        .unwrap()
+}
+
+fn active(
+    journal: &Journal,
+    quest_id: QuestId,
+    ignore_visibility: bool,
+) -> Vec<(EncounterType, bool)> {
+    let quest = &journal.quest[&quest_id];
+    quest.encounter[&location()]
+        .get_active(quest, journal, ignore_visibility)
+        .into_iter()
+        .collect()
+}
+
+#[test]
+fn built_in_quests_are_in_game() {
+    let mut journal = Journal::default();
+    journal.reset(&[PackId::base()]);
+    for quest_id in QuestId::built_in(PackId::base()) {
+        let quest = &journal.quest[&quest_id];
+        assert_eq!(quest.state, QuestState::InGame);
+        assert!(quest.encounter[&LocationId::prologue()].contains_key(EncounterType::Gain));
+    }
+
+    // a completed built-in quest stays completed
+    let quest_id = QuestId::built_in(PackId::base()).next().unwrap();
+    journal.quest.get_mut(&quest_id).unwrap().state = QuestState::Completed;
+    journal.built_in(&[PackId::base()]);
+    assert_eq!(journal.quest[&quest_id].state, QuestState::Completed);
+}
+
+#[test]
+fn encounters_depend_on_the_quest_state() {
+    let quest_id = quests()[0];
+    let mut journal = Journal::default();
+    let quest = journal.quest.entry(quest_id).or_default();
+    let quest_location = quest.encounter.entry(location()).or_default();
+    quest_location.insert(EncounterType::Gain, None, Vis::Visible);
+    quest_location.insert(EncounterType::When, None, Vis::Visible);
+    quest_location.insert(EncounterType::Complete, None, Vis::Visible);
+
+    assert_eq!(
+        active(&journal, quest_id, false),
+        [
+            (EncounterType::Gain, true),
+            (EncounterType::When, false),
+            (EncounterType::Complete, false),
+        ]
+    );
+
+    journal.quest.get_mut(&quest_id).unwrap().state = QuestState::InGame;
+    assert_eq!(
+        active(&journal, quest_id, false),
+        [
+            (EncounterType::Gain, false),
+            (EncounterType::When, true),
+            (EncounterType::Complete, true),
+        ]
+    );
+
+    journal.quest.get_mut(&quest_id).unwrap().state = QuestState::Lost;
+    assert!(
+        active(&journal, quest_id, false)
+            .iter()
+            .all(|(_, ready)| !ready)
+    );
+}
+
+#[test]
+fn hidden_encounters_are_skipped() {
+    let quest_id = quests()[0];
+    let mut journal = Journal::default();
+    let quest = journal.quest.entry(quest_id).or_default();
+    let quest_location = quest.encounter.entry(location()).or_default();
+    quest_location.insert(EncounterType::Gain, None, Vis::HiddenThisCampaign);
+    quest_location.insert(EncounterType::Unless, None, Vis::Visible);
+
+    assert_eq!(
+        active(&journal, quest_id, false),
+        [(EncounterType::Unless, true)]
+    );
+    assert_eq!(
+        active(&journal, quest_id, true),
+        [(EncounterType::Unless, true), (EncounterType::Gain, true)]
+    );
+
+    journal.quest.get_mut(&quest_id).unwrap().vis = Vis::HiddenForever;
+    assert!(active(&journal, quest_id, false).is_empty());
+}
+
+#[test]
+fn prerequisites_are_evaluated() {
+    let quests = quests();
+    let (a, b, c) = (quests[0], quests[1], quests[2]);
+    let mut journal = Journal::default();
+    let prerequisite =
+        Prerequisite::AllOf(vec![Prerequisite::Completed(b), Prerequisite::NotGained(c)]);
+    journal
+        .quest
+        .entry(a)
+        .or_default()
+        .encounter
+        .entry(location())
+        .or_default()
+        .insert(
+            EncounterType::Gain,
+            Some(prerequisite.clone()),
+            Vis::Visible,
+        );
+
+    assert!(!prerequisite.is_met(&journal));
+    assert_eq!(active(&journal, a, false), [(EncounterType::Gain, false)]);
+
+    journal.quest.entry(b).or_default().state = QuestState::Completed;
+    assert!(prerequisite.is_met(&journal));
+    assert_eq!(active(&journal, a, false), [(EncounterType::Gain, true)]);
+
+    journal.quest.entry(c).or_default().state = QuestState::InGame;
+    assert!(!prerequisite.is_met(&journal));
+
+    // a removed quest from older versions counts as completed or lost
+    assert!(!Prerequisite::Lost(b).is_met(&journal));
+    assert!(Prerequisite::Quest(b).is_met(&journal));
+}
+
+#[test]
+fn cleanup_removes_empty_quests() {
+    let quests = quests();
+    let mut journal = Journal::default();
+    journal
+        .quest
+        .entry(quests[0])
+        .or_default()
+        .encounter
+        .entry(location())
+        .or_default();
+    journal.quest.entry(quests[1]).or_default().note = "a note".to_string().into();
+
+    journal.cleanup();
+    assert!(!journal.quest.contains_key(&quests[0]));
+    assert!(journal.quest.contains_key(&quests[1]));
+}
+
+#[test]
+fn merge_keeps_the_progress() {
+    let quests = quests();
+    let mut first = Journal::default();
+    let quest = first.quest.entry(quests[0]).or_default();
+    quest.state = QuestState::Completed;
+    quest.note = "first".to_string().into();
+    quest
+        .encounter
+        .entry(location())
+        .or_default()
+        .insert(EncounterType::Gain, None, Vis::Visible);
+
+    let mut second = Journal::default();
+    let quest = second.quest.entry(quests[0]).or_default();
+    quest.state = QuestState::InGame;
+    quest.vis = Vis::HiddenThisCampaign;
+    quest.note = "second".to_string().into();
+    let quest_location = quest.encounter.entry(location()).or_default();
+    quest_location.insert(EncounterType::Gain, None, Vis::HiddenForever);
+    quest_location.insert(EncounterType::When, None, Vis::Visible);
+    second
+        .location
+        .insert(location(), "a location".to_string().into());
+
+    let conflicts = first.merge(&second);
+    let quest = &first.quest[&quests[0]];
+    assert_eq!(quest.state, QuestState::Completed);
+    assert_eq!(quest.vis, Vis::HiddenThisCampaign);
+    let quest_location = &quest.encounter[&location()];
+    assert_eq!(quest_location[&EncounterType::Gain].vis, Vis::HiddenForever);
+    assert!(quest_location.contains_key(EncounterType::When));
+
+    // an empty note is no conflict
+    assert_eq!(first.note(NoteId::Location(location())), "a location");
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(
+        conflicts[0].id(),
+        ConflictId::Note(NoteId::Quest(quests[0]))
+    );
+    assert!(matches!(&conflicts[0], Conflict::Note(_, note) if &**note == "second"));
+    assert_eq!(first.note(NoteId::Quest(quests[0])), "first");
+
+    let conflict = conflicts.into_iter().next().unwrap();
+    let mut other = first.clone();
+    other.resolve(conflict.clone(), Choice::Other);
+    assert_eq!(other.note(NoteId::Quest(quests[0])), "second");
+    first.resolve(conflict, Choice::Both);
+    assert_eq!(first.note(NoteId::Quest(quests[0])), "first\n\nsecond");
+}
+
+#[test]
+fn merge_reports_what_is_as_advanced() {
+    let quests = quests();
+    let mut first = Journal::default();
+    let quest = first.quest.entry(quests[0]).or_default();
+    quest.state = QuestState::Completed;
+    quest.encounter.entry(location()).or_default().insert(
+        EncounterType::Gain,
+        Some(Prerequisite::Completed(quests[1])),
+        Vis::Visible,
+    );
+
+    let mut second = Journal::default();
+    let quest = second.quest.entry(quests[0]).or_default();
+    quest.state = QuestState::Lost;
+    quest.encounter.entry(location()).or_default().insert(
+        EncounterType::Gain,
+        Some(Prerequisite::Lost(quests[1])),
+        Vis::Visible,
+    );
+
+    // nothing is taken silently
+    let conflicts = first.merge(&second);
+    let quest = &first.quest[&quests[0]];
+    assert_eq!(quest.state, QuestState::Completed);
+    let prerequisite = &quest.encounter[&location()][&EncounterType::Gain].prerequisite;
+    assert_eq!(*prerequisite, Some(Prerequisite::Completed(quests[1])));
+    let ids = conflicts.iter().map(Conflict::id).collect::<Vec<_>>();
+    assert!(ids.contains(&ConflictId::QuestState(quests[0])));
+    assert!(ids.contains(&ConflictId::Prerequisite(
+        quests[0],
+        location(),
+        EncounterType::Gain
+    )));
+    assert_eq!(conflicts.len(), 2);
+
+    for conflict in conflicts {
+        assert_eq!(conflict.default_choice(), Choice::Own);
+        first.resolve(conflict, Choice::Other);
+    }
+    let quest = &first.quest[&quests[0]];
+    assert_eq!(quest.state, QuestState::Lost);
+    let prerequisite = &quest.encounter[&location()][&EncounterType::Gain].prerequisite;
+    assert_eq!(*prerequisite, Some(Prerequisite::Lost(quests[1])));
+}
+
+#[test]
+fn diff_lists_the_changes() {
+    let quests = quests();
+    let mut before = Journal::default();
+    let quest = before.quest.entry(quests[0]).or_default();
+    quest.state = QuestState::InGame;
+    quest
+        .encounter
+        .entry(location())
+        .or_default()
+        .insert(EncounterType::Gain, None, Vis::Visible);
+    before.location.insert(location(), "old".to_string().into());
+
+    let mut after = before.clone();
+    let quest = after.quest.get_mut(&quests[0]).unwrap();
+    quest.state = QuestState::Completed;
+    let quest_location = quest.encounter.get_mut(&location()).unwrap();
+    quest_location.remove(EncounterType::Gain);
+    quest_location.insert(EncounterType::Complete, None, Vis::Visible);
+    after.quest.entry(quests[1]).or_default().note = "new".to_string().into();
+    after.location.clear();
+
+    assert!(before.diff(&before.clone()).is_empty());
+    assert_eq!(
+        before.diff(&after),
+        [
+            Difference::QuestState(quests[0], QuestState::InGame, QuestState::Completed),
+            Difference::EncounterRemoved(quests[0], location(), EncounterType::Gain),
+            Difference::EncounterAdded(quests[0], location(), EncounterType::Complete),
+            Difference::Note(NoteId::Quest(quests[1])),
+            Difference::Note(NoteId::Location(location())),
+        ]
+    );
+}
+
+#[test]
+fn crew_starts_anew() {
+    let mut crew_member = CrewMember::new("Sofi".to_string(), 7);
+    crew_member.health = 2;
+    crew_member.fatigue = 3;
+    crew_member.level = 4;
+    crew_member.injuries = "broken arm".to_string();
+    crew_member.reset();
+    assert_eq!(crew_member, CrewMember::new("Sofi".to_string(), 7));
+
+    // the crew is only merged into a journal without one
+    let mut first = Journal::default();
+    let mut second = Journal::default();
+    second.crew.push(crew_member);
+    first.merge(&second);
+    assert_eq!(first.crew, second.crew);
+    assert!(first.diff(&second).is_empty());
+    second.crew[0].health = 1;
+    first.merge(&second);
+    assert_eq!(first.diff(&second), [Difference::Crew]);
+}
+
+#[test]
+fn ledger_keeps_running_totals() {
+    let mut journal = Journal::default();
+    journal.ledger.location_id = Some(location());
+    journal
+        .ledger
+        .record(Resource::Coins, 5, "sold".to_string());
+    journal
+        .ledger
+        .record(Resource::Food, 3, "bought".to_string());
+    journal
+        .ledger
+        .record(Resource::Coins, -2, "bought".to_string());
+    assert_eq!(journal.ledger.total(Resource::Coins), 3);
+    assert_eq!(journal.ledger.total(Resource::ShipDamage), 0);
+    let totals = journal
+        .ledger
+        .running()
+        .map(|(_, total)| total)
+        .collect::<Vec<_>>();
+    assert_eq!(totals, [5, 3, 3]);
+    assert_eq!(journal.ledger.entry[0].location_id, Some(location()));
+
+    // the same entries are not added twice
+    let mut merged = journal.clone();
+    merged.merge(&journal);
+    assert_eq!(merged.ledger, journal.ledger);
+
+    // entered amounts can be huge, the totals saturate instead of overflowing
+    journal
+        .ledger
+        .record(Resource::Food, i32::MAX, String::new());
+    assert_eq!(journal.ledger.total(Resource::Food), i32::MAX);
+    assert_eq!(journal.ledger.running().last().unwrap().1, i32::MAX);
+}
+
+#[test]
+fn score_counts_every_part() {
+    let mut journal = Journal::default();
+    journal.ledger.location_id = Some(location());
+    journal.acquire_totem("Totem".to_string(), 3);
+    journal.acquire_totem("Another totem".to_string(), 2);
+    journal.ledger.record(Resource::Coins, 12, String::new());
+    journal
+        .ledger
+        .record(Resource::ShipDamage, 2, String::new());
+    let mut crew_member = CrewMember::new(String::new(), CrewMember::DEFAULT_MAX_HEALTH);
+    crew_member.level = 3;
+    journal.crew.push(crew_member);
+    assert_eq!(journal.totem[0].location_id, Some(location()));
+    assert_eq!(journal.totem[0].session, 1);
+
+    // only the points of the totems are scored without rates
+    let points = |journal: &Journal, part| {
+        let score = journal.score();
+        score.iter().find(|line| line.part == part).unwrap().points
+    };
+    assert_eq!(points(&journal, ScorePart::Totems), 5);
+    assert!(
+        ScorePart::iter()
+            .filter(|part| *part != ScorePart::Totems)
+            .all(|part| points(&journal, part) == 0)
+    );
+
+    journal
+        .score_rate
+        .insert(ScorePart::Coins, ScoreRate { points: 1, per: 5 });
+    journal
+        .score_rate
+        .insert(ScorePart::ShipDamage, ScoreRate { points: -1, per: 1 });
+    journal
+        .score_rate
+        .insert(ScorePart::CrewLevels, ScoreRate { points: 1, per: 1 });
+    assert_eq!(points(&journal, ScorePart::Coins), 2);
+    assert_eq!(points(&journal, ScorePart::Food), 0);
+    assert_eq!(points(&journal, ScorePart::ShipDamage), -2);
+    assert_eq!(points(&journal, ScorePart::CrewLevels), 2);
+    // entered values can be huge, the points saturate instead of overflowing
+    let rate = ScoreRate {
+        points: i32::MAX,
+        per: 1,
+    };
+    assert_eq!(rate.points(2), i32::MAX);
+    assert_eq!(
+        ScoreRate::try_from_csv("-1/5"),
+        Some(ScoreRate { points: -1, per: 5 })
+    );
+    assert_eq!(
+        ScoreRate::try_from_csv("2"),
+        Some(ScoreRate { points: 2, per: 1 })
+    );
+}
+
+#[test]
+fn new_campaign_starts_anew() {
+    let quests = quests();
+    let mut journal = Journal::default();
+    journal.reset(&[PackId::base()]);
+    journal.quest.entry(quests[0]).or_default().state = QuestState::Completed;
+    journal.ledger.location_id = Some(location());
+    journal.ledger.record(Resource::Coins, 12, String::new());
+    journal.acquire_totem("Totem".to_string(), 3);
+    journal.item.push(Item::new(ItemKind::Artifact));
+    journal.crew.push(CrewMember::new("Sofi".to_string(), 7));
+    journal.crew[0].level = 3;
+    journal.start_session();
+
+    journal.new_campaign();
+    assert_eq!(journal.quest[&quests[0]].state, QuestState::NotFound);
+    assert_eq!(journal.crew[0].level, CrewMember::START_LEVEL);
+    assert!(journal.ledger.entry.is_empty() && journal.ledger.location_id.is_none());
+    // the totems of the last campaign are not scored again
+    assert!(journal.totem.is_empty());
+    assert!(journal.score().iter().all(|line| line.points == 0));
+    assert!(journal.item.is_empty());
+    // the running session belongs to the last campaign
+    assert!(journal.session_start.is_none());
+    assert_eq!(journal.session.len(), 1);
+    assert!(journal.session[0].quest_lost.is_empty());
+    assert_eq!(
+        journal.event.last().unwrap().action,
+        EventAction::NewCampaign
+    );
+}
+
+#[test]
+fn merge_matches_totems_and_items() {
+    let mut first = Journal::default();
+    first.acquire_totem("Totem".to_string(), 3);
+    let mut item = Item::new(ItemKind::Artifact);
+    item.number = Some(7);
+    item.name = "Compass".to_string();
+    first.item.push(item);
+
+    // the other phone moved the artifact and corrected the session of the totem
+    let mut second = first.clone();
+    second.totem[0].session = 2;
+    second.acquire_totem("Another totem".to_string(), 2);
+    second.item[0].holder = "Sofi".to_string();
+    second.item.push(Item::new(ItemKind::AdventureCard));
+
+    let conflicts = first.merge(&second);
+    assert_eq!(first.totem.len(), 2);
+    assert_eq!(first.totem[0].session, 1);
+    assert_eq!(first.item.len(), 2);
+    assert!(first.item[0].holder.is_empty());
+    let ids = conflicts.iter().map(Conflict::id).collect::<Vec<_>>();
+    assert_eq!(
+        ids,
+        [
+            ConflictId::Totem("Totem".to_string()),
+            ConflictId::Item(ItemKind::Artifact, Some(7), "Compass".to_string())
+        ]
+    );
+
+    let mut both = first.clone();
+    for conflict in conflicts {
+        assert_eq!(conflict.default_choice(), Choice::Own);
+        both.resolve(conflict.clone(), Choice::Both);
+        first.resolve(conflict, Choice::Other);
+    }
+    assert_eq!(first.totem[0].session, 2);
+    assert_eq!(first.item[0].holder, "Sofi");
+    assert_eq!(first.totem.len(), 2);
+    assert_eq!(first.item.len(), 2);
+    assert_eq!(both.totem.len(), 3);
+    assert_eq!(both.item.len(), 3);
+}
+
+#[test]
+fn inventory_search() {
+    let mut item = Item::new(ItemKind::AdventureCard);
+    item.number = Some(42);
+    item.name = "Old Lantern".to_string();
+    assert!(item.matches(""));
+    assert!(item.matches(" 42 "));
+    assert!(!item.matches("4"));
+    assert!(item.matches("lantern"));
+    assert!(!item.matches("compass"));
+
+    let mut journal = Journal::default();
+    journal.item.push(item);
+    let mut merged = journal.clone();
+    merged.merge(&journal);
+    assert_eq!(merged.item, journal.item);
+    assert_eq!(Journal::default().diff(&journal), [Difference::Items]);
+}
+
+#[test]
+fn session_summarizes_the_changes() {
+    let quests = quests();
+    let mut journal = Journal::default();
+    journal.reset(&[PackId::base()]);
+    journal.quest.entry(quests[1]).or_default().state = QuestState::InGame;
+    journal.quest.entry(quests[2]).or_default().state = QuestState::Lost;
+    journal.start_session();
+
+    journal.quest.entry(quests[0]).or_default().state = QuestState::InGame;
+    // set back, e.g. by an undo, it was not gained
+    journal.quest.get_mut(&quests[2]).unwrap().state = QuestState::InGame;
+    journal.quest.get_mut(&quests[1]).unwrap().state = QuestState::Completed;
+    journal
+        .quest
+        .get_mut(&quests[1])
+        .unwrap()
+        .encounter
+        .entry(location())
+        .or_default()
+        .insert(EncounterType::Complete, None, Vis::Visible);
+    journal
+        .location
+        .insert(location(), "a note".to_string().into());
+    journal.record_event(
+        EventAction::Perform(EncounterType::Complete),
+        Some(quests[1]),
+        Some(location()),
+    );
+
+    let summary = journal.end_session().unwrap().clone();
+    assert!(journal.session_start.is_none());
+    assert_eq!(summary.quest_gained, [quests[0]]);
+    assert_eq!(summary.quest_completed, [quests[1]]);
+    assert!(summary.quest_lost.is_empty());
+    assert_eq!(
+        summary.encounter,
+        [(quests[1], location(), EncounterType::Complete)]
+    );
+    assert_eq!(summary.note, [NoteId::Location(location())]);
+    assert_eq!(summary.location, [location()]);
+    assert!(journal.end_session().is_none());
+}
@@ -0,0 +1,477 @@
+use sleeping_journal_core::data::crew::CrewMember;
+use sleeping_journal_core::data::encounter_type::EncounterType;
+use sleeping_journal_core::data::event::EventAction;
+use sleeping_journal_core::data::item::{Item, ItemKind};
+use sleeping_journal_core::data::ledger::Resource;
+use sleeping_journal_core::data::prerequisite::Prerequisite;
+use sleeping_journal_core::data::quest::QuestState;
+use sleeping_journal_core::data::score::{ScorePart, ScoreRate};
+use sleeping_journal_core::data::vis::Vis;
+use sleeping_journal_core::game::{GameLanguage, LocationId, PackId, QuestId, QuestLocale};
+use sleeping_journal_core::journal::Journal;
+use sleeping_journal_core::ser::csv::Reason;
+use sleeping_journal_core::ser::game_data_6::{
+    SerdeEncounter6, SerdeEvent6, SerdeGameData6, SerdeQuest6,
+};
+use sleeping_journal_core::ser::game_data_8::SerdeGameData8;
+
+const PACKS: &[PackId] = &[PackId::base()];
+
+fn journal() -> Journal {
+    let quests = QuestId::all_of(PackId::base())
+        .filter(|quest_id| !quest_id.is_built_in())
+        .collect::<Vec<_>>();
+    let location_id = LocationId::all_of(PackId::base())
+        .find(|location_id| *location_id != LocationId::prologue())
+        .unwrap();
+
+    let mut journal = Journal::default();
+    journal.reset(PACKS);
+    journal.start_session();
+    let quest = journal.quest.entry(quests[0]).or_default();
+    quest.state = QuestState::InGame;
+    quest.note = "first line\nsecond line, with \"quotes\""
+        .to_string()
+        .into();
+    let quest_location = quest.encounter.entry(location_id).or_default();
+    quest_location.insert(
+        EncounterType::Complete,
+        Some(Prerequisite::AnyOf(vec![
+            Prerequisite::Completed(quests[1]),
+            Prerequisite::NotGained(quests[2]),
+        ])),
+        Vis::Visible,
+    );
+    quest_location.insert(EncounterType::Lose, None, Vis::HiddenThisCampaign);
+    let quest = journal.quest.entry(quests[1]).or_default();
+    quest.vis = Vis::HiddenForever;
+    quest.note = "hidden".to_string().into();
+    journal
+        .location
+        .insert(location_id, "a location note".to_string().into());
+    journal.record_event(
+        EventAction::Perform(EncounterType::Complete),
+        Some(quests[0]),
+        Some(location_id),
+    );
+    let mut crew_member = CrewMember::new("Sofi, the \"captain\"".to_string(), 7);
+    crew_member.health = 4;
+    crew_member.fatigue = 2;
+    crew_member.injuries = "broken arm".to_string();
+    journal.crew.push(crew_member);
+    journal.crew.push(CrewMember::new(String::new(), 5));
+    journal
+        .ledger
+        .record(Resource::Coins, 5, "sold \"the\" map".to_string());
+    journal.ledger.location_id = Some(location_id);
+    journal.ledger.record(Resource::Food, -2, String::new());
+    journal.acquire_totem("Totem of the \"deep\"".to_string(), 4);
+    journal
+        .score_rate
+        .insert(ScorePart::Coins, ScoreRate { points: 1, per: 5 });
+    journal
+        .score_rate
+        .insert(ScorePart::ShipDamage, ScoreRate { points: -1, per: 1 });
+    let mut item = Item::new(ItemKind::Artifact);
+    item.number = Some(17);
+    item.name = "Compass, broken".to_string();
+    item.holder = "Sofi, the \"captain\"".to_string();
+    item.quest_id = Some(quests[0]);
+    journal.item.push(item);
+    let mut item = Item::new(ItemKind::AdventureCard);
+    item.location_id = Some(location_id);
+    journal.item.push(item);
+    journal.end_session();
+    journal.start_session();
+    journal
+}
+
+fn json(journal: &Journal) -> String {
+    serde_json::to_string(&journal.save_game_data()).unwrap()
+}
+
+#[test]
+fn game_data_round_trip() {
+    let journal = journal();
+    let stored = json(&journal);
+
+    let mut loaded = Journal::default();
+    loaded.load_game_data_8(
+        serde_json::from_str::<SerdeGameData8>(&stored).unwrap(),
+        PACKS,
+    );
+    assert_eq!(json(&loaded), stored);
+
+    // the quests of the running session did not change, also after loading it
+    let summary =
+        |journal: &Journal| journal.summarize(journal.session_start.clone().unwrap(), i64::MAX);
+    assert!(summary(&loaded).quest_gained.is_empty());
+    assert!(summary(&loaded).encounter.is_empty());
+    assert_eq!(summary(&loaded), summary(&journal));
+    assert_eq!(loaded.session[0].quest_gained.len(), 1);
+}
+
+#[test]
+fn csv_round_trip() {
+    // the CSV has no sessions, their summaries are exported as text
+    let mut journal = journal();
+    journal.session.clear();
+    journal.session_start = None;
+    for language in GameLanguage::iter() {
+        let mut quest_locale = QuestLocale::new();
+        quest_locale.set_language(language);
+        quest_locale.set_packs(PACKS);
+        let csv = journal.save_csv(&quest_locale);
+
+        // the language is taken from the file
+        let mut loaded = Journal::default();
+        let mut loaded_locale = QuestLocale::new();
+        assert!(
+            loaded
+                .load_csv(&csv, PACKS, &mut loaded_locale)
+                .is_ok_and(|rejected| rejected.is_empty())
+        );
+        assert_eq!(loaded_locale.language(), language);
+
+        assert_eq!(json(&loaded), json(&journal));
+    }
+}
+
+#[test]
+fn csv_rejects_unknown_files() {
+    let mut journal = Journal::default();
+    let mut quest_locale = QuestLocale::new();
+    assert!(
+        journal
+            .load_csv(b"some,other\nfile,format\n", PACKS, &mut quest_locale)
+            .is_err()
+    );
+}
+
+#[test]
+fn csv_skips_invalid_rows() {
+    let file = "type,location,quest,status,prerequisite,visibility,note,time,session
+language,,,English,,,,,
+quest,,Raid,in-game,,,,,
+quest,,NOT A QUEST,in-game,,,,,
+quest,,RAID,in-gme,,,,,
+encounter,2,RAID,complete,+COTTAGE | !ABANDONN,,,,
+encounter,2,RAID,complete,+COTTAGE &,,,,
+encounter,,RAID,complete,,,,,
+encounter,2,RAID,complete,,hidden-forver,,,
+location,2,,,,,a note,sometimes,
+crew,,,,,,,,,Sofi,8,7,0,1
+crew,,,,,,,,,Sofi,7,7,none,1
+crew,,,,,,,,,Sofi,7,7,0,1
+ledger,,,gold,,,,2024-01-01T10:00:00+00:00,,,,,,,3
+ledger,2,,coins,,,,2024-01-01T10:00:00+00:00,,,,,,,three
+ship,,,,,,,,,,,,,,
+totem,2,,,,,Totem,,first,,,,,,
+totem,2,,,,,Totem,,1,,,,,,many
+score,,,gold,,,,,,,,,,,1
+score,,,coins,,,,,,,,,,,1/0
+score,,,food,,,,,,,,,,,2/3
+item,,,relic,,,Compass,,,,,,,,,
+item,2,,artifact,,,Compass,,,,,,,,,17a
+item,2,,artifact,,,Compass,,,Sofi,,,,,,17
+";
+    let mut journal = Journal::default();
+    let mut quest_locale = QuestLocale::new();
+    let rejected = journal
+        .load_csv(file.as_bytes(), PACKS, &mut quest_locale)
+        .ok()
+        .unwrap();
+    let expected = [
+        (3, Reason::Quest, "Raid", Some("RAID")),
+        (4, Reason::Quest, "NOT A QUEST", None),
+        (5, Reason::Status, "in-gme", Some("in-game")),
+        (6, Reason::PrerequisiteQuest, "ABANDONN", Some("ABANDON")),
+        (7, Reason::Prerequisite, "+COTTAGE &", None),
+        (8, Reason::MissingLocation, "", None),
+        (
+            9,
+            Reason::Visibility,
+            "hidden-forver",
+            Some("hidden-forever"),
+        ),
+        (11, Reason::Health, "8", None),
+        (12, Reason::Fatigue, "none", None),
+        (14, Reason::Status, "gold", None),
+        (15, Reason::Amount, "three", None),
+        (16, Reason::MissingLocation, "", None),
+        (17, Reason::Session, "first", None),
+        (18, Reason::Amount, "many", None),
+        (19, Reason::Status, "gold", None),
+        (20, Reason::Amount, "1/0", None),
+        (22, Reason::Status, "relic", None),
+        (23, Reason::Card, "17a", None),
+    ];
+    assert_eq!(rejected.len(), expected.len());
+    for (rejected, (row, reason, value, suggestion)) in rejected.iter().zip(expected) {
+        assert_eq!(rejected.row, row);
+        assert_eq!(rejected.reason, reason);
+        assert_eq!(rejected.value, value);
+        assert_eq!(rejected.suggestion.as_deref(), suggestion);
+    }
+    assert_eq!(Reason::PrerequisiteQuest.column(), "prerequisite");
+    assert_eq!(journal.location.len(), 1);
+    assert_eq!(journal.crew.len(), 1);
+    assert_eq!(journal.item.len(), 1);
+    assert_eq!(
+        journal.score_rate(ScorePart::Food),
+        ScoreRate { points: 2, per: 3 }
+    );
+}
+
+#[test]
+fn removed_quests_migrate_by_performed_encounters() {
+    let quests = QuestId::all_of(PackId::base())
+        .filter(|quest_id| !quest_id.is_built_in())
+        .take(3)
+        .collect::<Vec<_>>();
+    let location_id = LocationId::all_of(PackId::base())
+        .find(|location_id| *location_id != LocationId::prologue())
+        .unwrap();
+    let removed = || {
+        SerdeQuest6(
+            QuestState::Removed,
+            [(
+                location_id.raw(),
+                SerdeEncounter6([(EncounterType::Complete, (None, Vis::Visible))].into()),
+            )]
+            .into(),
+            "".into(),
+            Vis::Visible,
+        )
+    };
+    let event = |action, quest_id: QuestId| {
+        SerdeEvent6(0, 1, action, Some(quest_id.raw()), Some(location_id.raw()))
+    };
+    let game_data = SerdeGameData6 {
+        version_6: (),
+        quests: quests
+            .iter()
+            .map(|quest_id| (quest_id.raw(), removed()))
+            .collect(),
+        locations: [].into(),
+        events: vec![
+            event(EventAction::Perform(EncounterType::Complete), quests[0]),
+            event(EventAction::Record(EncounterType::Lose), quests[0]),
+            event(EventAction::Record(EncounterType::Complete), quests[1]),
+            event(EventAction::Perform(EncounterType::Lose), quests[2]),
+        ],
+    };
+
+    // a recorded encounter was only added, it didn't complete or lose the quest
+    let mut journal = Journal::default();
+    journal.load_game_data_6(game_data, PACKS);
+    assert_eq!(journal.quest[&quests[0]].state, QuestState::Completed);
+    assert_eq!(journal.quest[&quests[1]].state, QuestState::Removed);
+    assert_eq!(journal.quest[&quests[2]].state, QuestState::Lost);
+}
+
+// the fixtures have the same campaign in each storage version as it was first released: quest 2 is
+// in the game with a gain and a complete encounter at location 1, quest 3 was completed and quest 4
+// was lost, the ids 9999 are unknown
+fn fixture(json: &str) -> Journal {
+    let mut journal = Journal::default();
+    assert!(journal.load_any_game_data(json, PACKS).is_ok());
+    journal
+}
+
+fn assert_fixture(
+    journal: &Journal,
+    quest_vis: Vis,
+    complete_prerequisite: Option<Prerequisite>,
+    removed: [QuestState; 2],
+) {
+    let quest_id = |raw| QuestId::from_raw(raw).unwrap();
+    let location_id = LocationId::from_raw(1).unwrap();
+
+    let quest = &journal.quest[&quest_id(2)];
+    assert_eq!(quest.state, QuestState::InGame);
+    assert_eq!(quest.vis, quest_vis);
+    assert_eq!(&*quest.note, "the quest note");
+    let quest_location = &quest.encounter[&location_id];
+    assert_eq!(quest_location.len(), 2);
+    let gain = &quest_location[&EncounterType::Gain];
+    assert_eq!(gain.prerequisite, Some(Prerequisite::Quest(quest_id(3))));
+    assert_eq!(gain.vis, Vis::Visible);
+    let complete = &quest_location[&EncounterType::Complete];
+    assert_eq!(complete.prerequisite, complete_prerequisite);
+    assert_eq!(complete.vis, Vis::HiddenThisCampaign);
+    assert_eq!(journal.quest[&quest_id(3)].state, removed[0]);
+    assert_eq!(journal.quest[&quest_id(4)].state, removed[1]);
+
+    // the unknown ids are neither current nor retired ones, they are dropped
+    let mut new = Journal::default();
+    new.reset(PACKS);
+    assert_eq!(journal.quest.len(), new.quest.len() + 3);
+    assert_eq!(journal.location.len(), 1);
+    assert_eq!(&*journal.location[&location_id], "the location note");
+
+    // the newer parts of the campaign are empty
+    assert!(journal.ledger.entry.is_empty());
+    assert!(journal.totem.is_empty());
+    assert!(journal.item.is_empty());
+    assert!(journal.score_rate.is_empty());
+    assert!(journal.session_start.is_none());
+    assert!(journal.session.is_empty());
+}
+
+#[test]
+fn game_data_1_migrates() {
+    // a single prerequisite is a raw id, only the one of the gain is kept;
+    // without an event log, a removed quest can't be told completed or lost
+    let journal = fixture(include_str!("fixtures/game_data_1.json"));
+    assert_fixture(&journal, Vis::Visible, None, [QuestState::Removed; 2]);
+}
+
+#[test]
+fn game_data_2_migrates() {
+    let journal = fixture(include_str!("fixtures/game_data_2.json"));
+    assert_fixture(
+        &journal,
+        Vis::HiddenThisCampaign,
+        None,
+        [QuestState::Removed; 2],
+    );
+}
+
+#[test]
+fn game_data_3_migrates() {
+    let journal = fixture(include_str!("fixtures/game_data_3.json"));
+    assert_fixture(
+        &journal,
+        Vis::HiddenThisCampaign,
+        None,
+        [QuestState::Removed; 2],
+    );
+}
+
+#[test]
+fn game_data_4_migrates() {
+    // the event log tells whether a removed quest was completed or lost
+    let journal = fixture(include_str!("fixtures/game_data_4.json"));
+    assert_fixture(
+        &journal,
+        Vis::HiddenThisCampaign,
+        None,
+        [QuestState::Completed, QuestState::Lost],
+    );
+    assert_eq!(journal.event.len(), 3);
+    assert!(journal.event[2].quest_id.is_none() && journal.event[2].location_id.is_none());
+}
+
+#[test]
+fn game_data_5_migrates() {
+    let journal = fixture(include_str!("fixtures/game_data_5.json"));
+    assert_fixture(
+        &journal,
+        Vis::HiddenThisCampaign,
+        None,
+        [QuestState::Completed, QuestState::Lost],
+    );
+}
+
+#[test]
+fn game_data_6_migrates() {
+    // from this version on the prerequisites of all encounter types are kept
+    let journal = fixture(include_str!("fixtures/game_data_6.json"));
+    let quest_id = QuestId::from_raw(4).unwrap();
+    assert_fixture(
+        &journal,
+        Vis::HiddenThisCampaign,
+        Some(Prerequisite::Quest(quest_id)),
+        [QuestState::Completed, QuestState::Lost],
+    );
+}
+
+#[test]
+fn game_data_7_migrates() {
+    let journal = fixture(include_str!("fixtures/game_data_7.json"));
+    let quest_id = QuestId::from_raw(4).unwrap();
+    assert_fixture(
+        &journal,
+        Vis::HiddenThisCampaign,
+        Some(Prerequisite::Quest(quest_id)),
+        [QuestState::Completed, QuestState::Lost],
+    );
+    assert!(journal.crew.is_empty());
+}
+
+#[test]
+fn game_data_8_migrates() {
+    // the first saves of this version only have the crew, the later added parts are missing
+    let journal = fixture(include_str!("fixtures/game_data_8.json"));
+    let quest_id = QuestId::from_raw(4).unwrap();
+    assert_fixture(
+        &journal,
+        Vis::HiddenThisCampaign,
+        Some(Prerequisite::Quest(quest_id)),
+        [QuestState::Completed, QuestState::Lost],
+    );
+    let mut crew_member = CrewMember::new("Sofi".to_string(), 7);
+    crew_member.health = 4;
+    crew_member.fatigue = 2;
+    crew_member.injuries = "broken arm".to_string();
+    assert_eq!(journal.crew, [crew_member]);
+}
+
+#[test]
+fn csv_totems_without_points() {
+    let file = "type,location,quest,status,prerequisite,visibility,note,time,session,name,health,max health,fatigue,level
+language,,,English,,,,,
+totem,2,,,,,Totem of the deep,,1,,,,,
+";
+    let mut journal = Journal::default();
+    let mut quest_locale = QuestLocale::new();
+    assert!(
+        journal
+            .load_csv(file.as_bytes(), PACKS, &mut quest_locale)
+            .is_ok_and(|rejected| rejected.is_empty())
+    );
+    assert_eq!(journal.totem.len(), 1);
+    assert_eq!(journal.totem[0].name, "Totem of the deep");
+    assert_eq!(journal.totem[0].points, 0);
+    assert_eq!(journal.totem[0].session, 1);
+}
+
+#[test]
+fn prerequisite_syntax() {
+    let quests = QuestId::all_of(PackId::base()).take(3).collect::<Vec<_>>();
+    let prerequisite = Prerequisite::AnyOf(vec![
+        Prerequisite::AllOf(vec![
+            Prerequisite::Quest(quests[0]),
+            Prerequisite::Lost(quests[1]),
+        ]),
+        Prerequisite::NotGained(quests[2]),
+    ]);
+    let storage = prerequisite.to_storage();
+    assert_eq!(
+        storage,
+        format!(
+            "({} & -{}) | !{}",
+            quests[0].raw(),
+            quests[1].raw(),
+            quests[2].raw()
+        )
+    );
+    assert_eq!(Prerequisite::try_from_storage(&storage), Some(prerequisite));
+    assert!(Prerequisite::try_from_storage("(1 & ").is_none());
+}
+
+#[test]
+fn raw_ids_round_trip() {
+    for pack in PackId::all() {
+        for quest_id in QuestId::all_of(pack) {
+            assert_eq!(QuestId::from_raw(quest_id.raw()), Some(quest_id));
+        }
+        for location_id in LocationId::all_of(pack) {
+            if location_id != LocationId::prologue() {
+                assert_eq!(LocationId::from_raw(location_id.raw()), Some(location_id));
+            }
+        }
+    }
+    assert!(LocationId::from_raw(LocationId::prologue().raw()).is_none());
+}
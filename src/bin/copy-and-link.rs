@@ -1,6 +1,9 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use yew_bootstrap::icons::BIFiles;
 
+const SERVICE_WORKER: &str = "service-worker.js";
+const MANIFEST: &str = "manifest.webmanifest";
+
 fn main() -> Result<(), std::io::Error> {
     let staging_dir = PathBuf::from(
         std::env::var("TRUNK_STAGING_DIR").expect("Environment variable TRUNK_STAGING_DIR"),
@@ -28,6 +31,52 @@ fn main() -> Result<(), std::io::Error> {
         .replace("<!version>", env!("CARGO_PKG_VERSION"));
     std::fs::write(&path, index)?;
 
+    // all files are cached for offline use, the index is requested as the directory
+    let mut files = Vec::new();
+    list_files(&staging_dir, &staging_dir, &mut files)?;
+    files.sort();
+
+    // the web app manifest, installing it needs the icons of 192 and 512 pixels
+    let favicon = files
+        .iter()
+        .find(|file| file.starts_with("favicon") && file.ends_with(".png"))
+        .map_or("favicon.png", String::as_str);
+    std::fs::write(
+        staging_dir.join(MANIFEST),
+        format!(
+            r#"{{
+  "name": "Unofficial Sleeping Gods Journal",
+  "short_name": "Sleeping Journal",
+  "start_url": "./",
+  "scope": "./",
+  "display": "standalone",
+  "icons": [
+    {{"src": "{favicon}", "sizes": "16x16", "type": "image/png"}},
+    {{"src": "icon-192.png", "sizes": "192x192", "type": "image/png"}},
+    {{"src": "icon-512.png", "sizes": "512x512", "type": "image/png"}}
+  ]
+}}
+"#
+        ),
+    )?;
+
+    // the service worker, a new version replaces the cache
+    let precache = ["./".to_string(), format!("./{MANIFEST}")]
+        .into_iter()
+        .chain(
+            files
+                .iter()
+                .filter(|file| *file != "index.html" && !file.ends_with(".map"))
+                .map(|file| format!("./{file}")),
+        )
+        .map(|file| format!("{file:?}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let service_worker = include_str!("../../static/service-worker.js")
+        .replace("<!version>", env!("CARGO_PKG_VERSION"))
+        .replace("/* <!precache> */", &precache);
+    std::fs::write(staging_dir.join(SERVICE_WORKER), service_worker)?;
+
     Ok(())
 }
 
@@ -43,3 +92,23 @@ fn parse_base_href(html: &str) -> &str {
         })
         .map_or("", |html| html)
 }
+
+// all files below `dir`, relative to `root` and with `/` as separator
+fn list_files(root: &Path, dir: &Path, files: &mut Vec<String>) -> Result<(), std::io::Error> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            list_files(root, &path, files)?;
+        } else if let Ok(relative) = path.strip_prefix(root) {
+            let relative = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            if relative != SERVICE_WORKER && relative != MANIFEST {
+                files.push(relative);
+            }
+        }
+    }
+    Ok(())
+}
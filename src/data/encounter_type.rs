@@ -1,23 +1,16 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::game::MsgLanguage;
+pub(crate) use sleeping_journal_core::data::encounter_type::EncounterType;
 use yew_bootstrap::icons::BI;
 
-#[cfg_attr(feature = "debug", derive(Debug))]
-#[derive(Clone, Copy, Hash, Ord, PartialOrd, Eq, PartialEq, Serialize_repr, Deserialize_repr)]
-#[repr(u8)]
-pub(crate) enum EncounterType {
-    Unless = 0,
-    Gain = 1,
-    When = 2,
-    Complete = 3,
-    Lose = 4,
+pub(crate) trait EncounterTypeExt {
+    fn icon_active(self) -> BI;
+    fn icon(self, active: bool) -> BI;
+    // description of performing (or recording) this encounter, used for the undo history
+    fn str_change(self, msg: MsgLanguage, quest: &str, location: &str) -> String;
 }
 
-impl EncounterType {
-    pub(crate) fn raw(self) -> u8 {
-        self as u8
-    }
-
-    pub(crate) fn icon_active(self) -> BI {
+impl EncounterTypeExt for EncounterType {
+    fn icon_active(self) -> BI {
         match self {
             EncounterType::Unless => BI::EXCLAMATION_SQUARE_FILL,
             EncounterType::Gain => BI::PLUS_SQUARE_FILL,
@@ -27,7 +20,7 @@ impl EncounterType {
         }
     }
 
-    pub(crate) fn icon(self, active: bool) -> BI {
+    fn icon(self, active: bool) -> BI {
         if active {
             self.icon_active()
         } else {
@@ -41,23 +34,14 @@ impl EncounterType {
         }
     }
 
-    pub(crate) fn to_csv(self) -> &'static str {
+    fn str_change(self, msg: MsgLanguage, quest: &str, location: &str) -> String {
         match self {
-            EncounterType::Unless => "unless",
-            EncounterType::Gain => "gain",
-            EncounterType::When => "when",
-            EncounterType::Complete => "complete",
-            EncounterType::Lose => "lose",
-        }
-    }
-    pub(crate) fn try_from_csv(input: &str) -> Option<Self> {
-        match input {
-            "unless" => Some(EncounterType::Unless),
-            "gain" => Some(EncounterType::Gain),
-            "when" => Some(EncounterType::When),
-            "complete" => Some(EncounterType::Complete),
-            "lose" => Some(EncounterType::Lose),
-            _ => None,
+            EncounterType::Gain => msg.str_change_gain(quest, location),
+            EncounterType::Complete => msg.str_change_complete(quest, location),
+            EncounterType::Lose => msg.str_change_lose(quest, location),
+            EncounterType::Unless | EncounterType::When => {
+                msg.str_change_encounter(quest, location)
+            }
         }
     }
 }
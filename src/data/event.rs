@@ -0,0 +1,48 @@
+use crate::data::encounter_type::EncounterTypeExt;
+use crate::data::vis::VisExt;
+use crate::global::data::Data;
+pub(crate) use sleeping_journal_core::data::event::{Event, EventAction};
+use yew_bootstrap::icons::BI;
+
+pub(crate) trait EventExt {
+    fn icon(&self) -> BI;
+    fn text(&self, data: &Data) -> String;
+}
+
+impl EventExt for Event {
+    fn icon(&self) -> BI {
+        match self.action {
+            EventAction::Perform(encounter_type) | EventAction::Record(encounter_type) => {
+                encounter_type.icon_active()
+            }
+            EventAction::EncounterVis(_, _) | EventAction::QuestVis(_) => BI::EYE_SLASH,
+            EventAction::QuestNote => BI::JOURNAL_TEXT,
+            EventAction::Edit => BI::PENCIL,
+            EventAction::NewCampaign => BI::ARROW_REPEAT,
+        }
+    }
+
+    fn text(&self, data: &Data) -> String {
+        let quest = self
+            .quest_id
+            .map_or("", |quest_id| data.quest_name(quest_id));
+        let location = self.location_id.map_or("", |location_id| {
+            location_id.name(data.quest_locale.language())
+        });
+        match self.action {
+            EventAction::Perform(encounter_type) | EventAction::Record(encounter_type) => {
+                encounter_type.str_change(data.msg, quest, location)
+            }
+            EventAction::EncounterVis(_, vis) => data.msg.str_time_vis(
+                &data.msg.str_change_encounter_vis(quest, location),
+                vis.text(data),
+            ),
+            EventAction::QuestVis(vis) => data
+                .msg
+                .str_time_vis(&data.msg.str_change_quest_vis(quest), vis.text(data)),
+            EventAction::QuestNote => data.msg.str_change_quest_note(quest),
+            EventAction::Edit => data.msg.str_change_edit_quest(quest),
+            EventAction::NewCampaign => data.msg.str_change_new_campaign().to_string(),
+        }
+    }
+}
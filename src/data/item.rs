@@ -0,0 +1,24 @@
+use crate::global::data::Data;
+pub(crate) use sleeping_journal_core::data::item::ItemKind;
+use yew_bootstrap::icons::BI;
+
+pub(crate) trait ItemKindExt {
+    fn icon(self) -> BI;
+    fn text(self, data: &Data) -> &'static str;
+}
+
+impl ItemKindExt for ItemKind {
+    fn icon(self) -> BI {
+        match self {
+            ItemKind::AdventureCard => BI::POSTCARD,
+            ItemKind::Artifact => BI::GEM,
+        }
+    }
+
+    fn text(self, data: &Data) -> &'static str {
+        match self {
+            ItemKind::AdventureCard => data.msg.str_inventory_adventure_card(),
+            ItemKind::Artifact => data.msg.str_inventory_artifact(),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::global::data::Data;
+pub(crate) use sleeping_journal_core::data::ledger::Resource;
+use yew_bootstrap::icons::BI;
+
+pub(crate) trait ResourceExt {
+    fn icon(self) -> BI;
+    fn text(self, data: &Data) -> &'static str;
+}
+
+impl ResourceExt for Resource {
+    fn icon(self) -> BI {
+        match self {
+            Resource::ShipDamage => BI::TOOLS,
+            Resource::Coins => BI::COIN,
+            Resource::Food => BI::BASKET,
+        }
+    }
+
+    fn text(self, data: &Data) -> &'static str {
+        match self {
+            Resource::ShipDamage => data.msg.str_ledger_ship_damage(),
+            Resource::Coins => data.msg.str_ledger_coins(),
+            Resource::Food => data.msg.str_ledger_food(),
+        }
+    }
+}
@@ -1,7 +1,11 @@
 pub(crate) mod encounter_type;
+pub(crate) mod event;
+pub(crate) mod item;
+pub(crate) mod ledger;
 pub(crate) mod nav;
 pub(crate) mod note;
+pub(crate) mod prerequisite;
 pub(crate) mod quest;
-pub(crate) mod quest_location;
 pub(crate) mod sub_component;
 pub(crate) mod vis;
+pub(crate) use sleeping_journal_core::data::quest_location;
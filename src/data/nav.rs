@@ -7,6 +7,15 @@ pub(crate) enum Nav {
     Info,
     TodoAndMap,
     Edit,
+    Timeline,
+    Graph,
+    Search,
+    Crew,
+    Ledger,
+    Totems,
+    Inventory,
+    Score,
+    Sessions,
     Settings,
 }
 
@@ -19,6 +28,15 @@ impl From<&Route> for Nav {
                 Nav::TodoAndMap
             }
             Route::Edit | Route::EditQuest(_) => Nav::Edit,
+            Route::Timeline => Nav::Timeline,
+            Route::Graph => Nav::Graph,
+            Route::Search(_) => Nav::Search,
+            Route::Crew => Nav::Crew,
+            Route::Ledger => Nav::Ledger,
+            Route::Totems => Nav::Totems,
+            Route::Inventory => Nav::Inventory,
+            Route::Score => Nav::Score,
+            Route::Sessions => Nav::Sessions,
             Route::Settings => Nav::Settings,
         }
     }
@@ -1,46 +1,13 @@
-use std::fmt::{Display, Formatter};
-use std::ops::Deref;
-use yew::AttrValue;
-use yew::html::IntoPropValue;
+pub(crate) use sleeping_journal_core::data::note::Note;
 use yew_bootstrap::icons::BI;
 
-#[cfg_attr(feature = "debug", derive(Debug))]
-#[derive(Clone, Default)]
-pub(crate) struct Note(String);
-
-impl Note {
-    #[inline]
-    pub(crate) const fn icon() -> BI {
-        BI::JOURNAL_TEXT
-    }
-}
-
-impl Deref for Note {
-    type Target = str;
-
-    #[inline]
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
+pub(crate) trait NoteExt {
+    fn icon() -> BI;
 }
 
-impl From<String> for Note {
+impl NoteExt for Note {
     #[inline]
-    fn from(value: String) -> Self {
-        Self(value)
-    }
-}
-
-impl IntoPropValue<Option<AttrValue>> for Note {
-    #[inline]
-    fn into_prop_value(self) -> Option<AttrValue> {
-        self.0.into_prop_value()
-    }
-}
-
-impl Display for Note {
-    #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        self.0.fmt(f)
+    fn icon() -> BI {
+        BI::JOURNAL_TEXT
     }
 }
@@ -0,0 +1,367 @@
+use crate::game::QuestId;
+use crate::global::app::{App, MsgApp};
+use crate::global::data::Data;
+use crate::quest_picker::QuestPicker;
+pub(crate) use sleeping_journal_core::data::prerequisite::Prerequisite;
+use web_sys::HtmlSelectElement;
+use yew::{Context, Html, TargetCast, html};
+use yew_bootstrap::icons::BI;
+
+pub(crate) trait PrerequisiteExt {
+    fn text(&self, data: &Data) -> String;
+    // an editor for an optional prerequisite, all changes are sent as PrerequisiteEdit
+    fn view_editor<F, M>(
+        prerequisite: Option<&Prerequisite>,
+        data: &Data,
+        ctx: &Context<App>,
+        id: &str,
+        picker: Option<&PrerequisitePicker>,
+        msg: F,
+    ) -> Html
+    where
+        F: Fn(PrerequisiteEdit) -> M + Copy + 'static,
+        M: Into<MsgApp>;
+}
+
+impl PrerequisiteExt for Prerequisite {
+    fn text(&self, data: &Data) -> String {
+        match self {
+            Prerequisite::Quest(quest_id) => data.quest_name(*quest_id).to_string(),
+            Prerequisite::Completed(quest_id) => data
+                .msg
+                .str_prereq_text_completed(data.quest_name(*quest_id)),
+            Prerequisite::Lost(quest_id) => {
+                data.msg.str_prereq_text_lost(data.quest_name(*quest_id))
+            }
+            Prerequisite::NotGained(quest_id) => data
+                .msg
+                .str_prereq_text_not_gained(data.quest_name(*quest_id)),
+            Prerequisite::AllOf(terms) | Prerequisite::AnyOf(terms) => {
+                let separator = if matches!(self, Prerequisite::AllOf(_)) {
+                    format!(" {} ", data.msg.str_prereq_and())
+                } else {
+                    format!(" {} ", data.msg.str_prereq_or())
+                };
+                let terms = terms
+                    .iter()
+                    .map(|term| match term {
+                        Prerequisite::AllOf(_) | Prerequisite::AnyOf(_) => {
+                            format!("({})", term.text(data))
+                        }
+                        Prerequisite::Quest(_)
+                        | Prerequisite::Completed(_)
+                        | Prerequisite::Lost(_)
+                        | Prerequisite::NotGained(_) => term.text(data),
+                    })
+                    .collect::<Vec<_>>();
+                terms.join(&separator)
+            }
+        }
+    }
+
+    fn view_editor<F, M>(
+        prerequisite: Option<&Prerequisite>,
+        data: &Data,
+        ctx: &Context<App>,
+        id: &str,
+        picker: Option<&PrerequisitePicker>,
+        msg: F,
+    ) -> Html
+    where
+        F: Fn(PrerequisiteEdit) -> M + Copy + 'static,
+        M: Into<MsgApp>,
+    {
+        match prerequisite {
+            None => html! {
+                <button
+                    type="button"
+                    class="btn btn-sm btn-outline-primary"
+                    onclick={ctx.link().callback(move |_| msg(PrerequisiteEdit::Extend(Vec::new(), true)))}
+                >
+                    {BI::PLUS_LG}{" "}{data.msg.prereq_add()}
+                </button>
+            },
+            Some(prerequisite) => view_node(prerequisite, data, ctx, id, &[], picker, msg),
+        }
+    }
+}
+
+fn view_node<F, M>(
+    prerequisite: &Prerequisite,
+    data: &Data,
+    ctx: &Context<App>,
+    id: &str,
+    path: &[usize],
+    picker: Option<&PrerequisitePicker>,
+    msg: F,
+) -> Html
+where
+    F: Fn(PrerequisiteEdit) -> M + Copy + 'static,
+    M: Into<MsgApp>,
+{
+    let path_extend_all = path.to_vec();
+    let path_extend_any = path.to_vec();
+    let path_remove = path.to_vec();
+    let remove = html! {
+        <button
+            type="button"
+            class="btn btn-outline-danger"
+            title={data.msg.str_prereq_remove()}
+            onclick={ctx.link().callback(move |_| msg(PrerequisiteEdit::Remove(path_remove.clone())))}
+        >
+            {BI::TRASH}
+        </button>
+    };
+    match prerequisite {
+        Prerequisite::Quest(quest_id)
+        | Prerequisite::Completed(quest_id)
+        | Prerequisite::Lost(quest_id)
+        | Prerequisite::NotGained(quest_id) => {
+            let current = prerequisite.as_term().map_or("", |(_, prefix)| prefix);
+            let path_pick = path.to_vec();
+            let path_quest = path.to_vec();
+            let path_condition = path.to_vec();
+            let conditions = [
+                ("", data.msg.str_prereq_done()),
+                ("+", data.msg.str_prereq_completed()),
+                ("-", data.msg.str_prereq_lost()),
+                ("!", data.msg.str_prereq_not_gained()),
+            ]
+            .into_iter()
+            .map(|(prefix, name)| {
+                html! {
+                    <option value={prefix} selected={prefix == current}>{name}</option>
+                }
+            });
+            let id = format!(
+                "{id}-{}",
+                path.iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("-")
+            );
+            let picker = picker
+                .filter(|picker| picker.path.as_deref() == Some(path))
+                .map(|picker| {
+                    picker.picker.view(
+                        data,
+                        ctx,
+                        &id,
+                        |_| true,
+                        move |query| msg(PrerequisiteEdit::Search(query)),
+                        move |quest_id| msg(PrerequisiteEdit::Quest(path_quest.clone(), quest_id)),
+                    )
+                });
+            html! {
+                <>
+                <div class="input-group input-group-sm mb-1">
+                    <button
+                        type="button"
+                        class="btn btn-outline-secondary flex-grow-1 text-start"
+                        onclick={ctx.link().callback(move |_| msg(PrerequisiteEdit::Pick(path_pick.clone())))}
+                    >
+                        {data.quest_name(*quest_id)}
+                    </button>
+                    <select
+                        class="form-select flex-grow-0 w-auto"
+                        onchange={ctx.link().callback(move |event: web_sys::Event| {
+                            msg(PrerequisiteEdit::Condition(
+                                path_condition.clone(),
+                                event.target_unchecked_into::<HtmlSelectElement>().value(),
+                            ))
+                        })}
+                    >
+                        {for conditions}
+                    </select>
+                    <button
+                        type="button"
+                        class="btn btn-outline-secondary"
+                        onclick={ctx.link().callback(move |_| msg(PrerequisiteEdit::Extend(path_extend_all.clone(), true)))}
+                    >
+                        {data.msg.prereq_and()}
+                    </button>
+                    <button
+                        type="button"
+                        class="btn btn-outline-secondary"
+                        onclick={ctx.link().callback(move |_| msg(PrerequisiteEdit::Extend(path_extend_any.clone(), false)))}
+                    >
+                        {data.msg.prereq_or()}
+                    </button>
+                    {remove}
+                </div>
+                if let Some(picker) = picker {
+                    <div class="mb-2">{picker}</div>
+                }
+                </>
+            }
+        }
+        Prerequisite::AllOf(terms) | Prerequisite::AnyOf(terms) => {
+            let all = matches!(prerequisite, Prerequisite::AllOf(_));
+            let path_toggle = path.to_vec();
+            let terms = terms.iter().enumerate().map(|(pos, term)| {
+                let mut path = path.to_vec();
+                path.push(pos);
+                view_node(term, data, ctx, id, &path, picker, msg)
+            });
+            html! {
+                <div class="border rounded p-2 mb-1">
+                    <div class="btn-group btn-group-sm mb-1">
+                        <button
+                            type="button"
+                            class="btn btn-outline-primary"
+                            onclick={ctx.link().callback(move |_| msg(PrerequisiteEdit::Toggle(path_toggle.clone())))}
+                        >
+                            if all {
+                                {data.msg.prereq_all_of()}
+                            } else {
+                                {data.msg.prereq_any_of()}
+                            }
+                        </button>
+                        {remove}
+                    </div>
+                    {for terms}
+                </div>
+            }
+        }
+    }
+}
+
+// the quest picker of an editor, it's open for at most one term
+#[derive(Default)]
+pub(crate) struct PrerequisitePicker {
+    path: Option<Vec<usize>>,
+    picker: QuestPicker,
+}
+
+#[derive(Clone)]
+pub(crate) enum PrerequisiteEdit {
+    // opens (or closes) the quest picker of a term
+    Pick(Vec<usize>),
+    Search(String),
+    Quest(Vec<usize>, QuestId),
+    // changes the condition of a term by its prefix in the expression
+    Condition(Vec<usize>, String),
+    // adds a new term next to the one at the path, either with "all of" (true) or "any of" (false)
+    Extend(Vec<usize>, bool),
+    Toggle(Vec<usize>),
+    Remove(Vec<usize>),
+}
+
+impl PrerequisiteEdit {
+    // new terms use the default quest, which can be changed afterward
+    pub(crate) fn apply(
+        self,
+        prerequisite: &mut Option<Prerequisite>,
+        picker: &mut PrerequisitePicker,
+        default: QuestId,
+    ) {
+        match self {
+            PrerequisiteEdit::Pick(path) => {
+                picker.path = if picker.path.as_ref() == Some(&path) {
+                    None
+                } else {
+                    Some(path)
+                };
+                picker.picker.clear();
+                return;
+            }
+            PrerequisiteEdit::Search(query) => {
+                picker.picker.set_query(query);
+                return;
+            }
+            // any change closes the picker, as the paths may have changed
+            _ => picker.path = None,
+        }
+        match self {
+            PrerequisiteEdit::Pick(_) | PrerequisiteEdit::Search(_) => (),
+            PrerequisiteEdit::Quest(path, quest_id) => {
+                if let Some(term) = prerequisite.as_mut().and_then(|p| p.get_mut(&path)) {
+                    if let Some(new) = term
+                        .as_term()
+                        .and_then(|(_, prefix)| Prerequisite::term(prefix, quest_id))
+                    {
+                        *term = new;
+                    }
+                }
+            }
+            PrerequisiteEdit::Condition(path, prefix) => {
+                if let Some(term) = prerequisite.as_mut().and_then(|p| p.get_mut(&path)) {
+                    if let Some(new) = term
+                        .as_term()
+                        .and_then(|(quest_id, _)| Prerequisite::term(&prefix, quest_id))
+                    {
+                        *term = new;
+                    }
+                }
+            }
+            PrerequisiteEdit::Extend(path, all) => {
+                let Some(root) = prerequisite.as_mut() else {
+                    *prerequisite = Some(Prerequisite::Quest(default));
+                    return;
+                };
+                // a group of the same kind just gets another term
+                if let Some((pos, parent_path)) = path.split_last() {
+                    match root.get_mut(parent_path) {
+                        Some(Prerequisite::AllOf(terms)) if all => {
+                            terms.insert(pos + 1, Prerequisite::Quest(default));
+                            return;
+                        }
+                        Some(Prerequisite::AnyOf(terms)) if !all => {
+                            terms.insert(pos + 1, Prerequisite::Quest(default));
+                            return;
+                        }
+                        _ => (),
+                    }
+                }
+                if let Some(term) = root.get_mut(&path) {
+                    let old = term.clone();
+                    let terms = vec![old, Prerequisite::Quest(default)];
+                    *term = if all {
+                        Prerequisite::AllOf(terms)
+                    } else {
+                        Prerequisite::AnyOf(terms)
+                    };
+                }
+            }
+            PrerequisiteEdit::Toggle(path) => {
+                if let Some(term) = prerequisite.as_mut().and_then(|p| p.get_mut(&path)) {
+                    match term {
+                        Prerequisite::AllOf(terms) => {
+                            *term = Prerequisite::AnyOf(std::mem::take(terms));
+                        }
+                        Prerequisite::AnyOf(terms) => {
+                            *term = Prerequisite::AllOf(std::mem::take(terms));
+                        }
+                        Prerequisite::Quest(_)
+                        | Prerequisite::Completed(_)
+                        | Prerequisite::Lost(_)
+                        | Prerequisite::NotGained(_) => (),
+                    }
+                }
+            }
+            PrerequisiteEdit::Remove(path) => {
+                let Some((pos, parent_path)) = path.split_last() else {
+                    *prerequisite = None;
+                    return;
+                };
+                if let Some(parent) = prerequisite.as_mut().and_then(|p| p.get_mut(parent_path)) {
+                    match parent {
+                        Prerequisite::AllOf(terms) | Prerequisite::AnyOf(terms) => {
+                            if *pos < terms.len() {
+                                terms.remove(*pos);
+                            }
+                            // a group with a single term is just the term
+                            if terms.len() == 1 {
+                                *parent = terms.remove(0);
+                            }
+                        }
+                        Prerequisite::Quest(_)
+                        | Prerequisite::Completed(_)
+                        | Prerequisite::Lost(_)
+                        | Prerequisite::NotGained(_) => (),
+                    }
+                }
+            }
+        }
+    }
+}
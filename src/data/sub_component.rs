@@ -24,7 +24,7 @@ impl UpdateResult {
 
 pub(crate) trait SubComponent {
     type Message: Into<MsgApp>;
-    type Ser: Default + Serialize + for<'de> Deserialize<'de>;
+    type Ser: Clone + Default + Serialize + for<'de> Deserialize<'de>;
 
     fn create(ctx: &Context<App>) -> Self;
     fn reset_to_new(&mut self) {}
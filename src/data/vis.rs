@@ -1,39 +1,18 @@
 use crate::global::data::Data;
-use serde_repr::{Deserialize_repr, Serialize_repr};
+pub(crate) use sleeping_journal_core::data::vis::Vis;
 use yew::{Classes, classes};
 use yew_bootstrap::util::Color;
 
-#[cfg_attr(feature = "debug", derive(Debug))]
-#[derive(
-    Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Serialize_repr, Deserialize_repr,
-)]
-#[repr(u8)]
-pub(crate) enum Vis {
-    #[default]
-    Visible = 0,
-    HiddenThisCampaign = 1,
-    HiddenForever = 2,
+pub(crate) trait VisExt {
+    fn to_style(self) -> Color;
+    fn to_style_primary(self) -> Color;
+    fn class_text(self) -> Classes;
+    fn class_btn_outline(self) -> Classes;
+    fn text(self, data: &Data) -> &'static str;
 }
 
-impl Vis {
-    pub(crate) fn to_csv(self) -> &'static str {
-        match self {
-            Vis::Visible => "",
-            Vis::HiddenThisCampaign => "hidden-this-campaign",
-            Vis::HiddenForever => "hidden-forever",
-        }
-    }
-
-    pub(crate) fn try_from_csv(input: &str) -> Option<Self> {
-        match input {
-            "" => Some(Vis::Visible),
-            "hidden-this-campaign" => Some(Vis::HiddenThisCampaign),
-            "hidden-forever" => Some(Vis::HiddenForever),
-            _ => None,
-        }
-    }
-
-    pub(crate) const fn to_style(self) -> Color {
+impl VisExt for Vis {
+    fn to_style(self) -> Color {
         match self {
             Vis::Visible => Color::Success,
             Vis::HiddenThisCampaign => Color::Warning,
@@ -41,7 +20,7 @@ impl Vis {
         }
     }
 
-    pub(crate) const fn to_style_primary(self) -> Color {
+    fn to_style_primary(self) -> Color {
         match self {
             Vis::Visible => Color::Primary,
             Vis::HiddenThisCampaign => Color::Warning,
@@ -49,7 +28,7 @@ impl Vis {
         }
     }
 
-    pub(crate) fn class_text(self) -> Classes {
+    fn class_text(self) -> Classes {
         match self {
             Vis::Visible => classes!(),
             Vis::HiddenThisCampaign => classes!("text-warning"),
@@ -57,7 +36,7 @@ impl Vis {
         }
     }
 
-    pub(crate) fn class_btn_outline(self) -> Classes {
+    fn class_btn_outline(self) -> Classes {
         match self {
             Vis::Visible => classes!("btn", "btn-outline-success"),
             Vis::HiddenThisCampaign => classes!("btn", "btn-outline-warning"),
@@ -65,7 +44,7 @@ impl Vis {
         }
     }
 
-    pub(crate) fn text(self, data: &Data) -> &'static str {
+    fn text(self, data: &Data) -> &'static str {
         match self {
             Vis::Visible => data.msg.str_vis_visible(),
             Vis::HiddenThisCampaign => data.msg.str_vis_hidden_this_campaign(),
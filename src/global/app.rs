@@ -1,30 +1,43 @@
 use crate::data::nav::Nav;
 use crate::data::sub_component::{SubComponent, UpdateResult};
 use crate::game::{MsgLanguage, QuestLocale};
+use crate::global::campaign::{CampaignId, Campaigns};
 use crate::global::data::Data;
+use crate::global::history::History;
+use crate::html::{listen_undo_keys, listen_update, text, update_available, update_reload};
 use crate::pane::action::{MsgAction, PaneAction};
+use crate::pane::crew::{MsgCrew, PaneCrew};
 use crate::pane::edit::{MsgEdit, PaneEdit};
 use crate::pane::edit_quest::{MsgEditQuest, PaneEditQuest};
+use crate::pane::graph::graph_view;
 use crate::pane::info::info_view;
+use crate::pane::inventory::{MsgInventory, PaneInventory};
+use crate::pane::ledger::{MsgLedger, PaneLedger};
 use crate::pane::map::{MsgMap, PaneMap};
 use crate::pane::map_location::{MsgMapLocation, PaneMapLocation};
 use crate::pane::map_new_quest::{MsgMapNewQuest, PaneMapNewQuest};
+use crate::pane::score::{MsgScore, PaneScore};
+use crate::pane::search::{MsgSearch, PaneSearch};
+use crate::pane::sessions::{MsgSessions, PaneSessions};
 use crate::pane::settings::{MsgSettings, PaneSettings};
+use crate::pane::timeline::{MsgTimeline, PaneTimeline};
 use crate::pane::todo::{MsgTodo, PaneTodo};
+use crate::pane::totems::{MsgTotems, PaneTotems};
 use crate::route::{Route, Router};
-use crate::ser::game_data_1::SerdeGameData1;
-use crate::ser::game_data_2::SerdeGameData2;
-use crate::ser::game_data_3::SerdeGameData3;
 use crate::ser::settings::SerdeSettings;
 use gloo_storage::errors::StorageError;
 use gloo_storage::{LocalStorage, Storage};
-use gloo_timers::callback::Interval;
+use gloo_timers::callback::{Interval, Timeout};
 #[cfg(feature = "debug")]
 use gloo_utils::format::JsValueSerdeExt;
-use std::collections::{HashMap, VecDeque};
+use sleeping_journal_core::journal::Journal;
+use std::collections::VecDeque;
 #[cfg(feature = "debug")]
 use web_sys::wasm_bindgen::JsValue;
+use web_sys::wasm_bindgen::closure::Closure;
+use web_sys::{Event, KeyboardEvent};
 use yew::{Component, Context, Html, classes, html};
+use yew_bootstrap::icons::BI;
 
 #[derive(Clone)]
 pub(crate) enum MsgApp {
@@ -36,11 +49,32 @@ pub(crate) enum MsgApp {
     MsgSettings(MsgSettings),
     MsgEditList(MsgEdit),
     MsgEditQuest(MsgEditQuest),
+    MsgTimeline(MsgTimeline),
+    MsgSearch(MsgSearch),
+    MsgCrew(MsgCrew),
+    MsgLedger(MsgLedger),
+    MsgTotems(MsgTotems),
+    MsgInventory(MsgInventory),
+    MsgScore(MsgScore),
+    MsgSessions(MsgSessions),
     Tick,
     Go(Route),
+    GoReplace(Route),
     Back,
     HistoryChanged,
     ResetToNew,
+    SwitchCampaign(CampaignId),
+    Undo,
+    Redo,
+    HideToast,
+    UpdateAvailable,
+    UpdateReload,
+}
+
+enum Toast {
+    Changed(String),
+    Undone(String),
+    Redone(String),
 }
 
 pub(crate) struct App {
@@ -48,6 +82,12 @@ pub(crate) struct App {
     save_settings: bool,
     router: Router,
     _tick_interval: Interval,
+    history: History,
+    toast: Option<Toast>,
+    toast_timeout: Option<Timeout>,
+    _undo_keys_listener: Closure<dyn Fn(KeyboardEvent)>,
+    update_available: bool,
+    _update_listener: Closure<dyn Fn(Event)>,
     // panes
     route: Route,
     pub(crate) quests_is_map: bool,
@@ -58,12 +98,97 @@ pub(crate) struct App {
     pub(crate) pane_action: PaneAction,
     pub(crate) pane_edit: PaneEdit,
     pub(crate) pane_edit_quest: PaneEditQuest,
+    pub(crate) pane_timeline: PaneTimeline,
+    pub(crate) pane_search: PaneSearch,
+    pub(crate) pane_crew: PaneCrew,
+    pub(crate) pane_ledger: PaneLedger,
+    pub(crate) pane_totems: PaneTotems,
+    pub(crate) pane_inventory: PaneInventory,
+    pub(crate) pane_score: PaneScore,
+    pub(crate) pane_sessions: PaneSessions,
     pub(crate) pane_settings: PaneSettings,
 }
 
 impl App {
-    const STORAGE_KEY_GAME_DATA: &'static str = "sleeping-gods-journal.game-data";
     const STORAGE_KEY_SETTINGS: &'static str = "sleeping-gods-journal.settings";
+
+    fn load_game_data(&mut self) {
+        let key = self.data.campaign.active_id().storage_key();
+        let loaded = LocalStorage::raw()
+            .get_item(&key)
+            .ok()
+            .flatten()
+            .is_some_and(|json| {
+                self.data
+                    .journal
+                    .load_any_game_data(&json, &self.data.campaign.active().packs)
+                    .is_ok()
+            });
+        if !loaded {
+            self.data.reset();
+        }
+    }
+
+    fn show_toast(&mut self, ctx: &Context<Self>, toast: Toast) {
+        let link = ctx.link().clone();
+        self.toast = Some(toast);
+        self.toast_timeout = Some(Timeout::new(8_000, move || {
+            link.send_message(MsgApp::HideToast);
+        }));
+    }
+
+    fn view_toast(&self, ctx: &Context<Self>) -> Html {
+        let Some(toast) = &self.toast else {
+            return Html::default();
+        };
+        let (message, button) = match toast {
+            Toast::Changed(change) => (
+                self.data.msg.str_undo_toast_changed(change),
+                (MsgApp::Undo, self.data.msg.str_undo_undo()),
+            ),
+            Toast::Undone(change) => (
+                self.data.msg.str_undo_toast_undone(change),
+                (MsgApp::Redo, self.data.msg.str_undo_redo()),
+            ),
+            Toast::Redone(change) => (
+                self.data.msg.str_undo_toast_redone(change),
+                (MsgApp::Undo, self.data.msg.str_undo_undo()),
+            ),
+        };
+        let (button_msg, button_text) = button;
+        html! {
+            <div class="toast-container position-fixed bottom-0 end-0 p-3">
+              <div class="toast show" role="status" aria-live="polite" aria-atomic="true">
+                <div class="d-flex align-items-center">
+                  <div class="toast-body">{message}</div>
+                  <button type="button" class="btn btn-primary btn-sm ms-auto" onclick={ctx.link().callback(move |_| button_msg.clone())}>
+                    {text(button_text)}
+                  </button>
+                  <button type="button" class="btn-close mx-2" aria-label={self.data.msg.str_close()} onclick={ctx.link().callback(|_| MsgApp::HideToast)}></button>
+                </div>
+              </div>
+            </div>
+        }
+    }
+
+    fn reset_panes_to_new(&mut self) {
+        self.pane_todo.reset_to_new();
+        self.pane_map.reset_to_new();
+        self.pane_map_location.reset_to_new();
+        self.pane_map_new_quest.reset_to_new();
+        self.pane_action.reset_to_new();
+        self.pane_edit.reset_to_new();
+        self.pane_edit_quest.reset_to_new();
+        self.pane_timeline.reset_to_new();
+        self.pane_search.reset_to_new();
+        self.pane_crew.reset_to_new();
+        self.pane_ledger.reset_to_new();
+        self.pane_totems.reset_to_new();
+        self.pane_inventory.reset_to_new();
+        self.pane_score.reset_to_new();
+        self.pane_sessions.reset_to_new();
+        self.pane_settings.reset_to_new();
+    }
 }
 
 impl Component for App {
@@ -76,17 +201,25 @@ impl Component for App {
         let mut result = Self {
             data: Data {
                 // game data
-                quest: HashMap::new(),
-                location: HashMap::new(),
+                journal: Journal::default(),
                 // global settings
+                campaign: Campaigns::new(),
                 quest_locale: QuestLocale::new(),
                 msg: MsgLanguage::default(),
+                spoiler_safe: false,
                 // messages
                 chain_msg: VecDeque::with_capacity(2),
+                change: None,
             },
             save_settings: false,
             router: Router::new(ctx),
             _tick_interval: Interval::new(10_000, move || link_cloned.send_message(MsgApp::Tick)),
+            history: History::default(),
+            toast: None,
+            toast_timeout: None,
+            _undo_keys_listener: listen_undo_keys(ctx),
+            update_available: update_available(),
+            _update_listener: listen_update(ctx),
             // panes
             route: Route::Info,
             quests_is_map: false,
@@ -98,28 +231,32 @@ impl Component for App {
             pane_settings: PaneSettings::create(ctx),
             pane_edit: PaneEdit::create(ctx),
             pane_edit_quest: PaneEditQuest::create(ctx),
+            pane_timeline: PaneTimeline::create(ctx),
+            pane_search: PaneSearch::create(ctx),
+            pane_crew: PaneCrew::create(ctx),
+            pane_ledger: PaneLedger::create(ctx),
+            pane_totems: PaneTotems::create(ctx),
+            pane_inventory: PaneInventory::create(ctx),
+            pane_score: PaneScore::create(ctx),
+            pane_sessions: PaneSessions::create(ctx),
         };
         result.data.reset(); // is required for all built-in's to work
 
         if let Ok(settings) = LocalStorage::get::<SerdeSettings>(Self::STORAGE_KEY_SETTINGS) {
             result.load_settings(settings);
         }
-        if let Ok(game_data) = LocalStorage::get::<SerdeGameData3>(Self::STORAGE_KEY_GAME_DATA) {
-            result.data.load_game_data_3(game_data);
-        } else if let Ok(game_data) =
-            LocalStorage::get::<SerdeGameData2>(Self::STORAGE_KEY_GAME_DATA)
-        {
-            result.data.load_game_data_2(game_data);
-        } else if let Ok(game_data) =
-            LocalStorage::get::<SerdeGameData1>(Self::STORAGE_KEY_GAME_DATA)
-        {
-            result.data.load_game_data_1(game_data);
-        }
+        result.load_game_data();
+        result.history = History::load(
+            result.data.campaign.active_id(),
+            result.data.journal.save_game_data().into_owned(),
+        );
 
         #[cfg(feature = "debug")]
         web_sys::console::log_1(&JsValue::from_serde(&result.save_settings).unwrap());
         #[cfg(feature = "debug")]
-        web_sys::console::log_1(&JsValue::from_serde(&result.data.save_game_data()).unwrap());
+        web_sys::console::log_1(
+            &JsValue::from_serde(&result.data.journal.save_game_data()).unwrap(),
+        );
 
         result
     }
@@ -139,6 +276,14 @@ impl Component for App {
                 MsgApp::MsgSettings(msg) => self.pane_settings.update(&mut self.data, ctx, msg),
                 MsgApp::MsgEditList(msg) => self.pane_edit.update(&mut self.data, ctx, msg),
                 MsgApp::MsgEditQuest(msg) => self.pane_edit_quest.update(&mut self.data, ctx, msg),
+                MsgApp::MsgTimeline(msg) => self.pane_timeline.update(&mut self.data, ctx, msg),
+                MsgApp::MsgSearch(msg) => self.pane_search.update(&mut self.data, ctx, msg),
+                MsgApp::MsgCrew(msg) => self.pane_crew.update(&mut self.data, ctx, msg),
+                MsgApp::MsgLedger(msg) => self.pane_ledger.update(&mut self.data, ctx, msg),
+                MsgApp::MsgTotems(msg) => self.pane_totems.update(&mut self.data, ctx, msg),
+                MsgApp::MsgInventory(msg) => self.pane_inventory.update(&mut self.data, ctx, msg),
+                MsgApp::MsgScore(msg) => self.pane_score.update(&mut self.data, ctx, msg),
+                MsgApp::MsgSessions(msg) => self.pane_sessions.update(&mut self.data, ctx, msg),
                 MsgApp::Tick => {
                     if self.save_settings {
                         let _: Result<(), StorageError> =
@@ -152,7 +297,11 @@ impl Component for App {
                     UpdateResult::empty()
                 }
                 MsgApp::Go(route) => {
-                    self.router.go(&self.data, route);
+                    self.router.go(route);
+                    UpdateResult::empty()
+                }
+                MsgApp::GoReplace(route) => {
+                    self.router.go_replace(route);
                     UpdateResult::empty()
                 }
                 MsgApp::Back => {
@@ -172,6 +321,15 @@ impl Component for App {
                                 Route::Info
                                 | Route::Edit
                                 | Route::EditQuest(_)
+                                | Route::Timeline
+                                | Route::Graph
+                                | Route::Search(_)
+                                | Route::Crew
+                                | Route::Ledger
+                                | Route::Totems
+                                | Route::Inventory
+                                | Route::Score
+                                | Route::Sessions
                                 | Route::Settings => (),
                             }
                             match route {
@@ -179,13 +337,21 @@ impl Component for App {
                                 | Route::Todo
                                 | Route::Map
                                 | Route::Edit
+                                | Route::Timeline
+                                | Route::Graph
+                                | Route::Crew
+                                | Route::Ledger
+                                | Route::Totems
+                                | Route::Inventory
+                                | Route::Score
+                                | Route::Sessions
                                 | Route::Settings => UpdateResult::Render.into(),
                                 Route::TodoAction(q, l) => {
                                     if self.pane_action.go(&self.data, q, l, false) {
                                         UpdateResult::Render.into()
                                     } else {
                                         // not valid -> "redirect" to start page
-                                        self.router.go_replace(&self.data, Route::Todo);
+                                        self.router.go_replace(Route::Todo);
                                         UpdateResult::empty()
                                     }
                                 }
@@ -198,7 +364,7 @@ impl Component for App {
                                         UpdateResult::Render.into()
                                     } else {
                                         // not valid -> "redirect" to start page
-                                        self.router.go_replace(&self.data, Route::MapLocation(l));
+                                        self.router.go_replace(Route::MapLocation(l));
                                         UpdateResult::empty()
                                     }
                                 }
@@ -206,12 +372,16 @@ impl Component for App {
                                     self.pane_map_new_quest.go(l);
                                     UpdateResult::Render.into()
                                 }
+                                Route::Search(query) => {
+                                    self.pane_search.go(query);
+                                    UpdateResult::Render.into()
+                                }
                                 Route::EditQuest(q) => {
                                     if self.pane_edit_quest.go(&self.data, q) {
                                         UpdateResult::Render.into()
                                     } else {
                                         // not valid -> "redirect" to start page
-                                        self.router.go_replace(&self.data, Route::Edit);
+                                        self.router.go_replace(Route::Edit);
                                         UpdateResult::empty()
                                     }
                                 }
@@ -221,30 +391,89 @@ impl Component for App {
                     }
                 }
                 MsgApp::ResetToNew => {
-                    self.pane_todo.reset_to_new();
-                    self.pane_map.reset_to_new();
-                    self.pane_map_location.reset_to_new();
-                    self.pane_map_new_quest.reset_to_new();
-                    self.pane_action.reset_to_new();
-                    self.pane_edit.reset_to_new();
-                    self.pane_edit_quest.reset_to_new();
-                    self.pane_settings.reset_to_new();
+                    self.reset_panes_to_new();
 
                     self.route = Route::Todo;
                     UpdateResult::Render.into()
                 }
+                MsgApp::SwitchCampaign(campaign_id) => {
+                    self.data.campaign.active_mut().panes = self.save_campaign_panes();
+                    if self.data.campaign.set_active(campaign_id) {
+                        self.data.packs_changed();
+                        self.reset_panes_to_new();
+                        self.load_campaign_panes(self.data.campaign.active().panes.clone());
+                        self.load_game_data();
+                        self.history = History::new(
+                            self.data.campaign.active_id(),
+                            self.data.journal.save_game_data().into_owned(),
+                        );
+                        self.history.save();
+                        self.toast = None;
+                    }
+                    UpdateResult::Render | UpdateResult::SaveSettings
+                }
+                MsgApp::Undo => {
+                    if let Some((change, game_data)) = self.history.undo() {
+                        self.data
+                            .journal
+                            .load_game_data_8(game_data, &self.data.campaign.active().packs);
+                        self.show_toast(ctx, Toast::Undone(change));
+                        // the current page may not be valid anymore
+                        self.data.chain_msg.push_back(MsgApp::HistoryChanged);
+                        UpdateResult::Render | UpdateResult::SaveGameData
+                    } else {
+                        UpdateResult::empty()
+                    }
+                }
+                MsgApp::Redo => {
+                    if let Some((change, game_data)) = self.history.redo() {
+                        self.data
+                            .journal
+                            .load_game_data_8(game_data, &self.data.campaign.active().packs);
+                        self.show_toast(ctx, Toast::Redone(change));
+                        // the current page may not be valid anymore
+                        self.data.chain_msg.push_back(MsgApp::HistoryChanged);
+                        UpdateResult::Render | UpdateResult::SaveGameData
+                    } else {
+                        UpdateResult::empty()
+                    }
+                }
+                MsgApp::HideToast => {
+                    self.toast = None;
+                    self.toast_timeout = None;
+                    UpdateResult::Render.into()
+                }
+                MsgApp::UpdateAvailable => {
+                    self.update_available = true;
+                    UpdateResult::Render.into()
+                }
+                MsgApp::UpdateReload => {
+                    update_reload();
+                    UpdateResult::empty()
+                }
             };
         }
         if r.contains(UpdateResult::SaveGameData) {
-            self.data.cleanup();
+            self.data.journal.cleanup();
+            let game_data = self.data.journal.save_game_data();
             let _: Result<(), StorageError> =
-                LocalStorage::set(Self::STORAGE_KEY_GAME_DATA, self.data.save_game_data());
+                LocalStorage::set(self.data.campaign.active_id().storage_key(), &game_data);
             #[cfg(feature = "debug")]
-            web_sys::console::log_1(&JsValue::from_serde(&self.data.save_game_data()).unwrap());
+            web_sys::console::log_1(&JsValue::from_serde(&game_data).unwrap());
+            let game_data = game_data.into_owned();
+            if let Some(change) = self.data.change.take() {
+                self.history.record(change.clone(), game_data);
+                self.show_toast(ctx, Toast::Changed(change));
+                r |= UpdateResult::Render;
+            } else {
+                self.history.replace_current(game_data);
+            }
+            self.history.save();
         }
         if r.contains(UpdateResult::SaveSettings) {
             #[cfg(feature = "debug")]
             web_sys::console::log_1(&"Settings should be saved".into());
+            self.data.campaign.active_mut().panes = self.save_campaign_panes();
             self.save_settings = true;
         }
         r.contains(UpdateResult::Render)
@@ -263,11 +492,29 @@ impl Component for App {
             Route::Settings => self.pane_settings.view(&self.data, ctx),
             Route::Edit => self.pane_edit.view(&self.data, ctx),
             Route::EditQuest(_) => self.pane_edit_quest.view(&self.data, ctx),
+            Route::Timeline => self.pane_timeline.view(&self.data, ctx),
+            Route::Graph => graph_view(&self.data, ctx),
+            Route::Search(_) => self.pane_search.view(&self.data, ctx),
+            Route::Crew => self.pane_crew.view(&self.data, ctx),
+            Route::Ledger => self.pane_ledger.view(&self.data, ctx),
+            Route::Totems => self.pane_totems.view(&self.data, ctx),
+            Route::Inventory => self.pane_inventory.view(&self.data, ctx),
+            Route::Score => self.pane_score.view(&self.data, ctx),
+            Route::Sessions => self.pane_sessions.view(&self.data, ctx),
         };
         let nav_bar = [
             (Route::Info, self.data.msg.nav_info()),
             (if self.quests_is_map { Route::Map } else { Route::Todo }, self.data.msg.nav_quests()),
+            (Route::Crew, self.data.msg.nav_crew()),
+            (Route::Ledger, self.data.msg.nav_ledger()),
+            (Route::Totems, self.data.msg.nav_totems()),
+            (Route::Inventory, self.data.msg.nav_inventory()),
+            (Route::Score, self.data.msg.nav_score()),
+            (Route::Sessions, self.data.msg.nav_sessions()),
             (Route::Edit, self.data.msg.nav_edit()),
+            (Route::Timeline, self.data.msg.nav_timeline()),
+            (Route::Graph, self.data.msg.nav_graph()),
+            (Route::Search(String::new()), self.data.msg.nav_search()),
             (Route::Settings, self.data.msg.nav_settings()),
         ]
             .into_iter()
@@ -326,10 +573,36 @@ impl Component for App {
               <div class="container-fluid">
                 <a class="navbar-brand" href="#">
                   {"Unofficial Sleeping Gods Journal"}
+                  if self.data.campaign.len() > 1 {
+                    <small class="ms-2 text-body-secondary">
+                      {self.data.campaign.active().display_name(self.data.msg)}
+                    </small>
+                  }
                 </a>
 
                 {nav_bar2}
 
+                <div class="btn-group ms-auto me-2" role="group">
+                  <button
+                    type="button"
+                    class="btn btn-outline-secondary btn-sm"
+                    title={self.history.undo_change().map(|change| self.data.msg.str_undo_toast_changed(change))}
+                    disabled={self.history.undo_change().is_none()}
+                    onclick={ctx.link().callback(|_| MsgApp::Undo)}
+                  >
+                    {BI::ARROW_COUNTERCLOCKWISE}
+                  </button>
+                  <button
+                    type="button"
+                    class="btn btn-outline-secondary btn-sm"
+                    title={self.history.redo_change().map(|change| self.data.msg.str_undo_redo_title(change))}
+                    disabled={self.history.redo_change().is_none()}
+                    onclick={ctx.link().callback(|_| MsgApp::Redo)}
+                  >
+                    {BI::ARROW_CLOCKWISE}
+                  </button>
+                </div>
+
                 <div class="navbar-nav">
                   <ul class="nav nav-pills">
                     { for nav_bar }
@@ -338,6 +611,15 @@ impl Component for App {
               </div>
             </nav>
 
+            if self.update_available {
+              <div class="alert alert-info d-flex align-items-center rounded-0 mb-0 py-2" role="status">
+                {self.data.msg.update_available()}
+                <button type="button" class="btn btn-primary btn-sm ms-auto" onclick={ctx.link().callback(|_| MsgApp::UpdateReload)}>
+                  {BI::ARROW_REPEAT}{self.data.msg.update_reload()}
+                </button>
+              </div>
+            }
+
             <main class="py-4">
               <div class="container">
                 { inner }
@@ -363,6 +645,8 @@ impl Component for App {
                 </div>
               </div>
             </nav>
+
+            {self.view_toast(ctx)}
           </div>
         }
     }
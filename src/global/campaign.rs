@@ -0,0 +1,184 @@
+use crate::game::{MsgLanguage, PackId};
+use crate::ser::settings::SerdeCampaignPanes;
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::slice::Iter;
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+#[repr(transparent)]
+pub(crate) struct CampaignId(u32);
+
+impl CampaignId {
+    const STORAGE_KEY_GAME_DATA: &'static str = "sleeping-gods-journal.game-data";
+
+    // the first campaign uses the key from before campaigns existed, thus no migration is required
+    pub(crate) fn storage_key(self) -> Cow<'static, str> {
+        if self.0 == 0 {
+            Cow::Borrowed(Self::STORAGE_KEY_GAME_DATA)
+        } else {
+            Cow::Owned(format!("{}.{}", Self::STORAGE_KEY_GAME_DATA, self.0))
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub(crate) fn number(self) -> u32 {
+        self.0 + 1
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct Campaign {
+    pub(crate) id: CampaignId,
+    pub(crate) name: String,
+    // the enabled content packs, never empty
+    pub(crate) packs: Vec<PackId>,
+    pub(crate) panes: SerdeCampaignPanes,
+}
+
+impl Campaign {
+    // enables or disables a pack, the last enabled pack can't be disabled
+    pub(crate) fn set_pack(&mut self, pack: PackId, enabled: bool) -> bool {
+        if enabled {
+            if self.packs.contains(&pack) {
+                return false;
+            }
+            self.packs.push(pack);
+            self.packs.sort();
+            true
+        } else if self.packs.len() > 1 && self.packs.contains(&pack) {
+            self.packs.retain(|p| *p != pack);
+            true
+        } else {
+            false
+        }
+    }
+
+    // an empty name is displayed as a numbered default name
+    pub(crate) fn display_name(&self, msg: MsgLanguage) -> Cow<'_, str> {
+        if self.name.is_empty() {
+            Cow::Owned(msg.str_sett_camp_default_name(&self.id.number().to_string()))
+        } else {
+            Cow::Borrowed(&self.name)
+        }
+    }
+}
+
+pub(crate) struct Campaigns {
+    active: CampaignId,
+    list: Vec<Campaign>,
+}
+
+impl Campaigns {
+    #[must_use]
+    pub(crate) fn new() -> Self {
+        Self {
+            active: CampaignId::default(),
+            list: vec![Campaign {
+                id: CampaignId::default(),
+                name: String::new(),
+                packs: vec![PackId::base()],
+                panes: SerdeCampaignPanes::default(),
+            }],
+        }
+    }
+
+    // replaces all campaigns, the list must not be empty
+    pub(crate) fn load(&mut self, active: CampaignId, list: Vec<Campaign>) {
+        if let Some(first) = list.first() {
+            self.active = if list.iter().any(|c| c.id == active) {
+                active
+            } else {
+                first.id
+            };
+            self.list = list;
+        }
+    }
+
+    #[inline]
+    pub(crate) fn iter(&self) -> Iter<'_, Campaign> {
+        self.list.iter()
+    }
+
+    #[inline]
+    #[must_use]
+    pub(crate) fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub(crate) fn active_id(&self) -> CampaignId {
+        self.active
+    }
+
+    #[must_use]
+    pub(crate) fn get(&self, id: CampaignId) -> Option<&Campaign> {
+        self.list.iter().find(|c| c.id == id)
+    }
+
+    pub(crate) fn get_mut(&mut self, id: CampaignId) -> Option<&mut Campaign> {
+        self.list.iter_mut().find(|c| c.id == id)
+    }
+
+    #[must_use]
+    pub(crate) fn active(&self) -> &Campaign {
+        self.get(self.active)
+            .expect("the active campaign always exists")
+    }
+
+    pub(crate) fn active_mut(&mut self) -> &mut Campaign {
+        let active = self.active;
+        self.get_mut(active)
+            .expect("the active campaign always exists")
+    }
+
+    pub(crate) fn set_active(&mut self, id: CampaignId) -> bool {
+        if self.get(id).is_some() {
+            self.active = id;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub(crate) fn add(
+        &mut self,
+        name: String,
+        packs: Vec<PackId>,
+        panes: SerdeCampaignPanes,
+    ) -> CampaignId {
+        let id = CampaignId(self.list.iter().map(|c| c.id.0 + 1).max().unwrap_or(0));
+        self.list.push(Campaign {
+            id,
+            name,
+            packs,
+            panes,
+        });
+        id
+    }
+
+    // the active campaign can't be removed (and with that never the last one)
+    pub(crate) fn remove(&mut self, id: CampaignId) -> bool {
+        if id == self.active {
+            return false;
+        }
+        let len = self.list.len();
+        self.list.retain(|c| c.id != id);
+        if self.list.len() == len {
+            false
+        } else {
+            LocalStorage::delete(id.storage_key());
+            true
+        }
+    }
+
+    // copies the stored game data of one campaign to another one
+    pub(crate) fn copy_stored_game_data(from: CampaignId, to: CampaignId) {
+        if let Ok(Some(game_data)) = LocalStorage::raw().get_item(&from.storage_key()) {
+            let _ = LocalStorage::raw().set_item(&to.storage_key(), &game_data);
+        }
+    }
+}
@@ -1,74 +1,74 @@
-use crate::data::encounter_type::EncounterType;
-use crate::data::note::Note;
 use crate::data::quest::{Quest, QuestState};
-use crate::data::vis::Vis;
 use crate::game::{LocationId, MsgLanguage, QuestId, QuestLocale};
 use crate::global::app::MsgApp;
+use crate::global::campaign::Campaigns;
 use crate::html::text;
-use std::collections::{HashMap, VecDeque};
-use std::iter::{Copied, FilterMap, Map};
-use std::slice::Iter;
+use sleeping_journal_core::journal::Journal;
+use std::collections::VecDeque;
 use yew::Html;
 
 pub(crate) struct Data {
     // game data
-    pub(crate) quest: HashMap<QuestId, Quest>,
-    pub(crate) location: HashMap<LocationId, Note>,
+    pub(crate) journal: Journal,
     // global settings
+    pub(crate) campaign: Campaigns,
     pub(crate) quest_locale: QuestLocale,
     pub(crate) msg: MsgLanguage,
+    pub(crate) spoiler_safe: bool,
     // messages
     pub(crate) chain_msg: VecDeque<MsgApp>,
+    pub(crate) change: Option<String>,
 }
 
-type QuestAllIter<'a> =
-    Map<Copied<Iter<'a, (QuestId, &'static str)>>, fn((QuestId, &'static str)) -> (QuestId, Html)>;
-
 impl Data {
     pub(crate) fn reset(&mut self) {
-        self.quest.clear();
-        self.location.clear();
-        self.built_in();
+        self.journal.reset(&self.campaign.active().packs);
     }
 
-    pub(crate) fn cleanup(&mut self) {
-        self.quest.retain(|_, quest| {
-            quest
-                .encounter
-                .retain(|_, quest_location| !quest_location.is_empty());
-            !quest.encounter.is_empty() || !quest.note.is_empty()
-        });
+    // must be called after the enabled packs of the active campaign changed
+    pub(crate) fn packs_changed(&mut self) {
+        self.quest_locale.set_packs(&self.campaign.active().packs);
+        self.journal.built_in(&self.campaign.active().packs);
     }
 
-    fn built_in(&mut self) {
-        for quest_id in [QuestId::raid(), QuestId::cottage()] {
-            let quest = self.quest.entry(quest_id).or_default();
-            if quest.state != QuestState::Removed {
-                quest.state = QuestState::InGame;
-            }
-            let loc_pro = quest.encounter.entry(LocationId::prologue()).or_default();
-            loc_pro.clear();
-            loc_pro.insert(EncounterType::Gain, None, Vis::Visible);
-        }
+    // with the spoiler protection only quests with an encounter or a state are revealed
+    #[must_use]
+    pub(crate) fn is_revealed(&self, quest_id: QuestId) -> bool {
+        !self.spoiler_safe
+            || self.journal.quest.get(&quest_id).is_some_and(|quest| {
+                quest.state != QuestState::NotFound || !quest.encounter.is_empty()
+            })
     }
 
-    pub(crate) fn quest_all_iter(&self) -> QuestAllIter<'_> {
-        self.quest_locale.all_str().map(|(l, n)| (l, text(n)))
+    #[must_use]
+    pub(crate) fn quest_name(&self, quest_id: QuestId) -> &'static str {
+        if self.is_revealed(quest_id) {
+            self.quest_locale.get(quest_id)
+        } else {
+            self.msg.str_spoiler_masked()
+        }
     }
 
-    #[allow(clippy::type_complexity)]
-    pub(crate) fn quest_iter<'a>(
-        &'a self,
-    ) -> FilterMap<
-        Copied<Iter<'a, (QuestId, &'static str)>>,
-        impl FnMut((QuestId, &'static str)) -> Option<(QuestId, &'a Quest, &'static str)>,
-    > {
+    pub(crate) fn quest_all_iter(&self) -> impl Iterator<Item = (QuestId, Html)> + '_ {
         self.quest_locale
             .all_str()
-            .filter_map(move |(quest_id, quest_name)| {
-                self.quest
-                    .get(&quest_id)
-                    .map(move |quest| (quest_id, quest, quest_name))
-            })
+            .filter(|(quest_id, _)| self.is_revealed(*quest_id))
+            .map(|(l, n)| (l, text(n)))
+    }
+
+    // the locations of the enabled packs
+    pub(crate) fn location_iter(&self) -> impl Iterator<Item = LocationId> + '_ {
+        self.campaign
+            .active()
+            .packs
+            .iter()
+            .flat_map(|pack| LocationId::all_of(*pack))
+            .filter(|location_id| *location_id != LocationId::prologue())
+    }
+
+    pub(crate) fn quest_iter(&self) -> impl Iterator<Item = (QuestId, &Quest, &'static str)> {
+        self.journal
+            .quest_iter(&self.quest_locale)
+            .filter(|(quest_id, _, _)| self.is_revealed(*quest_id))
     }
 }
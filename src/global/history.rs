@@ -0,0 +1,100 @@
+use crate::global::campaign::CampaignId;
+use gloo_storage::{SessionStorage, Storage};
+use serde::{Deserialize, Serialize};
+use sleeping_journal_core::ser::game_data_8::SerdeGameData8;
+use std::collections::VecDeque;
+use std::mem;
+
+#[derive(Serialize, Deserialize)]
+struct HistoryEntry {
+    change: String,
+    game_data: SerdeGameData8<'static>,
+}
+
+// The undo/redo history of the game data of one campaign,
+// it's stored in the session storage and thus survives reloads but not the closing of the tab.
+#[derive(Default, Serialize, Deserialize)]
+pub(crate) struct History {
+    campaign: CampaignId,
+    current: SerdeGameData8<'static>,
+    undo: VecDeque<HistoryEntry>,
+    redo: Vec<HistoryEntry>,
+}
+
+impl History {
+    const STORAGE_KEY_HISTORY: &'static str = "sleeping-gods-journal.history";
+    const MAX_ENTRIES: usize = 25;
+
+    #[must_use]
+    pub(crate) fn new(campaign: CampaignId, current: SerdeGameData8<'static>) -> Self {
+        Self {
+            campaign,
+            current,
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+        }
+    }
+
+    // restores the history of the session, if it's from the same campaign
+    #[must_use]
+    pub(crate) fn load(campaign: CampaignId, current: SerdeGameData8<'static>) -> Self {
+        match SessionStorage::get::<History>(Self::STORAGE_KEY_HISTORY) {
+            Ok(history) if history.campaign == campaign => Self { current, ..history },
+            _ => Self::new(campaign, current),
+        }
+    }
+
+    pub(crate) fn save(&self) {
+        if SessionStorage::set(Self::STORAGE_KEY_HISTORY, self).is_err() {
+            // most likely the quota is exceeded, the history is lost on reload then
+            SessionStorage::delete(Self::STORAGE_KEY_HISTORY);
+        }
+    }
+
+    // the game data has changed by a user action
+    pub(crate) fn record(&mut self, change: String, game_data: SerdeGameData8<'static>) {
+        let game_data = mem::replace(&mut self.current, game_data);
+        self.undo.push_back(HistoryEntry { change, game_data });
+        if self.undo.len() > Self::MAX_ENTRIES {
+            self.undo.pop_front();
+        }
+        self.redo.clear();
+    }
+
+    // the game data has changed, but not in a way which can be undone (e.g. cleanup)
+    pub(crate) fn replace_current(&mut self, game_data: SerdeGameData8<'static>) {
+        self.current = game_data;
+    }
+
+    #[must_use]
+    pub(crate) fn undo_change(&self) -> Option<&str> {
+        self.undo.back().map(|entry| entry.change.as_str())
+    }
+
+    #[must_use]
+    pub(crate) fn redo_change(&self) -> Option<&str> {
+        self.redo.last().map(|entry| entry.change.as_str())
+    }
+
+    // returns the description of the change and the game data to restore
+    pub(crate) fn undo(&mut self) -> Option<(String, SerdeGameData8<'static>)> {
+        let entry = self.undo.pop_back()?;
+        let game_data = mem::replace(&mut self.current, entry.game_data);
+        self.redo.push(HistoryEntry {
+            change: entry.change.clone(),
+            game_data,
+        });
+        Some((entry.change, self.current.clone()))
+    }
+
+    // returns the description of the change and the game data to restore
+    pub(crate) fn redo(&mut self) -> Option<(String, SerdeGameData8<'static>)> {
+        let entry = self.redo.pop()?;
+        let game_data = mem::replace(&mut self.current, entry.game_data);
+        self.undo.push_back(HistoryEntry {
+            change: entry.change.clone(),
+            game_data,
+        });
+        Some((entry.change, self.current.clone()))
+    }
+}
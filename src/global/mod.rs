@@ -1,2 +1,4 @@
 pub(crate) mod app;
+pub(crate) mod campaign;
 pub(crate) mod data;
+pub(crate) mod history;
@@ -1,6 +1,9 @@
 use crate::global::app::{App, MsgApp};
 use crate::global::data::Data;
-use web_sys::{Event, HtmlElement, HtmlInputElement};
+use base64::Engine;
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::wasm_bindgen::closure::Closure;
+use web_sys::{Event, HtmlElement, HtmlInputElement, HtmlTextAreaElement, KeyboardEvent};
 use yew::virtual_dom::{VNode, VText};
 use yew::{AttrValue, Callback, Context, Html, NodeRef, TargetCast, html};
 
@@ -11,6 +14,20 @@ pub(crate) const fn text(content: &'static str) -> Html {
     })
 }
 
+// the content is offered as a file by clicking the hidden link
+pub(crate) fn download<T: AsRef<[u8]>>(link: &NodeRef, mime: &str, file_name: &str, content: T) {
+    let content = content.as_ref();
+    let mut file = String::with_capacity((50 + content.len()) * 6 / 8);
+    file.push_str("data:");
+    file.push_str(mime);
+    file.push_str(";charset=UTF-8;base64,");
+    base64::engine::general_purpose::STANDARD.encode_string(content, &mut file);
+    let link: HtmlElement = link.cast::<HtmlElement>().unwrap();
+    let _ = link.set_attribute("href", &file);
+    let _ = link.set_attribute("download", file_name);
+    link.click();
+}
+
 #[inline]
 pub(crate) fn callback_input_value<F, M, S>(ctx: &Context<App>, f: F) -> Callback<Event>
 where
@@ -22,6 +39,57 @@ where
         .callback(move |e: Event| f(e.target_unchecked_into::<HtmlInputElement>().value().into()))
 }
 
+// listens for undo (ctrl+z) and redo (ctrl+shift+z or ctrl+y), text fields keep their own undo
+pub(crate) fn listen_undo_keys(ctx: &Context<App>) -> Closure<dyn Fn(KeyboardEvent)> {
+    let link = ctx.link().clone();
+    let listener = Closure::<dyn Fn(KeyboardEvent)>::new(move |event: KeyboardEvent| {
+        if !(event.ctrl_key() || event.meta_key()) || event.alt_key() {
+            return;
+        }
+        if event.target().is_some_and(|target| {
+            target.has_type::<HtmlInputElement>() || target.has_type::<HtmlTextAreaElement>()
+        }) {
+            return;
+        }
+        let msg = match event.key().to_lowercase().as_str() {
+            "z" if event.shift_key() => MsgApp::Redo,
+            "z" => MsgApp::Undo,
+            "y" => MsgApp::Redo,
+            _ => return,
+        };
+        event.prevent_default();
+        link.send_message(msg);
+    });
+    let _ = gloo_utils::document()
+        .add_event_listener_with_callback("keydown", listener.as_ref().unchecked_ref());
+    listener
+}
+
+// the service worker (see index.html) has installed a new version
+pub(crate) fn listen_update(ctx: &Context<App>) -> Closure<dyn Fn(Event)> {
+    let link = ctx.link().clone();
+    let listener = Closure::<dyn Fn(Event)>::new(move |_: Event| {
+        link.send_message(MsgApp::UpdateAvailable);
+    });
+    let _ = gloo_utils::document()
+        .add_event_listener_with_callback("sw-update", listener.as_ref().unchecked_ref());
+    listener
+}
+
+// the event may have been sent before the app was started
+pub(crate) fn update_available() -> bool {
+    gloo_utils::document()
+        .document_element()
+        .is_some_and(|element| element.has_attribute("data-update"))
+}
+
+// activates the new version and reloads the page
+pub(crate) fn update_reload() {
+    if let Ok(event) = Event::new("sw-reload") {
+        let _ = gloo_utils::document().dispatch_event(&event);
+    }
+}
+
 #[derive(Default)]
 pub(crate) struct Modal {
     head: NodeRef,
@@ -15,6 +15,7 @@ pub(crate) mod global;
 pub(crate) mod html;
 pub(crate) mod modal;
 pub(crate) mod pane;
+pub(crate) mod quest_picker;
 pub(crate) mod route;
 pub(crate) mod ser;
 
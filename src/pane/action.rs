@@ -1,7 +1,9 @@
-use crate::data::encounter_type::EncounterType;
-use crate::data::quest::{Quest, QuestState};
+use crate::data::encounter_type::{EncounterType, EncounterTypeExt};
+use crate::data::event::EventAction;
+use crate::data::prerequisite::PrerequisiteExt;
+use crate::data::quest::{Quest, QuestState, QuestStateExt};
 use crate::data::sub_component::{SubComponent, UpdateResult, UpdateResults};
-use crate::data::vis::Vis;
+use crate::data::vis::{Vis, VisExt};
 use crate::game::{LocationId, MsgLanguage, QuestId};
 use crate::global::app::{App, MsgApp};
 use crate::global::data::Data;
@@ -51,24 +53,40 @@ impl SubComponent for PaneAction {
         _ctx: &Context<App>,
         msg: Self::Message,
     ) -> UpdateResults {
+        let quest_name = data.quest_locale.get(self.quest_id);
+        let location_name = self.location_id.name(data.quest_locale.language());
         match msg {
             MsgAction::Perform(encounter_type) => {
+                data.change = Some(encounter_type.str_change(data.msg, quest_name, location_name));
+                data.journal.record_event(
+                    EventAction::Perform(encounter_type),
+                    Some(self.quest_id),
+                    Some(self.location_id),
+                );
                 match encounter_type {
                     EncounterType::Unless | EncounterType::When => None,
                     EncounterType::Gain => data
+                        .journal
                         .quest
                         .get_mut(&self.quest_id)
                         .map(|quest| quest.state = QuestState::InGame),
-                    EncounterType::Complete | EncounterType::Lose => data
+                    EncounterType::Complete => data
+                        .journal
                         .quest
                         .get_mut(&self.quest_id)
-                        .map(|quest| quest.state = QuestState::Removed),
+                        .map(|quest| quest.state = QuestState::Completed),
+                    EncounterType::Lose => data
+                        .journal
+                        .quest
+                        .get_mut(&self.quest_id)
+                        .map(|quest| quest.state = QuestState::Lost),
                 };
                 data.chain_msg.push_back(MsgApp::Back);
                 UpdateResult::SaveGameData.into()
             }
             MsgAction::Hide(encounter_type, vis, back) => {
                 if let Some(qle) = data
+                    .journal
                     .quest
                     .get_mut(&self.quest_id)
                     .and_then(|quest| quest.encounter.get_mut(&self.location_id))
@@ -76,22 +94,34 @@ impl SubComponent for PaneAction {
                 {
                     qle.vis = vis;
                 }
+                data.change = Some(data.msg.str_change_encounter_vis(quest_name, location_name));
+                data.journal.record_event(
+                    EventAction::EncounterVis(encounter_type, vis),
+                    Some(self.quest_id),
+                    Some(self.location_id),
+                );
                 if back {
                     data.chain_msg.push_back(MsgApp::Back);
                 }
                 UpdateResult::SaveGameData.into()
             }
             MsgAction::Note(note) => {
-                let quest = data.quest.get_mut(&self.quest_id).unwrap();
+                let quest = data.journal.quest.get_mut(&self.quest_id).unwrap();
 
                 quest.note = note.into();
+                data.change = Some(data.msg.str_change_quest_note(quest_name));
+                data.journal
+                    .record_event(EventAction::QuestNote, Some(self.quest_id), None);
 
                 UpdateResult::SaveGameData.into()
             }
             MsgAction::HideQuest(vis) => {
-                if let Some(q) = data.quest.get_mut(&self.quest_id) {
+                if let Some(q) = data.journal.quest.get_mut(&self.quest_id) {
                     q.vis = vis;
                 }
+                data.change = Some(data.msg.str_change_quest_vis(quest_name));
+                data.journal
+                    .record_event(EventAction::QuestVis(vis), Some(self.quest_id), None);
 
                 data.chain_msg.push_back(MsgApp::Back);
 
@@ -101,7 +131,7 @@ impl SubComponent for PaneAction {
     }
 
     fn view(&self, data: &Data, ctx: &Context<App>) -> Html {
-        let quest = &data.quest[&self.quest_id];
+        let quest = &data.journal.quest[&self.quest_id];
         let quest_location = quest.encounter.get(&self.location_id);
         let ignore_visibility = self.is_map;
 
@@ -109,7 +139,7 @@ impl SubComponent for PaneAction {
             quest_location.map_or(Default::default(), |quest_location| {
                 (
                     quest_location.iter(),
-                    quest_location.get_active(quest, data, ignore_visibility),
+                    quest_location.get_active(quest, &data.journal, ignore_visibility),
                 )
             });
 
@@ -119,6 +149,19 @@ impl SubComponent for PaneAction {
                 let active = active_quest_location[encounter_type];
                 let encounter_type = *encounter_type;
                 let message = et2msg(data.msg, encounter_type, quest, active);
+                let prerequisite = qle.prerequisite.as_ref().map(|prerequisite| {
+                    if prerequisite.is_met(&data.journal) {
+                        data.msg.str_acti_prerequisite_met(&prerequisite.text(data))
+                    } else {
+                        data.msg.str_acti_prerequisite_not_met(&prerequisite.text(data))
+                    }
+                });
+                let prerequisite = prerequisite.map(|prerequisite| html! {
+                    <>
+                        <small>{prerequisite}</small>
+                        <br/>
+                    </>
+                });
                 let message = if let Some(message) = message {
                     html! {
                         <>
@@ -138,6 +181,7 @@ impl SubComponent for PaneAction {
                     html! {
                         <li class="list-group-item">
                             {data.msg.location_with_icon(  self.location_id.name(data.quest_locale.language()), encounter_type.icon(active))}<br/>
+                            {prerequisite}
                             {message}
                             {PaneEditQuest::select_hidden(
                                 data,
@@ -153,6 +197,7 @@ impl SubComponent for PaneAction {
                 } else {
                     html! {
                     <li class="list-group-item">
+                        {prerequisite}
                         {message}
                         <Button style={Vis::HiddenThisCampaign.to_style()} onclick={ctx.link().callback(move |_|MsgAction::Hide(encounter_type, Vis::HiddenThisCampaign, true))}>{data.msg.todo_do_hide_this_campaign(encounter_type.icon(active))}</Button>
                         <br/>
@@ -173,7 +218,7 @@ impl SubComponent for PaneAction {
                 <li class="list-group-item">
                     {data.msg.note_quest()}<br/>
                     <div class="form-floating">
-                      <textarea class="form-control" placeholder={data.msg.str_note_placeholder()} id="floatingTextarea" onchange={callback_input_value(ctx, MsgAction::Note)} value={quest.note.clone()} style="height: 150px"/>
+                      <textarea class="form-control" placeholder={data.msg.str_note_placeholder()} id="floatingTextarea" onchange={callback_input_value(ctx, MsgAction::Note)} value={quest.note.to_string()} style="height: 150px"/>
                       <label for="floatingTextarea">{data.msg.note()}</label>
                     </div>
                 </li>
@@ -226,15 +271,19 @@ fn et2msg(
         EncounterType::Complete => {
             if active {
                 Some(msg_language.str_acti_complete_quest())
-            } else {
+            } else if quest.state != QuestState::InGame {
                 Some(msg_language.str_acti_complete_quest_no())
+            } else {
+                Some(msg_language.str_acti_complete_quest_no_pre())
             }
         }
         EncounterType::Lose => {
             if active {
                 Some(msg_language.str_acti_lose_quest())
-            } else {
+            } else if quest.state != QuestState::InGame {
                 Some(msg_language.str_acti_lose_quest_no())
+            } else {
+                Some(msg_language.str_acti_lose_quest_no_pre())
             }
         }
     }
@@ -248,7 +297,7 @@ impl PaneAction {
         location_id: LocationId,
         is_map: bool,
     ) -> bool {
-        if let Some(q) = data.quest.get(&quest_id) {
+        if let Some(q) = data.journal.quest.get(&quest_id) {
             if q.encounter.contains_key(&location_id) {
                 self.quest_id = quest_id;
                 self.location_id = location_id;
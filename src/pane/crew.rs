@@ -0,0 +1,220 @@
+use crate::data::sub_component::{SubComponent, UpdateResult, UpdateResults};
+use crate::global::app::{App, MsgApp};
+use crate::global::data::Data;
+use crate::html::callback_input_value;
+use crate::ser::settings::EmptySer;
+use sleeping_journal_core::data::crew::CrewMember;
+use yew::{Context, Html, html};
+use yew_bootstrap::component::{Button, ButtonGroup};
+use yew_bootstrap::icons::BI;
+use yew_bootstrap::util::Color;
+
+#[derive(Clone)]
+pub(crate) enum MsgCrew {
+    Add,
+    Remove(usize),
+    Name(usize, String),
+    Injuries(usize, String),
+    Change(usize, CrewValue, bool),
+}
+
+impl From<MsgCrew> for MsgApp {
+    #[inline]
+    fn from(msg: MsgCrew) -> Self {
+        MsgApp::MsgCrew(msg)
+    }
+}
+
+#[derive(Copy, Clone)]
+pub(crate) enum CrewValue {
+    Health,
+    MaxHealth,
+    Fatigue,
+    Level,
+}
+
+pub(crate) struct PaneCrew {}
+
+impl SubComponent for PaneCrew {
+    type Message = MsgCrew;
+    type Ser = EmptySer;
+
+    fn create(_ctx: &Context<App>) -> Self {
+        Self {}
+    }
+
+    fn update(
+        &mut self,
+        data: &mut Data,
+        _ctx: &Context<App>,
+        msg: Self::Message,
+    ) -> UpdateResults {
+        match msg {
+            MsgCrew::Add => {
+                data.journal.crew.push(CrewMember::new(
+                    String::new(),
+                    CrewMember::DEFAULT_MAX_HEALTH,
+                ));
+                data.change = Some(data.msg.str_change_crew_add().to_string());
+                UpdateResult::Render | UpdateResult::SaveGameData
+            }
+            MsgCrew::Remove(pos) => {
+                if pos < data.journal.crew.len() {
+                    let crew_member = data.journal.crew.remove(pos);
+                    data.change = Some(data.msg.str_change_crew_remove(&crew_member.name));
+                    UpdateResult::Render | UpdateResult::SaveGameData
+                } else {
+                    UpdateResult::empty()
+                }
+            }
+            MsgCrew::Name(pos, name) => change(data, pos, |crew_member| crew_member.name = name),
+            MsgCrew::Injuries(pos, injuries) => {
+                change(data, pos, |crew_member| crew_member.injuries = injuries)
+            }
+            MsgCrew::Change(pos, value, increase) => {
+                change(data, pos, |crew_member| step(crew_member, value, increase))
+            }
+        }
+    }
+
+    fn view(&self, data: &Data, ctx: &Context<App>) -> Html {
+        let counter = |pos: usize, value: CrewValue, current: Html| {
+            html! {
+                <ButtonGroup>
+                    <Button
+                        style={Color::Secondary}
+                        outline={true}
+                        onclick={ctx.link().callback(move |_|MsgCrew::Change(pos, value, false))}
+                    >
+                        {BI::DASH}
+                    </Button>
+                    <span class="btn btn-outline-secondary disabled text-body">{current}</span>
+                    <Button
+                        style={Color::Secondary}
+                        outline={true}
+                        onclick={ctx.link().callback(move |_|MsgCrew::Change(pos, value, true))}
+                    >
+                        {BI::PLUS}
+                    </Button>
+                </ButtonGroup>
+            }
+        };
+        let crew = data.journal.crew.iter().enumerate().map(|(pos, crew_member)| {
+            html! {
+                <tr>
+                    <td>
+                        <input
+                            type="text"
+                            class="form-control"
+                            placeholder={data.msg.str_crew_name_placeholder()}
+                            value={crew_member.name.clone()}
+                            onchange={callback_input_value(ctx, move |name| MsgCrew::Name(pos, name))}
+                        />
+                    </td>
+                    <td>
+                        {counter(
+                            pos,
+                            CrewValue::Health,
+                            html! {<>{crew_member.health}{" / "}{crew_member.max_health}</>},
+                        )}
+                    </td>
+                    <td>{counter(pos, CrewValue::MaxHealth, html! {{crew_member.max_health}})}</td>
+                    <td>{counter(pos, CrewValue::Fatigue, html! {{crew_member.fatigue}})}</td>
+                    <td>{counter(pos, CrewValue::Level, html! {{crew_member.level}})}</td>
+                    <td>
+                        <input
+                            type="text"
+                            class="form-control"
+                            placeholder={data.msg.str_crew_injuries_placeholder()}
+                            value={crew_member.injuries.clone()}
+                            onchange={callback_input_value(ctx, move |injuries| MsgCrew::Injuries(pos, injuries))}
+                        />
+                    </td>
+                    <td>
+                        <Button
+                            style={Color::Danger}
+                            outline={true}
+                            onclick={ctx.link().callback(move |_|MsgCrew::Remove(pos))}
+                        >
+                            {BI::TRASH}
+                        </Button>
+                    </td>
+                </tr>
+            }
+        });
+        html! {
+            <>
+            <div class="d-flex align-items-center mb-4">
+              <h2 class="h4 mb-0">
+                {data.msg.crew_header()}
+              </h2>
+              <Button
+                style={Color::Primary}
+                class="ms-auto"
+                onclick={ctx.link().callback(|_|MsgCrew::Add)}
+              >
+                {BI::PERSON_PLUS}
+                {data.msg.crew_add()}
+              </Button>
+            </div>
+
+            if data.journal.crew.is_empty() {
+                <p>{data.msg.crew_empty()}</p>
+            } else {
+                <div class="table-responsive">
+                    <table class="table align-middle">
+                        <thead>
+                            <tr>
+                                <th>{data.msg.crew_name()}</th>
+                                <th>{data.msg.crew_health()}</th>
+                                <th>{data.msg.crew_max_health()}</th>
+                                <th>{data.msg.crew_fatigue()}</th>
+                                <th>{data.msg.crew_level()}</th>
+                                <th>{data.msg.crew_injuries()}</th>
+                                <th></th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            {for crew}
+                        </tbody>
+                    </table>
+                </div>
+            }
+            </>
+        }
+    }
+
+    fn save(&self) -> Self::Ser {
+        EmptySer {}
+    }
+
+    fn load(&mut self, _stored: Self::Ser) {}
+}
+
+fn change<F>(data: &mut Data, pos: usize, f: F) -> UpdateResults
+where
+    F: FnOnce(&mut CrewMember),
+{
+    let Some(crew_member) = data.journal.crew.get_mut(pos) else {
+        return UpdateResult::empty();
+    };
+    f(crew_member);
+    data.change = Some(data.msg.str_change_crew(&crew_member.name));
+    UpdateResult::Render | UpdateResult::SaveGameData
+}
+
+// one more or less, the health stays within the max health
+fn step(crew_member: &mut CrewMember, value: CrewValue, increase: bool) {
+    let (field, min, max) = match value {
+        CrewValue::Health => (&mut crew_member.health, 0, crew_member.max_health),
+        CrewValue::MaxHealth => (&mut crew_member.max_health, 1, u8::MAX),
+        CrewValue::Fatigue => (&mut crew_member.fatigue, 0, u8::MAX),
+        CrewValue::Level => (&mut crew_member.level, CrewMember::START_LEVEL, u8::MAX),
+    };
+    *field = if increase {
+        field.saturating_add(1).min(max)
+    } else {
+        field.saturating_sub(1).max(min)
+    };
+    crew_member.health = crew_member.health.min(crew_member.max_health);
+}
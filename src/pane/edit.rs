@@ -1,7 +1,9 @@
-use crate::data::sub_component::{SubComponent, UpdateResults};
-use crate::data::vis::Vis;
+use crate::data::quest::QuestExt;
+use crate::data::sub_component::{SubComponent, UpdateResult, UpdateResults};
+use crate::data::vis::{Vis, VisExt};
 use crate::global::app::{App, MsgApp};
 use crate::global::data::Data;
+use crate::quest_picker::{QuestMatch, QuestPicker};
 use crate::route::Route;
 use crate::ser::settings::EmptySer;
 use yew::{Context, Html, html};
@@ -9,7 +11,9 @@ use yew_bootstrap::component::{Alert, Button};
 use yew_bootstrap::util::Color;
 
 #[derive(Clone)]
-pub(crate) enum MsgEdit {}
+pub(crate) enum MsgEdit {
+    Search(String),
+}
 
 impl From<MsgEdit> for MsgApp {
     #[inline]
@@ -18,14 +22,22 @@ impl From<MsgEdit> for MsgApp {
     }
 }
 
-pub(crate) struct PaneEdit {}
+pub(crate) struct PaneEdit {
+    picker: QuestPicker,
+}
 
 impl SubComponent for PaneEdit {
     type Message = MsgEdit;
     type Ser = EmptySer;
 
     fn create(_ctx: &Context<App>) -> Self {
-        Self {}
+        Self {
+            picker: QuestPicker::default(),
+        }
+    }
+
+    fn reset_to_new(&mut self) {
+        self.picker.clear();
     }
 
     fn update(
@@ -34,11 +46,22 @@ impl SubComponent for PaneEdit {
         _ctx: &Context<App>,
         msg: Self::Message,
     ) -> UpdateResults {
-        match msg {}
+        match msg {
+            MsgEdit::Search(query) => {
+                self.picker.set_query(query);
+                UpdateResult::Render.into()
+            }
+        }
     }
 
     fn view(&self, data: &Data, ctx: &Context<App>) -> Html {
-        let quests = data.quest_iter().map(|(quest_id, quest, quest_name)| {
+        // only quests with data can be edited
+        let matches = self
+            .picker
+            .matches(data, |quest_id| data.journal.quest.contains_key(&quest_id));
+        let quests = matches.into_iter().map(|QuestMatch { quest_id, other }| {
+            let quest = &data.journal.quest[&quest_id];
+            let quest_name = data.quest_locale.get(quest_id);
             let max_vis = quest.encounter.values().map(|ql| ql.values().map(|qle| qle.vis).max().unwrap_or(Vis::Visible)).max().unwrap_or(Vis::Visible).max(quest.vis);
             html! {
                 <li class="list-group-item">
@@ -46,6 +69,9 @@ impl SubComponent for PaneEdit {
                         onclick={ctx.link().callback(move |_|MsgApp::Go(Route::EditQuest(quest_id)))}
                     >
                         {data.msg.quest_header(quest_name, quest.icon(quest_id))}
+                        if let Some(other) = other {
+                            <small class="ms-2">{other}</small>
+                        }
                     </Button>
                 </li>
             }
@@ -56,6 +82,9 @@ impl SubComponent for PaneEdit {
                 {data.msg.edit_hint()}
             </Alert>
             <ul class="list-group">
+                <li class="list-group-item">
+                    {self.picker.view_input(data, ctx, "input-search-edit", MsgEdit::Search)}
+                </li>
                 {for quests}
             </ul>
             </>
@@ -1,7 +1,11 @@
-use crate::data::encounter_type::EncounterType;
-use crate::data::quest::{Quest, QuestState};
+use crate::data::encounter_type::{EncounterType, EncounterTypeExt};
+use crate::data::event::EventAction;
+use crate::data::prerequisite::{
+    Prerequisite, PrerequisiteEdit, PrerequisiteExt, PrerequisitePicker,
+};
+use crate::data::quest::{Quest, QuestExt, QuestState, QuestStateExt};
 use crate::data::sub_component::{SubComponent, UpdateResult, UpdateResults};
-use crate::data::vis::Vis;
+use crate::data::vis::{Vis, VisExt};
 use crate::game::{LocationId, QuestId};
 use crate::global::app::{App, MsgApp};
 use crate::global::data::Data;
@@ -21,6 +25,7 @@ pub(crate) enum MsgEditQuest {
     Vis(Vis),
     EncounterVis(LocationId, EncounterType, Vis),
     KillEncounter(LocationId, EncounterType),
+    Prerequisite(LocationId, EncounterType, PrerequisiteEdit),
     Save,
 }
 
@@ -36,10 +41,14 @@ pub(crate) struct PaneEditQuest {
     quest: Quest,
     ref_note: NodeRef,
     encounters_to_remove: BTreeSet<(LocationId, EncounterType)>,
+    // the picker belongs to the prerequisite of one encounter
+    prerequisite_picker: (Option<(LocationId, EncounterType)>, PrerequisitePicker),
     // callbacks
     cb_state_not_found: Callback<Event>,
     cb_state_in_game: Callback<Event>,
     cb_state_removed: Callback<Event>,
+    cb_state_completed: Callback<Event>,
+    cb_state_lost: Callback<Event>,
     cb_save: Callback<MouseEvent>,
 }
 
@@ -53,6 +62,7 @@ impl SubComponent for PaneEditQuest {
             quest: Quest::default(),
             ref_note: NodeRef::default(),
             encounters_to_remove: BTreeSet::default(),
+            prerequisite_picker: (None, PrerequisitePicker::default()),
             cb_state_not_found: ctx
                 .link()
                 .callback(|_| MsgEditQuest::State(QuestState::NotFound)),
@@ -62,6 +72,12 @@ impl SubComponent for PaneEditQuest {
             cb_state_removed: ctx
                 .link()
                 .callback(|_| MsgEditQuest::State(QuestState::Removed)),
+            cb_state_completed: ctx
+                .link()
+                .callback(|_| MsgEditQuest::State(QuestState::Completed)),
+            cb_state_lost: ctx
+                .link()
+                .callback(|_| MsgEditQuest::State(QuestState::Lost)),
             cb_save: ctx.link().callback(|_| MsgEditQuest::Save),
         }
     }
@@ -97,11 +113,45 @@ impl SubComponent for PaneEditQuest {
                     }
                 }
 
+                // the encounters with another visibility than the saved ones
+                let saved = data.journal.quest.get(&self.quest_id);
+                let vis_changed = self
+                    .quest
+                    .encounter
+                    .iter()
+                    .flat_map(|(location_id, ql)| {
+                        ql.iter().filter_map(move |(encounter_type, qle)| {
+                            let saved_vis = saved
+                                .and_then(|quest| quest.encounter.get(location_id))
+                                .and_then(|saved_ql| saved_ql.get(encounter_type))
+                                .map(|saved_qle| saved_qle.vis);
+                            (saved_vis != Some(qle.vis)).then_some((
+                                *location_id,
+                                *encounter_type,
+                                qle.vis,
+                            ))
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
                 // save quest into data
                 mem::swap(
-                    data.quest.entry(self.quest_id).or_default(),
+                    data.journal.quest.entry(self.quest_id).or_default(),
                     &mut self.quest,
                 );
+                data.change = Some(
+                    data.msg
+                        .str_change_edit_quest(data.quest_locale.get(self.quest_id)),
+                );
+                data.journal
+                    .record_event(EventAction::Edit, Some(self.quest_id), None);
+                for (location_id, encounter_type, vis) in vis_changed {
+                    data.journal.record_event(
+                        EventAction::EncounterVis(encounter_type, vis),
+                        Some(self.quest_id),
+                        Some(location_id),
+                    );
+                }
 
                 // go back to list + save game data
                 data.chain_msg.push_back(MsgApp::Go(Route::Edit));
@@ -126,6 +176,31 @@ impl SubComponent for PaneEditQuest {
                     .insert((location_id, encounter_type));
                 UpdateResult::empty()
             }
+            MsgEditQuest::Prerequisite(location_id, encounter_type, edit) => {
+                let default = data
+                    .quest_all_iter()
+                    .next()
+                    .map_or(self.quest_id, |(quest_id, _)| quest_id);
+                if let Some(qle) = self
+                    .quest
+                    .encounter
+                    .get_mut(&location_id)
+                    .and_then(|ql| ql.get_mut(encounter_type))
+                {
+                    if self.prerequisite_picker.0 != Some((location_id, encounter_type)) {
+                        self.prerequisite_picker = (
+                            Some((location_id, encounter_type)),
+                            PrerequisitePicker::default(),
+                        );
+                    }
+                    edit.apply(
+                        &mut qle.prerequisite,
+                        &mut self.prerequisite_picker.1,
+                        default,
+                    );
+                }
+                UpdateResult::Render.into()
+            }
         }
     }
 
@@ -151,13 +226,24 @@ impl SubComponent for PaneEditQuest {
                                 Some(ctx.link().callback(move |_|MsgEditQuest::KillEncounter(location_id, encounter_type))),
                             )}
                             <br/>
-                            if let Some(pre_quest_id) = encounter.prerequisite {
-                              {data.msg.ed_qu_prerequisite(data.quest_locale.get( pre_quest_id))}<br/>
-                            }
+                            {data.msg.ed_qu_prerequisite()}<br/>
+                            {Prerequisite::view_editor(
+                                encounter.prerequisite.as_ref(),
+                                data,
+                                ctx,
+                                &format!("prerequisite-{}-{}", location_id.raw(), encounter_type.raw()),
+                                (self.prerequisite_picker.0 == Some((location_id, encounter_type))).then_some(&self.prerequisite_picker.1),
+                                move |edit| MsgEditQuest::Prerequisite(location_id, encounter_type, edit),
+                            )}
                         </li>
                     }
                 })
             });
+        let legacy_removed = data
+            .journal
+            .quest
+            .get(&self.quest_id)
+            .is_some_and(|quest| quest.state == QuestState::Removed);
         html! {
             <>
             <Alert style={Color::Info}>
@@ -198,12 +284,37 @@ impl SubComponent for PaneEditQuest {
                             type="radio"
                             class="btn-check"
                             name="state"
-                            id="state2"
+                            id="state3"
+                            autocomplete="off"
+                            checked={self.quest.state == QuestState::Completed}
+                            onchange={&self.cb_state_completed}
+                        />
+                        <label class="btn btn-outline-primary" for="state3">{QuestState::Completed.text(data)}</label>
+
+                        <input
+                            type="radio"
+                            class="btn-check"
+                            name="state"
+                            id="state4"
                             autocomplete="off"
-                            checked={self.quest.state == QuestState::Removed}
-                            onchange={&self.cb_state_removed}
+                            checked={self.quest.state == QuestState::Lost}
+                            onchange={&self.cb_state_lost}
                         />
-                        <label class="btn btn-outline-primary" for="state2">{QuestState::Removed.text(data)}</label>
+                        <label class="btn btn-outline-primary" for="state4">{QuestState::Lost.text(data)}</label>
+
+                        // only offered for old data, where it's unknown whether it was completed or lost
+                        if legacy_removed {
+                            <input
+                                type="radio"
+                                class="btn-check"
+                                name="state"
+                                id="state2"
+                                autocomplete="off"
+                                checked={self.quest.state == QuestState::Removed}
+                                onchange={&self.cb_state_removed}
+                            />
+                            <label class="btn btn-outline-primary" for="state2">{QuestState::Removed.text(data)}</label>
+                        }
                     </div>
                 </li>
                 <li class="list-group-item">
@@ -211,7 +322,7 @@ impl SubComponent for PaneEditQuest {
                         class="form-control"
                         placeholder={data.msg.str_note_placeholder()}
                         id="floatingTextarea"
-                        value={self.quest.note.clone()}
+                        value={self.quest.note.to_string()}
                         style="height: 150px"
                         ref={&self.ref_note}
                     />
@@ -250,9 +361,10 @@ impl SubComponent for PaneEditQuest {
 
 impl PaneEditQuest {
     pub(crate) fn go(&mut self, data: &Data, quest_id: QuestId) -> bool {
-        if let Some(quest) = data.quest.get(&quest_id) {
+        if let Some(quest) = data.journal.quest.get(&quest_id) {
             self.quest_id = quest_id;
             self.quest = quest.clone();
+            self.prerequisite_picker = (None, PrerequisitePicker::default());
             true
         } else {
             false
@@ -0,0 +1,279 @@
+use crate::data::prerequisite::Prerequisite;
+use crate::data::quest::{QuestState, QuestStateExt};
+use crate::data::vis::{Vis, VisExt};
+use crate::game::{LocationId, QuestId};
+use crate::global::app::{App, MsgApp};
+use crate::global::data::Data;
+use crate::route::Route;
+use std::collections::{BTreeMap, BTreeSet};
+use yew::{AttrValue, Context, Html, html};
+
+const NODE_WIDTH: usize = 220;
+const NODE_HEIGHT: usize = 40;
+const GAP_X: usize = 120;
+const GAP_Y: usize = 24;
+const PADDING: usize = 8;
+const NAME_LENGTH: usize = 26;
+
+struct Node {
+    quest_id: QuestId,
+    layer: usize,
+    follow_ups: usize,
+}
+
+// all prerequisites (from -> to) with the locations where they are required
+fn edges(data: &Data) -> BTreeMap<(QuestId, QuestId), BTreeSet<LocationId>> {
+    let mut edges = BTreeMap::<_, BTreeSet<_>>::new();
+    for (quest_id, quest) in &data.journal.quest {
+        for (location_id, quest_location) in &quest.encounter {
+            for qle in quest_location.values() {
+                for prerequisite in qle.prerequisite.iter().flat_map(Prerequisite::required) {
+                    edges
+                        .entry((prerequisite, *quest_id))
+                        .or_default()
+                        .insert(*location_id);
+                }
+            }
+        }
+    }
+    edges
+}
+
+// places every quest one layer right of its latest prerequisite
+fn nodes(edges: &BTreeMap<(QuestId, QuestId), BTreeSet<LocationId>>) -> Vec<Node> {
+    let mut layer = BTreeMap::new();
+    for (from, to) in edges.keys() {
+        layer.insert(*from, 0);
+        layer.insert(*to, 0);
+    }
+
+    // the relaxation is limited, as an edited prerequisite could create a cycle
+    for _ in 0..layer.len() {
+        let mut changed = false;
+        for (from, to) in edges.keys() {
+            let required = layer[from] + 1;
+            if layer[to] < required {
+                layer.insert(*to, required);
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    layer
+        .iter()
+        .map(|(quest_id, layer)| {
+            let mut reached = BTreeSet::new();
+            let mut todo = vec![*quest_id];
+            while let Some(from) = todo.pop() {
+                for (_, to) in edges.keys().filter(|(f, _)| *f == from) {
+                    if *to != *quest_id && reached.insert(*to) {
+                        todo.push(*to);
+                    }
+                }
+            }
+            Node {
+                quest_id: *quest_id,
+                layer: *layer,
+                follow_ups: reached.len(),
+            }
+        })
+        .collect()
+}
+
+fn fill(state: QuestState) -> &'static str {
+    match state {
+        QuestState::NotFound => "fill: var(--bs-body-bg)",
+        QuestState::InGame => "fill: var(--bs-primary-bg-subtle)",
+        QuestState::Removed => "fill: var(--bs-secondary-bg)",
+        QuestState::Completed => "fill: var(--bs-success-bg-subtle)",
+        QuestState::Lost => "fill: var(--bs-danger-bg-subtle)",
+    }
+}
+
+fn stroke(vis: Vis) -> &'static str {
+    match vis {
+        Vis::Visible => "stroke: var(--bs-primary)",
+        Vis::HiddenThisCampaign => "stroke: var(--bs-warning); stroke-dasharray: 6 3",
+        Vis::HiddenForever => "stroke: var(--bs-danger); stroke-dasharray: 2 3",
+    }
+}
+
+fn short_name(name: &str) -> String {
+    if name.chars().count() > NAME_LENGTH {
+        let mut short = name.chars().take(NAME_LENGTH - 1).collect::<String>();
+        short.push('…');
+        short
+    } else {
+        name.to_string()
+    }
+}
+
+pub(crate) fn graph_view(data: &Data, ctx: &Context<App>) -> Html {
+    let edges = edges(data);
+    if edges.is_empty() {
+        return html! {<p>{data.msg.graph_empty()}</p>};
+    }
+
+    let mut nodes = nodes(&edges);
+    nodes.sort_by(|a, b| {
+        a.layer
+            .cmp(&b.layer)
+            .then(b.follow_ups.cmp(&a.follow_ups))
+            .then_with(|| data.quest_name(a.quest_id).cmp(data.quest_name(b.quest_id)))
+    });
+
+    let mut position = BTreeMap::new();
+    let mut rows = 0;
+    let mut row = 0;
+    let mut last_layer = 0;
+    for node in &nodes {
+        if node.layer != last_layer {
+            last_layer = node.layer;
+            row = 0;
+        }
+        position.insert(
+            node.quest_id,
+            (
+                PADDING + node.layer * (NODE_WIDTH + GAP_X),
+                PADDING + row * (NODE_HEIGHT + GAP_Y),
+            ),
+        );
+        row += 1;
+        rows = rows.max(row);
+    }
+    let width = 2 * PADDING + (last_layer + 1) * (NODE_WIDTH + GAP_X) - GAP_X;
+    let height = 2 * PADDING + rows * (NODE_HEIGHT + GAP_Y) - GAP_Y;
+
+    let language = data.quest_locale.language();
+    let lines = edges.iter().map(|((from, to), locations)| {
+        let (x1, y1) = position[from];
+        let (x2, y2) = position[to];
+        let (x1, y1) = (x1 + NODE_WIDTH, y1 + NODE_HEIGHT / 2);
+        let y2 = y2 + NODE_HEIGHT / 2;
+        let bend = GAP_X / 2;
+        let path = format!(
+            "M{x1},{y1} C{},{y1} {},{y2} {x2},{y2}",
+            x1 + bend,
+            x2.saturating_sub(bend)
+        );
+        let label = locations
+            .iter()
+            .map(|location_id| location_id.name(language))
+            .collect::<Vec<_>>()
+            .join(", ");
+        html! {
+            <g>
+                <path d={path} fill="none" style="stroke: var(--bs-secondary-color)" marker-end="url(#graph-arrow)"/>
+                <text
+                    x={usize::midpoint(x1, x2).to_string()}
+                    y={(usize::midpoint(y1, y2) - 4).to_string()}
+                    text-anchor="middle"
+                    font-size="11"
+                    style="fill: var(--bs-secondary-color); paint-order: stroke; stroke: var(--bs-body-bg); stroke-width: 3"
+                >
+                    {label}
+                </text>
+            </g>
+        }
+    });
+
+    let boxes = nodes.iter().map(|node| {
+        let quest_id = node.quest_id;
+        let (x, y) = position[&quest_id];
+        let (state, vis) = data
+            .journal
+            .quest
+            .get(&quest_id)
+            .map_or((QuestState::NotFound, Vis::Visible), |quest| {
+                (quest.state, quest.vis)
+            });
+        let name = data.quest_name(quest_id);
+        let onclick = ctx
+            .link()
+            .callback(move |_| MsgApp::Go(Route::EditQuest(quest_id)));
+        html! {
+            <g {onclick} style="cursor: pointer">
+                <title>{data.msg.str_graph_follow_ups(name, &node.follow_ups.to_string())}</title>
+                <rect
+                    x={x.to_string()}
+                    y={y.to_string()}
+                    width={NODE_WIDTH.to_string()}
+                    height={NODE_HEIGHT.to_string()}
+                    rx="6"
+                    stroke-width="2"
+                    style={AttrValue::from(format!("{}; {}", fill(state), stroke(vis)))}
+                />
+                <text
+                    x={(x + 10).to_string()}
+                    y={(y + NODE_HEIGHT / 2 + 5).to_string()}
+                    font-size="14"
+                    style="fill: var(--bs-body-color)"
+                >
+                    {short_name(name)}
+                </text>
+                if node.follow_ups > 0 {
+                    <text
+                        x={(x + NODE_WIDTH - 10).to_string()}
+                        y={(y + NODE_HEIGHT / 2 + 5).to_string()}
+                        text-anchor="end"
+                        font-size="12"
+                        style="fill: var(--bs-secondary-color)"
+                    >
+                        {format!("+{}", node.follow_ups)}
+                    </text>
+                }
+            </g>
+        }
+    });
+
+    let legend_state = [
+        QuestState::NotFound,
+        QuestState::InGame,
+        QuestState::Completed,
+        QuestState::Lost,
+        QuestState::Removed,
+    ]
+    .into_iter()
+        .map(|state| {
+            html! {
+                <span class="ms-2 px-2 border rounded" style={fill(state).replace("fill", "background-color")}>
+                    {state.text(data)}
+                </span>
+            }
+        });
+    let legend_vis = [Vis::Visible, Vis::HiddenThisCampaign, Vis::HiddenForever]
+        .into_iter()
+        .map(|vis| {
+            html! {
+                <span class={vis.class_text()}>{" "}{vis.text(data)}</span>
+            }
+        });
+
+    html! {
+        <>
+        <p class="small">
+            {data.msg.graph_legend_state()}{for legend_state}<br/>
+            {data.msg.graph_legend_vis()}{for legend_vis}
+        </p>
+        <div class="overflow-auto">
+            <svg
+                width={width.to_string()}
+                height={height.to_string()}
+                viewBox={format!("0 0 {width} {height}")}
+                xmlns="http://www.w3.org/2000/svg"
+            >
+                <defs>
+                    <marker id="graph-arrow" viewBox="0 0 10 10" refX="10" refY="5" markerWidth="8" markerHeight="8" orient="auto-start-reverse">
+                        <path d="M0,0 L10,5 L0,10 z" style="fill: var(--bs-secondary-color)"/>
+                    </marker>
+                </defs>
+                {for lines}
+                {for boxes}
+            </svg>
+        </div>
+        </>
+    }
+}
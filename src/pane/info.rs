@@ -1,6 +1,6 @@
-use crate::data::encounter_type::EncounterType;
-use crate::data::note::Note;
-use crate::data::quest::Quest;
+use crate::data::encounter_type::{EncounterType, EncounterTypeExt};
+use crate::data::note::{Note, NoteExt};
+use crate::data::quest::{Quest, QuestExt};
 use crate::global::data::Data;
 use yew::Html;
 
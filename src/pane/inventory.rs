@@ -0,0 +1,408 @@
+use crate::data::item::{ItemKind, ItemKindExt};
+use crate::data::sub_component::{SubComponent, UpdateResult, UpdateResults};
+use crate::game::{LocationId, QuestId};
+use crate::global::app::{App, MsgApp};
+use crate::global::data::Data;
+use crate::html::{callback_input_value, text};
+use crate::route::Route;
+use crate::ser::settings::EmptySer;
+use sleeping_journal_core::data::item::Item;
+use std::collections::BTreeSet;
+use std::str::FromStr;
+use web_sys::HtmlSelectElement;
+use yew::{Context, Html, TargetCast, html};
+use yew_bootstrap::component::{Button, ButtonSize};
+use yew_bootstrap::icons::BI;
+use yew_bootstrap::util::Color;
+
+#[derive(Clone)]
+pub(crate) enum MsgInventory {
+    Add(ItemKind),
+    Remove(usize),
+    Number(usize, String),
+    Name(usize, String),
+    Holder(usize, String),
+    Origin(usize, String),
+    FilterKind(String),
+    FilterHolder(String),
+    Search(String),
+}
+
+impl From<MsgInventory> for MsgApp {
+    #[inline]
+    fn from(msg: MsgInventory) -> Self {
+        MsgApp::MsgInventory(msg)
+    }
+}
+
+// the filters, every item is shown if they are empty
+pub(crate) struct PaneInventory {
+    kind: Option<ItemKind>,
+    holder: Option<String>,
+    search: String,
+}
+
+impl SubComponent for PaneInventory {
+    type Message = MsgInventory;
+    type Ser = EmptySer;
+
+    fn create(_ctx: &Context<App>) -> Self {
+        Self {
+            kind: None,
+            holder: None,
+            search: String::new(),
+        }
+    }
+
+    fn reset_to_new(&mut self) {
+        self.kind = None;
+        self.holder = None;
+        self.search.clear();
+    }
+
+    fn update(
+        &mut self,
+        data: &mut Data,
+        _ctx: &Context<App>,
+        msg: Self::Message,
+    ) -> UpdateResults {
+        match msg {
+            MsgInventory::Add(kind) => {
+                data.journal.item.push(Item::new(kind));
+                data.change = Some(data.msg.str_change_item_add(kind.text(data)));
+                // the new item must not be hidden by the filters
+                self.reset_to_new();
+                UpdateResult::Render | UpdateResult::SaveGameData
+            }
+            MsgInventory::Remove(pos) => {
+                if pos < data.journal.item.len() {
+                    let item = data.journal.item.remove(pos);
+                    data.change = Some(data.msg.str_change_item_remove(&item.name));
+                    UpdateResult::Render | UpdateResult::SaveGameData
+                } else {
+                    UpdateResult::empty()
+                }
+            }
+            MsgInventory::Number(pos, number) => match number.trim() {
+                "" => change(data, pos, |item| item.number = None),
+                number => match u16::from_str(number) {
+                    Ok(number) => change(data, pos, |item| item.number = Some(number)),
+                    Err(_) => UpdateResult::Render.into(),
+                },
+            },
+            MsgInventory::Name(pos, name) => change(data, pos, |item| item.name = name),
+            MsgInventory::Holder(pos, holder) => {
+                match usize::from_str(&holder)
+                    .ok()
+                    .and_then(|index| holders(data).into_iter().nth(index))
+                {
+                    Some(holder) => change(data, pos, |item| item.holder = holder),
+                    None => UpdateResult::Render.into(),
+                }
+            }
+            MsgInventory::Origin(pos, origin) => {
+                let (location_id, quest_id) = read_origin(&origin);
+                change(data, pos, |item| {
+                    item.location_id = location_id;
+                    item.quest_id = quest_id;
+                })
+            }
+            MsgInventory::FilterKind(kind) => {
+                self.kind = ItemKind::try_from_csv(&kind);
+                UpdateResult::Render.into()
+            }
+            MsgInventory::FilterHolder(holder) => {
+                self.holder = usize::from_str(&holder)
+                    .ok()
+                    .and_then(|index| holders(data).into_iter().nth(index));
+                UpdateResult::Render.into()
+            }
+            MsgInventory::Search(search) => {
+                self.search = search;
+                UpdateResult::Render.into()
+            }
+        }
+    }
+
+    fn view(&self, data: &Data, ctx: &Context<App>) -> Html {
+        let language = data.quest_locale.language();
+        let holders = holders(data);
+        let holder_name = |holder: &str| {
+            if holder.is_empty() {
+                data.msg.str_inventory_ship().to_string()
+            } else {
+                holder.to_string()
+            }
+        };
+
+        let shown = data
+            .journal
+            .item
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| {
+                self.kind.is_none_or(|kind| item.kind == kind)
+                    && self
+                        .holder
+                        .as_ref()
+                        .is_none_or(|holder| item.holder == *holder)
+                    && item.matches(&self.search)
+            })
+            .collect::<Vec<_>>();
+        let shown_len = shown.len();
+
+        let items = shown.into_iter().map(|(pos, item)| {
+            let holder_options = holders.iter().enumerate().map(|(index, holder)| {
+                html! {
+                    <option value={index.to_string()} selected={item.holder == *holder}>
+                        {holder_name(holder)}
+                    </option>
+                }
+            });
+            let locations = data.location_iter().map(|location_id| {
+                html! {
+                    <option
+                        value={format!("l{}", location_id.raw())}
+                        selected={item.location_id == Some(location_id)}
+                    >
+                        {location_id.name(language)}
+                    </option>
+                }
+            });
+            let quests = data.quest_iter().map(|(quest_id, _, quest_name)| {
+                html! {
+                    <option
+                        value={format!("q{}", quest_id.raw())}
+                        selected={item.quest_id == Some(quest_id)}
+                    >
+                        {quest_name}
+                    </option>
+                }
+            });
+            let on_holder = ctx.link().callback(move |event: web_sys::Event| {
+                MsgInventory::Holder(pos, event.target_unchecked_into::<HtmlSelectElement>().value())
+            });
+            let on_origin = ctx.link().callback(move |event: web_sys::Event| {
+                MsgInventory::Origin(pos, event.target_unchecked_into::<HtmlSelectElement>().value())
+            });
+            html! {
+                <tr>
+                    <td title={item.kind.text(data)}>{item.kind.icon()}</td>
+                    <td style="width: 7em">
+                        <input
+                            type="number"
+                            class="form-control"
+                            min="0"
+                            value={item.number.map(|number| number.to_string()).unwrap_or_default()}
+                            onchange={callback_input_value(ctx, move |number| MsgInventory::Number(pos, number))}
+                        />
+                    </td>
+                    <td>
+                        <input
+                            type="text"
+                            class="form-control"
+                            placeholder={data.msg.str_inventory_name_placeholder()}
+                            value={item.name.clone()}
+                            onchange={callback_input_value(ctx, move |name| MsgInventory::Name(pos, name))}
+                        />
+                    </td>
+                    <td>
+                        <select class="form-select" onchange={on_holder}>
+                            {for holder_options}
+                        </select>
+                    </td>
+                    <td>
+                        <div class="input-group">
+                            <select class="form-select" onchange={on_origin}>
+                                <option
+                                    value=""
+                                    selected={item.location_id.is_none() && item.quest_id.is_none()}
+                                >
+                                    {data.msg.inventory_no_origin()}
+                                </option>
+                                <optgroup label={data.msg.str_inventory_locations()}>
+                                    {for locations}
+                                </optgroup>
+                                <optgroup label={data.msg.str_inventory_quests()}>
+                                    {for quests}
+                                </optgroup>
+                            </select>
+                            if let Some(location_id) = item.location_id {
+                                <Button
+                                    style={Color::Secondary}
+                                    outline={true}
+                                    onclick={ctx.link().callback(move |_|MsgApp::Go(Route::MapLocation(location_id)))}
+                                >
+                                    {BI::GEO_ALT}
+                                </Button>
+                            }
+                            if let Some(quest_id) = item.quest_id {
+                                <Button
+                                    style={Color::Secondary}
+                                    outline={true}
+                                    onclick={ctx.link().callback(move |_|MsgApp::Go(Route::EditQuest(quest_id)))}
+                                >
+                                    {BI::PENCIL}
+                                </Button>
+                            }
+                        </div>
+                    </td>
+                    <td class="text-end">
+                        <Button
+                            size={ButtonSize::Small}
+                            style={Color::Danger}
+                            outline={true}
+                            onclick={ctx.link().callback(move |_|MsgInventory::Remove(pos))}
+                        >
+                            {BI::TRASH}
+                        </Button>
+                    </td>
+                </tr>
+            }
+        });
+
+        let add = ItemKind::iter().map(|kind| {
+            html! {
+                <Button
+                    style={Color::Primary}
+                    class="ms-2"
+                    onclick={ctx.link().callback(move |_|MsgInventory::Add(kind))}
+                >
+                    {BI::PLUS}{kind.icon()}{" "}{text(kind.text(data))}
+                </Button>
+            }
+        });
+        let filter_kinds = ItemKind::iter().map(|kind| {
+            html! {
+                <option value={kind.to_csv()} selected={self.kind == Some(kind)}>
+                    {text(kind.text(data))}
+                </option>
+            }
+        });
+        let filter_holders = holders.iter().enumerate().map(|(index, holder)| {
+            html! {
+                <option
+                    value={index.to_string()}
+                    selected={self.holder.as_ref() == Some(holder)}
+                >
+                    {holder_name(holder)}
+                </option>
+            }
+        });
+        let on_filter_kind = ctx.link().callback(|event: web_sys::Event| {
+            MsgInventory::FilterKind(event.target_unchecked_into::<HtmlSelectElement>().value())
+        });
+        let on_filter_holder = ctx.link().callback(|event: web_sys::Event| {
+            MsgInventory::FilterHolder(event.target_unchecked_into::<HtmlSelectElement>().value())
+        });
+
+        html! {
+            <>
+            <div class="d-flex align-items-center mb-4">
+                <h2 class="h4 mb-0">
+                    {data.msg.inventory_header(&shown_len.to_string(), &data.journal.item.len().to_string())}
+                </h2>
+                <div class="ms-auto">
+                    {for add}
+                </div>
+            </div>
+
+            <div class="row g-2 mb-4">
+                <div class="col-md-3">
+                    <select class="form-select" onchange={on_filter_kind}>
+                        <option value="" selected={self.kind.is_none()}>
+                            {data.msg.inventory_all_kinds()}
+                        </option>
+                        {for filter_kinds}
+                    </select>
+                </div>
+                <div class="col-md-3">
+                    <select class="form-select" onchange={on_filter_holder}>
+                        <option value="" selected={self.holder.is_none()}>
+                            {data.msg.inventory_all_holders()}
+                        </option>
+                        {for filter_holders}
+                    </select>
+                </div>
+                <div class="col-md-6">
+                    <input
+                        type="search"
+                        class="form-control"
+                        placeholder={data.msg.str_inventory_search_placeholder()}
+                        value={self.search.clone()}
+                        onchange={callback_input_value(ctx, MsgInventory::Search)}
+                    />
+                </div>
+            </div>
+
+            if data.journal.item.is_empty() {
+                <p>{data.msg.inventory_empty()}</p>
+            } else if shown_len == 0 {
+                <p>{data.msg.inventory_none_shown()}</p>
+            } else {
+                <div class="table-responsive">
+                    <table class="table align-middle">
+                        <thead>
+                            <tr>
+                                <th></th>
+                                <th>{data.msg.inventory_number()}</th>
+                                <th>{data.msg.inventory_name()}</th>
+                                <th>{data.msg.inventory_holder()}</th>
+                                <th>{data.msg.inventory_origin()}</th>
+                                <th></th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            {for items}
+                        </tbody>
+                    </table>
+                </div>
+            }
+            </>
+        }
+    }
+
+    fn save(&self) -> Self::Ser {
+        EmptySer {}
+    }
+
+    fn load(&mut self, _stored: Self::Ser) {}
+}
+
+fn change<F>(data: &mut Data, pos: usize, f: F) -> UpdateResults
+where
+    F: FnOnce(&mut Item),
+{
+    let Some(item) = data.journal.item.get_mut(pos) else {
+        return UpdateResult::empty();
+    };
+    f(item);
+    data.change = Some(data.msg.str_change_item(&item.name));
+    UpdateResult::Render | UpdateResult::SaveGameData
+}
+
+// who can hold an item: the ship (empty) first, then the crew and everyone else holding one
+fn holders(data: &Data) -> Vec<String> {
+    let others = data
+        .journal
+        .crew
+        .iter()
+        .map(|crew_member| &crew_member.name)
+        .chain(data.journal.item.iter().map(|item| &item.holder))
+        .filter(|holder| !holder.is_empty())
+        .cloned()
+        .collect::<BTreeSet<_>>();
+    std::iter::once(String::new()).chain(others).collect()
+}
+
+// `l` and the raw id of a location or `q` and the raw id of a quest
+fn read_origin(origin: &str) -> (Option<LocationId>, Option<QuestId>) {
+    let raw = |raw: &str| usize::from_str(raw).ok();
+    if let Some(location_id) = origin.strip_prefix('l') {
+        (raw(location_id).and_then(LocationId::from_raw), None)
+    } else if let Some(quest_id) = origin.strip_prefix('q') {
+        (None, raw(quest_id).and_then(QuestId::from_raw))
+    } else {
+        (None, None)
+    }
+}
@@ -0,0 +1,305 @@
+use crate::data::ledger::{Resource, ResourceExt};
+use crate::data::sub_component::{SubComponent, UpdateResult, UpdateResults};
+use crate::game::LocationId;
+use crate::global::app::{App, MsgApp};
+use crate::global::data::Data;
+use crate::html::{callback_input_value, text};
+use crate::route::Route;
+use crate::ser::settings::EmptySer;
+use std::str::FromStr;
+use web_sys::HtmlSelectElement;
+use yew::{AttrValue, Context, Html, TargetCast, html};
+use yew_bootstrap::component::form::{FormControl, FormControlType, SelectOption};
+use yew_bootstrap::component::{Button, ButtonSize};
+use yew_bootstrap::icons::BI;
+use yew_bootstrap::util::Color;
+
+#[derive(Clone)]
+pub(crate) enum MsgLedger {
+    ShipLocation(String),
+    Resource(String),
+    Amount(String),
+    Reason(String),
+    Add,
+    Remove(usize),
+}
+
+impl From<MsgLedger> for MsgApp {
+    #[inline]
+    fn from(msg: MsgLedger) -> Self {
+        MsgApp::MsgLedger(msg)
+    }
+}
+
+// the entry which is about to be added
+pub(crate) struct PaneLedger {
+    resource: Resource,
+    amount: String,
+    reason: String,
+}
+
+impl SubComponent for PaneLedger {
+    type Message = MsgLedger;
+    type Ser = EmptySer;
+
+    fn create(_ctx: &Context<App>) -> Self {
+        Self {
+            resource: Resource::Coins,
+            amount: String::new(),
+            reason: String::new(),
+        }
+    }
+
+    fn reset_to_new(&mut self) {
+        self.resource = Resource::Coins;
+        self.amount.clear();
+        self.reason.clear();
+    }
+
+    fn update(
+        &mut self,
+        data: &mut Data,
+        _ctx: &Context<App>,
+        msg: Self::Message,
+    ) -> UpdateResults {
+        match msg {
+            MsgLedger::ShipLocation(location_id) => {
+                let location_id = usize::from_str(&location_id)
+                    .ok()
+                    .and_then(LocationId::from_raw);
+                data.journal.ledger.location_id = location_id;
+                data.change = Some(data.msg.str_change_ship_location(
+                    location_id.map_or(data.msg.str_ledger_no_location(), |location_id| {
+                        location_id.name(data.quest_locale.language())
+                    }),
+                ));
+                UpdateResult::Render | UpdateResult::SaveGameData
+            }
+            MsgLedger::Resource(resource) => {
+                if let Some(resource) = Resource::try_from_csv(&resource) {
+                    self.resource = resource;
+                }
+                UpdateResult::Render.into()
+            }
+            MsgLedger::Amount(amount) => {
+                self.amount = amount;
+                UpdateResult::Render.into()
+            }
+            MsgLedger::Reason(reason) => {
+                self.reason = reason;
+                UpdateResult::Render.into()
+            }
+            MsgLedger::Add => match i32::from_str(self.amount.trim()) {
+                Ok(amount) if amount != 0 => {
+                    data.journal.ledger.record(
+                        self.resource,
+                        amount,
+                        self.reason.trim().to_string(),
+                    );
+                    data.change = Some(
+                        data.msg
+                            .str_change_ledger(self.resource.text(data), &format!("{amount:+}")),
+                    );
+                    self.amount.clear();
+                    self.reason.clear();
+                    UpdateResult::Render | UpdateResult::SaveGameData
+                }
+                _ => UpdateResult::empty(),
+            },
+            MsgLedger::Remove(pos) => {
+                if pos < data.journal.ledger.entry.len() {
+                    let entry = data.journal.ledger.entry.remove(pos);
+                    data.change =
+                        Some(data.msg.str_change_ledger_remove(entry.resource.text(data)));
+                    UpdateResult::Render | UpdateResult::SaveGameData
+                } else {
+                    UpdateResult::empty()
+                }
+            }
+        }
+    }
+
+    fn view(&self, data: &Data, ctx: &Context<App>) -> Html {
+        let ledger = &data.journal.ledger;
+        let language = data.quest_locale.language();
+
+        let totals = Resource::iter().map(|resource| {
+            html! {
+                <div class="col">
+                    <div class="card text-center">
+                        <div class="card-body">
+                            <div class="text-body-secondary">{resource.icon()}{" "}{text(resource.text(data))}</div>
+                            <div class="fs-2">{ledger.total(resource)}</div>
+                        </div>
+                    </div>
+                </div>
+            }
+        });
+
+        let locations = data.location_iter().map(|location_id| {
+            html! {
+                <SelectOption
+                    value={AttrValue::from(location_id.raw().to_string())}
+                    label={AttrValue::Static(location_id.name(language))}
+                    selected={ledger.location_id == Some(location_id)}
+                />
+            }
+        });
+
+        let resources = Resource::iter().map(|resource| {
+            html! {
+                <SelectOption
+                    value={AttrValue::Static(resource.to_csv())}
+                    label={AttrValue::Static(resource.text(data))}
+                    selected={self.resource == resource}
+                />
+            }
+        });
+
+        let entries = ledger
+            .running()
+            .enumerate()
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .map(|(pos, (entry, total))| {
+                let time = entry
+                    .local_time()
+                    .map(|time| time.format("%Y-%m-%d %H:%M").to_string())
+                    .unwrap_or_default();
+                html! {
+                    <tr>
+                        <td><small>{time}</small></td>
+                        <td>{entry.resource.icon()}{" "}{text(entry.resource.text(data))}</td>
+                        <td class="text-end">{format!("{:+}", entry.amount)}</td>
+                        <td class="text-end">{total}</td>
+                        <td>{&entry.reason}</td>
+                        <td>
+                            if let Some(location_id) = entry.location_id {
+                                <Button
+                                    size={ButtonSize::Small}
+                                    onclick={ctx.link().callback(move |_|MsgApp::Go(Route::MapLocation(location_id)))}
+                                >
+                                    {text(location_id.name(language))}
+                                </Button>
+                            }
+                        </td>
+                        <td class="text-end">
+                            <Button
+                                size={ButtonSize::Small}
+                                style={Color::Danger}
+                                outline={true}
+                                onclick={ctx.link().callback(move |_|MsgLedger::Remove(pos))}
+                            >
+                                {BI::TRASH}
+                            </Button>
+                        </td>
+                    </tr>
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let on_location = ctx.link().callback(|event: web_sys::Event| {
+            MsgLedger::ShipLocation(event.target_unchecked_into::<HtmlSelectElement>().value())
+        });
+        let on_resource = ctx.link().callback(|event: web_sys::Event| {
+            MsgLedger::Resource(event.target_unchecked_into::<HtmlSelectElement>().value())
+        });
+
+        html! {
+            <>
+            <div class="row mb-4">
+                {for totals}
+            </div>
+
+            <FormControl
+                id="input-select-ship-location"
+                ctype={FormControlType::Select}
+                label={AttrValue::Static(data.msg.str_ledger_ship_location())}
+                onchange={on_location}
+            >
+                <SelectOption
+                    value={AttrValue::Static("")}
+                    label={AttrValue::Static(data.msg.str_ledger_no_location())}
+                    selected={ledger.location_id.is_none()}
+                />
+                {for locations}
+            </FormControl>
+
+            <h2 class="h5 mt-4">{data.msg.ledger_add_header()}</h2>
+            <div class="row g-2 align-items-end">
+                <div class="col-md-3">
+                    <FormControl
+                        id="input-select-ledger-resource"
+                        ctype={FormControlType::Select}
+                        label={AttrValue::Static(data.msg.str_ledger_resource())}
+                        onchange={on_resource}
+                    >
+                        {for resources}
+                    </FormControl>
+                </div>
+                <div class="col-md-2">
+                    <label class="form-label" for="input-ledger-amount">{data.msg.ledger_amount()}</label>
+                    <input
+                        type="number"
+                        class="form-control"
+                        id="input-ledger-amount"
+                        placeholder="-2"
+                        value={self.amount.clone()}
+                        onchange={callback_input_value(ctx, MsgLedger::Amount)}
+                    />
+                </div>
+                <div class="col-md-5">
+                    <label class="form-label" for="input-ledger-reason">{data.msg.ledger_reason()}</label>
+                    <input
+                        type="text"
+                        class="form-control"
+                        id="input-ledger-reason"
+                        placeholder={data.msg.str_ledger_reason_placeholder()}
+                        value={self.reason.clone()}
+                        onchange={callback_input_value(ctx, MsgLedger::Reason)}
+                    />
+                </div>
+                <div class="col-md-2 mb-3">
+                    <Button
+                        style={Color::Primary}
+                        class="w-100"
+                        onclick={ctx.link().callback(|_|MsgLedger::Add)}
+                    >
+                        {BI::PLUS}{data.msg.ledger_add()}
+                    </Button>
+                </div>
+            </div>
+
+            if entries.is_empty() {
+                <p class="mt-4">{data.msg.ledger_empty()}</p>
+            } else {
+                <div class="table-responsive mt-4">
+                    <table class="table table-hover align-middle">
+                        <thead>
+                            <tr>
+                                <th>{data.msg.ledger_time()}</th>
+                                <th>{data.msg.ledger_resource()}</th>
+                                <th class="text-end">{data.msg.ledger_amount()}</th>
+                                <th class="text-end">{data.msg.ledger_total()}</th>
+                                <th>{data.msg.ledger_reason()}</th>
+                                <th>{data.msg.ledger_location()}</th>
+                                <th></th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            {for entries}
+                        </tbody>
+                    </table>
+                </div>
+            }
+            </>
+        }
+    }
+
+    fn save(&self) -> Self::Ser {
+        EmptySer {}
+    }
+
+    fn load(&mut self, _stored: Self::Ser) {}
+}
@@ -1,7 +1,8 @@
-use crate::data::note::Note;
+use crate::data::encounter_type::EncounterTypeExt;
+use crate::data::note::{Note, NoteExt};
 use crate::data::sub_component::{SubComponent, UpdateResult, UpdateResults};
-use crate::data::vis::Vis;
-use crate::game::{LocationId, MAP, MAP_DEFAULT_POSITION};
+use crate::data::vis::{Vis, VisExt};
+use crate::game::{LocationId, PackId};
 use crate::global::app::{App, MsgApp};
 use crate::global::data::Data;
 use crate::html::{Modal, text};
@@ -13,7 +14,7 @@ use yew_bootstrap::util::Color;
 
 #[derive(Clone)]
 pub(crate) enum MsgMap {
-    Page(usize),
+    Page(PackId, usize),
     ShowNote(LocationId),
 }
 
@@ -25,13 +26,17 @@ impl From<MsgMap> for MsgApp {
 }
 
 pub(crate) struct PaneMap {
+    pack: PackId,
     position: usize,
     modal: Modal,
 }
 
-#[derive(Default, Serialize, Deserialize)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
 pub(crate) struct PaneMapSer {
+    // settings from before packs existed are for the base game
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pack: String,
     position: usize,
 }
 
@@ -41,13 +46,15 @@ impl SubComponent for PaneMap {
 
     fn create(_ctx: &Context<App>) -> Self {
         Self {
-            position: MAP_DEFAULT_POSITION,
+            pack: PackId::base(),
+            position: PackId::base().pack().map_default_position,
             modal: Modal::default(),
         }
     }
 
     fn reset_to_new(&mut self) {
-        self.position = MAP_DEFAULT_POSITION;
+        self.pack = PackId::base();
+        self.position = PackId::base().pack().map_default_position;
     }
 
     fn update(
@@ -57,8 +64,9 @@ impl SubComponent for PaneMap {
         msg: Self::Message,
     ) -> UpdateResults {
         match msg {
-            MsgMap::Page(pos) => {
-                if pos < MAP.len() && MAP[pos].is_some() {
+            MsgMap::Page(pack, pos) => {
+                if Self::is_valid(pack, pos) {
+                    self.pack = pack;
                     self.position = pos;
                     UpdateResult::Render | UpdateResult::SaveSettings
                 } else {
@@ -66,7 +74,7 @@ impl SubComponent for PaneMap {
                 }
             }
             MsgMap::ShowNote(location_id) => {
-                if let Some(note) = data.location.get(&location_id) {
+                if let Some(note) = data.journal.location.get(&location_id) {
                     self.modal.open(
                         &data
                             .msg
@@ -80,34 +88,53 @@ impl SubComponent for PaneMap {
     }
 
     fn view(&self, data: &Data, ctx: &Context<App>) -> Html {
-        let map = MAP.iter().enumerate().map(|(pos, pl_pr)| {
-            match pl_pr {
-                None => html! {<td/>},
-                Some((pl, pr)) => {
-                    let page = if pr.is_empty() {
-                        data.msg.page_short(pl)
-                    } else { data.msg.page_short_lr(pl, pr) };
-                    let style = if self.position == pos { Color::Success } else { Color::Warning };
-                    html! {<td width="33%" align="center"><Button size={ButtonSize::Small} style={style} onclick={ctx.link().callback(move |_| MsgMap::Page(pos))}>{page}</Button></td>}
+        let packs = &data.campaign.active().packs;
+        let (current_pack, current_position) = if packs.contains(&self.pack) {
+            (self.pack, self.position)
+        } else {
+            (packs[0], packs[0].pack().map_default_position)
+        };
+
+        let maps = packs.iter().map(|pack| {
+            let pack = *pack;
+            let map = pack.pack().map.iter().enumerate().map(|(pos, pl_pr)| {
+                match pl_pr {
+                    None => html! {<td/>},
+                    Some((pl, pr)) => {
+                        let page = if pr.is_empty() {
+                            data.msg.page_short(pl)
+                        } else { data.msg.page_short_lr(pl, pr) };
+                        let style = if current_pack == pack && current_position == pos { Color::Success } else { Color::Warning };
+                        html! {<td width="33%" align="center"><Button size={ButtonSize::Small} style={style} onclick={ctx.link().callback(move |_| MsgMap::Page(pack, pos))}>{page}</Button></td>}
+                    }
                 }
+            }).collect::<Vec<_>>();
+            let map = map.chunks(3).map(|tr| {
+                // let tr = VNode::VList(VList::with_children(tr,None));
+                let tr = tr.to_vec();
+                html! {<tr>{for tr}</tr>}
+            });
+            html! {
+                <table class="me-2 mb-2" style="background-color: #FAE4BC">
+                    if packs.len() > 1 {
+                        <thead><tr><th colspan="3">{pack.pack().name}</th></tr></thead>
+                    }
+                    <tbody>
+                        {for map}
+                    </tbody>
+                </table>
             }
-        }).collect::<Vec<_>>();
-        let map = map.chunks(3).map(|tr| {
-            // let tr = VNode::VList(VList::with_children(tr,None));
-            let tr = tr.to_vec();
-            html! {<tr>{for tr}</tr>}
         });
 
-        let left = Self::render_list(data, ctx, MAP[self.position].unwrap().0);
-        let right = Self::render_list(data, ctx, MAP[self.position].unwrap().1);
+        let (page_left, page_right) = current_pack.pack().map[current_position].unwrap();
+        let left = Self::render_list(data, ctx, current_pack, page_left);
+        let right = Self::render_list(data, ctx, current_pack, page_right);
 
         html! {
             <>
-            <table style="background-color: #FAE4BC">
-                <tbody>
-                    {for map}
-                </tbody>
-            </table>
+            <div class="d-flex flex-wrap align-items-start">
+                {for maps}
+            </div>
             <div class="row">
               <div class="col-lg-6">
                 {left}
@@ -124,29 +151,41 @@ impl SubComponent for PaneMap {
 
     fn save(&self) -> Self::Ser {
         PaneMapSer {
+            pack: self.pack.pack().key.to_string(),
             position: self.position,
         }
     }
 
     fn load(&mut self, stored: Self::Ser) {
-        if stored.position < MAP.len() && MAP[stored.position].is_some() {
-            self.position = stored.position;
+        let pack = if stored.pack.is_empty() {
+            Some(PackId::base())
         } else {
-            self.position = MAP_DEFAULT_POSITION;
+            PackId::try_from_key(&stored.pack)
+        };
+        match pack {
+            Some(pack) if Self::is_valid(pack, stored.position) => {
+                self.pack = pack;
+                self.position = stored.position;
+            }
+            _ => self.reset_to_new(),
         }
     }
 }
 
 impl PaneMap {
-    fn render_list(data: &Data, ctx: &Context<App>, page: &str) -> Html {
-        let locations = LocationId::all().filter(|l| l.page() == Some(page))
+    fn is_valid(pack: PackId, position: usize) -> bool {
+        pack.pack().map.get(position).is_some_and(Option::is_some)
+    }
+
+    fn render_list(data: &Data, ctx: &Context<App>, pack: PackId, page: &str) -> Html {
+        let locations = LocationId::all_of(pack).filter(|l| l.page() == Some(page))
             .map(|l| {
                 let location_name = text(l.name(data.quest_locale.language()));
                 let quests = itertools::Itertools::intersperse(data.quest_iter().filter_map(|(_, quest, name)| {
                     if let Some(ql) = quest.encounter.get(&l) {
-                        let vis = ql.iter().map(|(_, qle)| qle.vis).min().unwrap_or(Vis::Visible);
+                        let vis = ql.values().map(|qle| qle.vis).min().unwrap_or(Vis::Visible);
                         let vis = vis.max(quest.vis);
-                        let icons = ql.iter().map(|(et, _)| {
+                        let icons = ql.keys().map(|et| {
                             html! { <span class="ms-1">{ et.icon(false) }</span> }
                         });
 
@@ -175,7 +214,7 @@ impl PaneMap {
                             {for quests}
                         </td>
                         <td>
-                        if let Some(note) = data.location.get(&l) {
+                        if let Some(note) = data.journal.location.get(&l) {
                             {" "}
                             <Button
                               class="btn-sm d-flex align-items-center"
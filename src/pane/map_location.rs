@@ -1,4 +1,4 @@
-use crate::data::quest::QuestViewMode;
+use crate::data::quest::{QuestExt, QuestViewMode};
 use crate::data::sub_component::{SubComponent, UpdateResult, UpdateResults};
 use crate::game::{LocationId, QuestId};
 use crate::global::app::{App, MsgApp};
@@ -47,11 +47,15 @@ impl SubComponent for PaneMapLocation {
     ) -> UpdateResults {
         match msg {
             MsgMapLocation::Note(str) => {
-                data.location.insert(self.location_id, str.into());
+                data.journal.location.insert(self.location_id, str.into());
+                data.change =
+                    Some(data.msg.str_change_location_note(
+                        self.location_id.name(data.quest_locale.language()),
+                    ));
                 UpdateResult::SaveGameData.into()
             }
             MsgMapLocation::ViewQuestNote(quest_id) => {
-                if let Some(quest) = data.quest.get(&quest_id) {
+                if let Some(quest) = data.journal.quest.get(&quest_id) {
                     self.modal.open(
                         &data
                             .msg
@@ -67,6 +71,7 @@ impl SubComponent for PaneMapLocation {
     fn view(&self, data: &Data, ctx: &Context<App>) -> Html {
         let location_id = self.location_id;
         let location_note = data
+            .journal
             .location
             .get(&self.location_id)
             .map(ToString::to_string);
@@ -1,13 +1,16 @@
-use crate::data::encounter_type::EncounterType;
-use crate::data::quest::{Quest, QuestState};
+use crate::data::encounter_type::{EncounterType, EncounterTypeExt};
+use crate::data::event::EventAction;
+use crate::data::prerequisite::{
+    Prerequisite, PrerequisiteEdit, PrerequisiteExt, PrerequisitePicker,
+};
+use crate::data::quest::{Quest, QuestExt, QuestState};
 use crate::data::sub_component::{SubComponent, UpdateResult, UpdateResults};
 use crate::data::vis::Vis;
 use crate::game::{LocationId, QuestId};
 use crate::global::app::{App, MsgApp};
 use crate::global::data::Data;
-use crate::html::{callback_input_value, text};
+use crate::quest_picker::QuestPicker;
 use crate::ser::settings::EmptySer;
-use std::str::FromStr;
 use web_sys::HtmlInputElement;
 use yew::{Context, Html, NodeRef, html};
 use yew_bootstrap::component::Button;
@@ -17,7 +20,8 @@ use yew_bootstrap::util::Color;
 pub(crate) enum MsgMapNewQuest {
     SelectEncounterType(EncounterType),
     SelectQuest(QuestId),
-    SetPrerequisite(String),
+    Search(String),
+    Prerequisite(PrerequisiteEdit),
     Save,
 }
 
@@ -33,7 +37,9 @@ pub(crate) struct PaneMapNewQuest {
     location_id: LocationId,
     encounter_type: EncounterType,
     quest_id: QuestId,
-    prerequisite: Option<QuestId>,
+    prerequisite: Option<Prerequisite>,
+    prerequisite_picker: PrerequisitePicker,
+    picker: QuestPicker,
     note_input: NodeRef,
 }
 
@@ -46,10 +52,12 @@ pub(crate) enum Page {
 fn nqt_allow(nqt: EncounterType, data: &Data, quest_id: QuestId) -> bool {
     match nqt {
         EncounterType::Gain => data
+            .journal
             .quest
             .get(&quest_id)
             .is_none_or(|q| q.state == QuestState::NotFound),
         EncounterType::Complete | EncounterType::Lose => data
+            .journal
             .quest
             .get(&quest_id)
             .is_some_and(|q| q.state == QuestState::InGame),
@@ -78,6 +86,8 @@ impl SubComponent for PaneMapNewQuest {
             encounter_type: EncounterType::Unless, // anything will do
             quest_id: QuestId::cottage(),          // anything will do
             prerequisite: None,                    // anything will do
+            prerequisite_picker: PrerequisitePicker::default(),
+            picker: QuestPicker::default(),
             note_input: NodeRef::default(),
         }
     }
@@ -91,28 +101,41 @@ impl SubComponent for PaneMapNewQuest {
         match msg {
             MsgMapNewQuest::SelectEncounterType(nqt) => {
                 self.encounter_type = nqt;
+                self.picker.clear();
                 self.page = Page::SelectQuest;
                 UpdateResult::Render.into()
             }
             MsgMapNewQuest::SelectQuest(quest_id) => {
                 self.quest_id = quest_id;
                 self.prerequisite = None;
+                self.prerequisite_picker = PrerequisitePicker::default();
                 self.page = Page::NoteAndPrerequisite;
                 UpdateResult::Render.into()
             }
-            MsgMapNewQuest::SetPrerequisite(prerequisite) => {
-                self.prerequisite = usize::from_str(&prerequisite)
-                    .ok()
-                    .and_then(QuestId::from_raw);
-                UpdateResult::empty()
+            MsgMapNewQuest::Search(query) => {
+                self.picker.set_query(query);
+                UpdateResult::Render.into()
+            }
+            MsgMapNewQuest::Prerequisite(edit) => {
+                let default = self
+                    .prerequisite_candidates(data)
+                    .first()
+                    .copied()
+                    .or_else(|| data.quest_all_iter().next().map(|(q, _)| q))
+                    .unwrap_or(self.quest_id);
+                edit.apply(
+                    &mut self.prerequisite,
+                    &mut self.prerequisite_picker,
+                    default,
+                );
+                UpdateResult::Render.into()
             }
             MsgMapNewQuest::Save => {
-                let quest = data.quest.entry(self.quest_id).or_default();
+                let quest = data.journal.quest.entry(self.quest_id).or_default();
                 match self.encounter_type {
                     EncounterType::Gain => quest.state = QuestState::InGame,
-                    EncounterType::Complete | EncounterType::Lose => {
-                        quest.state = QuestState::Removed;
-                    }
+                    EncounterType::Complete => quest.state = QuestState::Completed,
+                    EncounterType::Lose => quest.state = QuestState::Lost,
                     EncounterType::When | EncounterType::Unless => (),
                 }
                 quest.note = self
@@ -122,7 +145,17 @@ impl SubComponent for PaneMapNewQuest {
                     .value()
                     .into();
                 let encounters = quest.encounter.entry(self.location_id).or_default();
-                encounters.insert(self.encounter_type, self.prerequisite, Vis::Visible);
+                encounters.insert(self.encounter_type, self.prerequisite.take(), Vis::Visible);
+                data.change = Some(self.encounter_type.str_change(
+                    data.msg,
+                    data.quest_locale.get(self.quest_id),
+                    self.location_id.name(data.quest_locale.language()),
+                ));
+                data.journal.record_event(
+                    EventAction::Record(self.encounter_type),
+                    Some(self.quest_id),
+                    Some(self.location_id),
+                );
                 data.chain_msg.push_back(MsgApp::Back);
                 UpdateResult::SaveGameData.into()
             }
@@ -179,17 +212,6 @@ impl PaneMapNewQuest {
     }
 
     pub(crate) fn view_new_quest2(&self, data: &Data, ctx: &Context<App>) -> Html {
-        let quests = data.quest_all_iter().filter_map(|(quest_id, quest_name)| {
-            if nqt_allow(self.encounter_type, data, quest_id) {
-                Some(html! {
-                    <li class="list-group-item">
-                        <Button style={Color::Primary} onclick={ctx.link().callback(move |_|MsgMapNewQuest::SelectQuest(quest_id))} children={quest_name} />
-                    </li>
-                })
-            } else {
-                None
-            }
-        });
         html! {
             <ul class="list-group">
                 <li class="list-group-item">
@@ -201,58 +223,47 @@ impl PaneMapNewQuest {
                 <li class="list-group-item">
                     <h3>{nqt_head(self.encounter_type)}</h3>
                 </li>
-                {for quests}
+                <li class="list-group-item">
+                    {self.picker.view(
+                        data,
+                        ctx,
+                        "input-search-new-quest",
+                        |quest_id| nqt_allow(self.encounter_type, data, quest_id),
+                        MsgMapNewQuest::Search,
+                        MsgMapNewQuest::SelectQuest,
+                    )}
+                </li>
             </ul>
         }
     }
 
-    pub(crate) fn view_new_quest3(&self, data: &Data, ctx: &Context<App>) -> Html {
-        let mut prev = if self.encounter_type == EncounterType::Gain {
-            data.quest
-                .iter()
-                .filter_map(|(quest_id, quest)| {
-                    if quest.state == QuestState::Removed
-                        && quest.encounter.iter().any(|(location_id, es)| {
-                            *location_id == self.location_id
-                                && (es.contains_key(EncounterType::Complete)
-                                    || es.contains_key(EncounterType::Lose))
-                        })
-                    {
-                        Some(*quest_id)
-                    } else {
-                        None
-                    }
-                })
-                .collect()
-        } else {
-            vec![]
-        };
-        prev.sort_by_cached_key(|quest_id| data.quest_locale.get(*quest_id));
-        let mut prerequisite = Vec::new();
-        if !prev.is_empty() {
-            prerequisite.push(html! {
-                <div class="form-check">
-                    <input class="form-check-input" type="radio" name="selectPrerequisite" id="selectPrerequisiteOff" value="" checked={true} onchange={callback_input_value(ctx, MsgMapNewQuest::SetPrerequisite)} />
-                    <label class="form-check-label" for="selectPrerequisiteOff">
-                        {data.msg.ma_nq_no_prerequisite()}
-                    </label>
-                </div>
-            });
-            for (pos, quest_id) in prev.into_iter().enumerate() {
-                let id = format!("selectPrerequisite{pos}");
-                let name = data.quest_locale.get(quest_id);
-                prerequisite.push(html! {
-                    <div class="form-check">
-                        <input class="form-check-input" type="radio" name="selectPrerequisite" id={id.clone()} value={quest_id.raw().to_string()} onchange={callback_input_value(ctx, MsgMapNewQuest::SetPrerequisite)} />
-                        <label class="form-check-label" for={id}>
-                            {text(name)}
-                        </label>
-                    </div>
-            });
-            }
-        }
+    // quests which were completed or lost at this location are the most likely prerequisites
+    fn prerequisite_candidates(&self, data: &Data) -> Vec<QuestId> {
+        let mut result = data
+            .journal
+            .quest
+            .iter()
+            .filter_map(|(quest_id, quest)| {
+                if quest.state.is_done()
+                    && quest.encounter.iter().any(|(location_id, es)| {
+                        *location_id == self.location_id
+                            && (es.contains_key(EncounterType::Complete)
+                                || es.contains_key(EncounterType::Lose))
+                    })
+                {
+                    Some(*quest_id)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        result.sort_by_cached_key(|quest_id| data.quest_locale.get(*quest_id));
+        result
+    }
 
+    pub(crate) fn view_new_quest3(&self, data: &Data, ctx: &Context<App>) -> Html {
         let quest_note = data
+            .journal
             .quest
             .get(&self.quest_id)
             .map_or("", |quest| &quest.note)
@@ -272,12 +283,17 @@ impl PaneMapNewQuest {
                 <li class="list-group-item">
                     <h3>{data.msg.quest_header( data.quest_locale.get(self.quest_id), Quest::icon_from_raw(self.quest_id))}</h3>
                 </li>
-                if !prerequisite.is_empty() {
-                    <li class="list-group-item">
-                    {data.msg.ma_nq_prerequisite()}
-                    {for prerequisite}
-                    </li>
-                }
+                <li class="list-group-item">
+                {data.msg.ma_nq_prerequisite()}<br/>
+                {Prerequisite::view_editor(
+                    self.prerequisite.as_ref(),
+                    data,
+                    ctx,
+                    "select-prerequisite",
+                    Some(&self.prerequisite_picker),
+                    MsgMapNewQuest::Prerequisite,
+                )}
+                </li>
                 <li class="list-group-item">
                     {data.msg.note_quest()}<br/>
                     <div class="form-floating">
@@ -1,9 +1,18 @@
 pub(crate) mod action;
+pub(crate) mod crew;
 pub(crate) mod edit;
 pub(crate) mod edit_quest;
+pub(crate) mod graph;
 pub(crate) mod info;
+pub(crate) mod inventory;
+pub(crate) mod ledger;
 pub(crate) mod map;
 pub(crate) mod map_location;
 pub(crate) mod map_new_quest;
+pub(crate) mod score;
+pub(crate) mod search;
+pub(crate) mod sessions;
 pub(crate) mod settings;
+pub(crate) mod timeline;
 pub(crate) mod todo;
+pub(crate) mod totems;
@@ -0,0 +1,149 @@
+use crate::data::sub_component::{SubComponent, UpdateResult, UpdateResults};
+use crate::global::app::{App, MsgApp};
+use crate::global::data::Data;
+use crate::html::callback_input_value;
+use crate::ser::settings::EmptySer;
+use sleeping_journal_core::data::score::{ScorePart, ScoreRate};
+use std::str::FromStr;
+use yew::{Context, Html, html};
+
+#[derive(Clone)]
+pub(crate) enum MsgScore {
+    Points(ScorePart, String),
+    Per(ScorePart, String),
+}
+
+impl From<MsgScore> for MsgApp {
+    #[inline]
+    fn from(msg: MsgScore) -> Self {
+        MsgApp::MsgScore(msg)
+    }
+}
+
+pub(crate) struct PaneScore {}
+
+impl SubComponent for PaneScore {
+    type Message = MsgScore;
+    type Ser = EmptySer;
+
+    fn create(_ctx: &Context<App>) -> Self {
+        Self {}
+    }
+
+    fn update(
+        &mut self,
+        data: &mut Data,
+        _ctx: &Context<App>,
+        msg: Self::Message,
+    ) -> UpdateResults {
+        let (part, rate) = match msg {
+            MsgScore::Points(part, points) => match i32::from_str(&points) {
+                Ok(points) => (
+                    part,
+                    ScoreRate {
+                        points,
+                        ..data.journal.score_rate(part)
+                    },
+                ),
+                Err(_) => return UpdateResult::Render.into(),
+            },
+            MsgScore::Per(part, per) => match i32::from_str(&per) {
+                Ok(per) if per > 0 => (
+                    part,
+                    ScoreRate {
+                        per,
+                        ..data.journal.score_rate(part)
+                    },
+                ),
+                _ => return UpdateResult::Render.into(),
+            },
+        };
+        if rate == ScoreRate::default_for(part) {
+            data.journal.score_rate.remove(&part);
+        } else {
+            data.journal.score_rate.insert(part, rate);
+        }
+        data.change = Some(data.msg.str_change_score_rate(part_text(data, part)));
+        UpdateResult::Render | UpdateResult::SaveGameData
+    }
+
+    fn view(&self, data: &Data, ctx: &Context<App>) -> Html {
+        let score = data.journal.score();
+        let total = score
+            .iter()
+            .map(|line| line.points)
+            .fold(0, i32::saturating_add);
+        let rows = score.into_iter().map(|line| {
+            let part = line.part;
+            html! {
+                <tr>
+                    <td>{part_text(data, part)}</td>
+                    <td class="text-end">{line.count}</td>
+                    <td>
+                        <div class="input-group input-group-sm" style="max-width: 14rem">
+                            <input
+                                type="number"
+                                class="form-control"
+                                value={line.rate.points.to_string()}
+                                onchange={callback_input_value(ctx, move |points| MsgScore::Points(part, points))}
+                            />
+                            <span class="input-group-text">{data.msg.score_rate_per()}</span>
+                            <input
+                                type="number"
+                                class="form-control"
+                                min="1"
+                                value={line.rate.per.to_string()}
+                                onchange={callback_input_value(ctx, move |per| MsgScore::Per(part, per))}
+                            />
+                        </div>
+                    </td>
+                    <td class="text-end">{line.points}</td>
+                </tr>
+            }
+        });
+        html! {
+            <>
+            <h2 class="h4 mb-4">{data.msg.score_header()}</h2>
+            <div class="table-responsive">
+                <table class="table align-middle">
+                    <thead>
+                        <tr>
+                            <th></th>
+                            <th class="text-end">{data.msg.score_count()}</th>
+                            <th>{data.msg.score_rate()}</th>
+                            <th class="text-end">{data.msg.score_points()}</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {for rows}
+                    </tbody>
+                    <tfoot>
+                        <tr class="fw-bold">
+                            <td colspan="3">{data.msg.score_total()}</td>
+                            <td class="text-end">{total}</td>
+                        </tr>
+                    </tfoot>
+                </table>
+            </div>
+            <p class="text-body-secondary">{data.msg.score_hint()}</p>
+            </>
+        }
+    }
+
+    fn save(&self) -> Self::Ser {
+        EmptySer {}
+    }
+
+    fn load(&mut self, _stored: Self::Ser) {}
+}
+
+fn part_text(data: &Data, part: ScorePart) -> &'static str {
+    match part {
+        ScorePart::Totems => data.msg.str_score_totems(),
+        ScorePart::CompletedQuests => data.msg.str_score_completed_quests(),
+        ScorePart::Coins => data.msg.str_ledger_coins(),
+        ScorePart::Food => data.msg.str_ledger_food(),
+        ScorePart::ShipDamage => data.msg.str_ledger_ship_damage(),
+        ScorePart::CrewLevels => data.msg.str_score_crew_levels(),
+    }
+}
@@ -0,0 +1,220 @@
+use crate::data::note::{Note, NoteExt};
+use crate::data::quest::QuestExt;
+use crate::data::sub_component::{SubComponent, UpdateResult, UpdateResults};
+use crate::game::LocationId;
+use crate::global::app::{App, MsgApp};
+use crate::global::data::Data;
+use crate::html::text;
+use crate::route::Route;
+use crate::ser::settings::EmptySer;
+use web_sys::HtmlInputElement;
+use yew::{Context, Html, InputEvent, TargetCast, html};
+use yew_bootstrap::component::{Button, ButtonSize};
+use yew_bootstrap::util::Color;
+
+// characters shown before and after the first match
+const CONTEXT: usize = 40;
+
+#[derive(Clone)]
+pub(crate) enum MsgSearch {
+    Query(String),
+}
+
+impl From<MsgSearch> for MsgApp {
+    #[inline]
+    fn from(msg: MsgSearch) -> Self {
+        MsgApp::MsgSearch(msg)
+    }
+}
+
+pub(crate) struct PaneSearch {
+    query: String,
+}
+
+impl SubComponent for PaneSearch {
+    type Message = MsgSearch;
+    type Ser = EmptySer;
+
+    fn create(_ctx: &Context<App>) -> Self {
+        Self {
+            query: String::new(),
+        }
+    }
+
+    fn reset_to_new(&mut self) {
+        self.query.clear();
+    }
+
+    fn update(
+        &mut self,
+        data: &mut Data,
+        _ctx: &Context<App>,
+        msg: Self::Message,
+    ) -> UpdateResults {
+        match msg {
+            MsgSearch::Query(query) => {
+                // the query is part of the route, but typing should not fill the history
+                self.query.clone_from(&query);
+                data.chain_msg
+                    .push_back(MsgApp::GoReplace(Route::Search(query)));
+                UpdateResult::Render.into()
+            }
+        }
+    }
+
+    fn view(&self, data: &Data, ctx: &Context<App>) -> Html {
+        let query = lowercase(self.query.trim());
+        let results = if query.is_empty() {
+            Vec::new()
+        } else {
+            Self::results(data, ctx, &query)
+        };
+
+        html! {
+            <>
+            <input
+                type="search"
+                class="form-control mb-3"
+                id="input-search"
+                value={self.query.clone()}
+                placeholder={data.msg.str_search_placeholder()}
+                oninput={ctx.link().callback(|event: InputEvent| {
+                    MsgSearch::Query(event.target_unchecked_into::<HtmlInputElement>().value())
+                })}
+            />
+            if query.is_empty() {
+                <p>{data.msg.search_hint()}</p>
+            } else if results.is_empty() {
+                <p>{data.msg.search_empty()}</p>
+            } else {
+                <table class="table table-hover align-middle">
+                    <tbody>
+                        {for results}
+                    </tbody>
+                </table>
+            }
+            </>
+        }
+    }
+
+    fn save(&self) -> Self::Ser {
+        EmptySer {}
+    }
+
+    fn load(&mut self, _stored: Self::Ser) {}
+}
+
+impl PaneSearch {
+    pub(crate) fn go(&mut self, query: String) {
+        self.query = query;
+    }
+
+    fn results(data: &Data, ctx: &Context<App>, query: &[char]) -> Vec<Html> {
+        let language = data.quest_locale.language();
+        let packs = &data.campaign.active().packs;
+        let mut results = Vec::new();
+
+        // locations, by their number or note
+        for location_id in LocationId::all() {
+            if location_id == LocationId::prologue() || !packs.contains(&location_id.pack()) {
+                continue;
+            }
+            let name = location_id.name(language);
+            let name_match = snippet(name, query);
+            let note_match = data
+                .journal
+                .location
+                .get(&location_id)
+                .and_then(|note| snippet(note, query));
+            if name_match.is_some() || note_match.is_some() {
+                results.push(html! {
+                    <tr>
+                        <td>
+                            <Button
+                                size={ButtonSize::Small}
+                                onclick={ctx.link().callback(move |_| MsgApp::Go(Route::MapLocation(location_id)))}
+                            >
+                                {name_match.unwrap_or_else(|| text(name))}
+                            </Button>
+                        </td>
+                        <td>
+                            if let Some(note_match) = note_match {
+                                <span class="me-1">{Note::icon()}</span>{note_match}
+                            }
+                        </td>
+                    </tr>
+                });
+            }
+        }
+
+        // quests, by their name or note
+        for (quest_id, quest, quest_name) in data.quest_iter() {
+            let name_match = snippet(quest_name, query);
+            let note_match = snippet(&quest.note, query);
+            if name_match.is_some() || note_match.is_some() {
+                results.push(html! {
+                    <tr>
+                        <td>
+                            <Button
+                                size={ButtonSize::Small}
+                                style={Color::Secondary}
+                                onclick={ctx.link().callback(move |_| MsgApp::Go(Route::EditQuest(quest_id)))}
+                            >
+                                {quest.icon(quest_id)}{" "}{name_match.unwrap_or_else(|| text(quest_name))}
+                            </Button>
+                        </td>
+                        <td>
+                            if let Some(note_match) = note_match {
+                                <span class="me-1">{Note::icon()}</span>{note_match}
+                            }
+                        </td>
+                    </tr>
+                });
+            }
+        }
+
+        results
+    }
+}
+
+// lowercase per character, thus the positions stay the same
+fn lowercase(s: &str) -> Vec<char> {
+    s.chars()
+        .map(|c| c.to_lowercase().next().unwrap_or(c))
+        .collect()
+}
+
+fn find(haystack: &[char], needle: &[char], from: usize) -> Option<usize> {
+    (from..=haystack.len().checked_sub(needle.len())?)
+        .find(|pos| haystack[*pos..*pos + needle.len()] == *needle)
+}
+
+// the part of the text around the first match, with all matches highlighted
+fn snippet(content: &str, query: &[char]) -> Option<Html> {
+    let chars = content
+        .chars()
+        .map(|c| if c == '\n' { ' ' } else { c })
+        .collect::<Vec<_>>();
+    let lower = lowercase(content);
+    let first = find(&lower, query, 0)?;
+    let start = first.saturating_sub(CONTEXT);
+    let end = (first + query.len() + CONTEXT).min(chars.len());
+
+    let mut parts = Vec::new();
+    if start > 0 {
+        parts.push(html! {"…"});
+    }
+    let mut pos = start;
+    while let Some(found) = find(&lower, query, pos).filter(|found| found + query.len() <= end) {
+        let before = chars[pos..found].iter().collect::<String>();
+        let matched = chars[found..found + query.len()].iter().collect::<String>();
+        parts.push(html! {<>{before}<mark>{matched}</mark></>});
+        pos = found + query.len();
+    }
+    parts.push(html! {{chars[pos..end].iter().collect::<String>()}});
+    if end < chars.len() {
+        parts.push(html! {"…"});
+    }
+
+    Some(html! {<>{for parts}</>})
+}
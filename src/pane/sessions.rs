@@ -0,0 +1,305 @@
+use crate::data::encounter_type::EncounterType;
+use crate::data::sub_component::{SubComponent, UpdateResult, UpdateResults};
+use crate::game::QuestId;
+use crate::global::app::{App, MsgApp};
+use crate::global::data::Data;
+use crate::html::download;
+use crate::ser::settings::EmptySer;
+use chrono::Local;
+use sleeping_journal_core::data::session::SessionSummary;
+use sleeping_journal_core::journal::NoteId;
+use yew::{Context, Html, NodeRef, html};
+use yew_bootstrap::component::{Button, ButtonSize};
+use yew_bootstrap::icons::BI;
+use yew_bootstrap::util::Color;
+
+#[derive(Clone)]
+pub(crate) enum MsgSessions {
+    Start,
+    End,
+    Export(usize),
+}
+
+impl From<MsgSessions> for MsgApp {
+    #[inline]
+    fn from(msg: MsgSessions) -> Self {
+        MsgApp::MsgSessions(msg)
+    }
+}
+
+pub(crate) struct PaneSessions {
+    save_element: NodeRef,
+}
+
+impl SubComponent for PaneSessions {
+    type Message = MsgSessions;
+    type Ser = EmptySer;
+
+    fn create(_ctx: &Context<App>) -> Self {
+        Self {
+            save_element: NodeRef::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        data: &mut Data,
+        _ctx: &Context<App>,
+        msg: Self::Message,
+    ) -> UpdateResults {
+        match msg {
+            MsgSessions::Start => {
+                data.journal.start_session();
+                data.change = Some(data.msg.str_change_session_start().to_string());
+                UpdateResult::Render | UpdateResult::SaveGameData
+            }
+            MsgSessions::End => {
+                if data.journal.end_session().is_none() {
+                    return UpdateResult::empty();
+                }
+                data.change = Some(data.msg.str_change_session_end().to_string());
+                UpdateResult::Render | UpdateResult::SaveGameData
+            }
+            MsgSessions::Export(pos) => {
+                if let Some(summary) = data.journal.session.get(pos) {
+                    let date = summary
+                        .local_start()
+                        .map(|time| time.format("%Y-%m-%d").to_string())
+                        .unwrap_or_default();
+                    download(
+                        &self.save_element,
+                        "text/plain",
+                        &format!("sgh_session_{}_{date}.txt", pos + 1),
+                        summary_text(data, pos + 1, summary),
+                    );
+                }
+                UpdateResult::empty()
+            }
+        }
+    }
+
+    fn view(&self, data: &Data, ctx: &Context<App>) -> Html {
+        let running = data.journal.session_start.as_ref().map(|start| {
+            // what changed so far, it's summarized again on every render
+            let summary = data
+                .journal
+                .summarize(start.clone(), Local::now().timestamp());
+            let since = summary
+                .local_start()
+                .map(|time| time.format("%H:%M").to_string())
+                .unwrap_or_default();
+            html! {
+                <div class="card border-primary mb-4">
+                    <div class="card-header d-flex align-items-center">
+                        <span>{data.msg.session_running(&since)}</span>
+                        <Button
+                            style={Color::Primary}
+                            class="ms-auto"
+                            onclick={ctx.link().callback(|_|MsgSessions::End)}
+                        >
+                            {BI::STOP_FILL}{data.msg.session_end()}
+                        </Button>
+                    </div>
+                    <div class="card-body">
+                        {view_summary(data, &summary)}
+                    </div>
+                </div>
+            }
+        });
+
+        let sessions = data
+            .journal
+            .session
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(pos, summary)| {
+                html! {
+                    <div class="card mb-3">
+                        <div class="card-header d-flex align-items-center">
+                            <span>{session_title(data, pos + 1, summary)}</span>
+                            <Button
+                                size={ButtonSize::Small}
+                                style={Color::Secondary}
+                                outline={true}
+                                class="ms-auto"
+                                onclick={ctx.link().callback(move |_|MsgSessions::Export(pos))}
+                            >
+                                {BI::DOWNLOAD}{" "}{data.msg.session_export()}
+                            </Button>
+                        </div>
+                        <div class="card-body">
+                            {view_summary(data, summary)}
+                        </div>
+                    </div>
+                }
+            })
+            .collect::<Vec<_>>();
+
+        html! {
+            <>
+            if let Some(running) = running {
+                {running}
+            } else {
+                <div class="d-flex align-items-center mb-4">
+                    <p class="mb-0">{data.msg.session_hint()}</p>
+                    <Button
+                        style={Color::Primary}
+                        class="ms-auto"
+                        onclick={ctx.link().callback(|_|MsgSessions::Start)}
+                    >
+                        {BI::PLAY_FILL}{data.msg.session_start()}
+                    </Button>
+                </div>
+            }
+
+            <h2 class="h4 mb-3">{data.msg.session_header()}</h2>
+            if sessions.is_empty() {
+                <p>{data.msg.session_empty()}</p>
+            } else {
+                {for sessions}
+            }
+            <a
+                ref={&self.save_element}
+                style="display: none"
+            />
+            </>
+        }
+    }
+
+    fn save(&self) -> Self::Ser {
+        EmptySer {}
+    }
+
+    fn load(&mut self, _stored: Self::Ser) {}
+}
+
+fn session_title(data: &Data, number: usize, summary: &SessionSummary) -> String {
+    let start = summary
+        .local_start()
+        .map(|time| time.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default();
+    let end = summary
+        .local_end()
+        .map(|time| time.format("%H:%M").to_string())
+        .unwrap_or_default();
+    data.msg
+        .str_session_title(&number.to_string(), &start, &end)
+}
+
+// every part of the summary which is not empty, with one line per quest, encounter, note or location
+fn sections(data: &Data, summary: &SessionSummary) -> Vec<(&'static str, Vec<String>)> {
+    let language = data.quest_locale.language();
+    let quests = |quest_ids: &[QuestId]| {
+        quest_ids
+            .iter()
+            .map(|quest_id| data.quest_locale.get(*quest_id).to_string())
+            .collect::<Vec<_>>()
+    };
+    let sections = [
+        (
+            data.msg.str_session_quest_gained(),
+            quests(&summary.quest_gained),
+        ),
+        (
+            data.msg.str_session_quest_completed(),
+            quests(&summary.quest_completed),
+        ),
+        (
+            data.msg.str_session_quest_lost(),
+            quests(&summary.quest_lost),
+        ),
+        (
+            data.msg.str_session_encounter(),
+            summary
+                .encounter
+                .iter()
+                .map(|(quest_id, location_id, encounter_type)| {
+                    data.msg.str_session_encounter_line(
+                        data.quest_locale.get(*quest_id),
+                        location_id.name(language),
+                        encounter_type_text(data, *encounter_type),
+                    )
+                })
+                .collect(),
+        ),
+        (
+            data.msg.str_session_note(),
+            summary
+                .note
+                .iter()
+                .map(|note_id| match note_id {
+                    NoteId::Quest(quest_id) => data.quest_locale.get(*quest_id).to_string(),
+                    NoteId::Location(location_id) => location_id.name(language).to_string(),
+                })
+                .collect(),
+        ),
+        (
+            data.msg.str_session_location(),
+            summary
+                .location
+                .iter()
+                .map(|location_id| location_id.name(language).to_string())
+                .collect(),
+        ),
+    ];
+    sections
+        .into_iter()
+        .filter(|(_, lines)| !lines.is_empty())
+        .collect()
+}
+
+fn view_summary(data: &Data, summary: &SessionSummary) -> Html {
+    let sections = sections(data, summary);
+    if sections.is_empty() {
+        return html! {<p class="mb-0">{data.msg.session_nothing()}</p>};
+    }
+    let sections = sections.into_iter().map(|(label, lines)| {
+        html! {
+            <div class="col-md-6 col-lg-4 mb-3">
+                <h3 class="h6">{label}{" "}<span class="badge text-bg-secondary">{lines.len()}</span></h3>
+                <ul class="mb-0">
+                    {for lines.into_iter().map(|line| html! {<li>{line}</li>})}
+                </ul>
+            </div>
+        }
+    });
+    html! {
+        <div class="row">
+            {for sections}
+        </div>
+    }
+}
+
+// the summary as plain text, e.g. to be read at the start of the next session
+fn summary_text(data: &Data, number: usize, summary: &SessionSummary) -> String {
+    let mut text = session_title(data, number, summary);
+    text.push('\n');
+    let sections = sections(data, summary);
+    if sections.is_empty() {
+        text.push('\n');
+        text.push_str(data.msg.str_session_nothing());
+        text.push('\n');
+    }
+    for (label, lines) in sections {
+        text.push('\n');
+        text.push_str(label);
+        text.push('\n');
+        for line in lines {
+            text.push_str("- ");
+            text.push_str(&line);
+            text.push('\n');
+        }
+    }
+    text
+}
+
+fn encounter_type_text(data: &Data, encounter_type: EncounterType) -> &'static str {
+    match encounter_type {
+        EncounterType::Unless => data.msg.str_session_type_unless(),
+        EncounterType::Gain => data.msg.str_session_type_gain(),
+        EncounterType::When => data.msg.str_session_type_when(),
+        EncounterType::Complete => data.msg.str_session_type_complete(),
+        EncounterType::Lose => data.msg.str_session_type_lose(),
+    }
+}
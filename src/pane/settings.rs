@@ -1,15 +1,23 @@
-use crate::data::quest::QuestState;
+use crate::data::encounter_type::EncounterTypeExt;
+use crate::data::item::ItemKindExt;
+use crate::data::prerequisite::PrerequisiteExt;
+use crate::data::quest::{QuestState, QuestStateExt};
 use crate::data::sub_component::{SubComponent, UpdateResult, UpdateResults};
-use crate::data::vis::Vis;
-use crate::game::{GameLanguage, MsgLanguage, QuestId};
+use crate::game::{GameLanguage, MsgLanguage, PackId, QuestLocale};
 use crate::global::app::{App, MsgApp};
+use crate::global::campaign::{CampaignId, Campaigns};
 use crate::global::data::Data;
-use crate::html::text;
-use crate::ser::csv::MyError;
-use base64::Engine;
+use crate::html::{callback_input_value, download, text};
+use crate::ser::settings::SerdeCampaignPanes;
 use gloo_file::File;
 use gloo_file::callbacks::FileReader;
+use gloo_storage::{LocalStorage, Storage};
 use serde::{Deserialize, Serialize};
+use sleeping_journal_core::data::item::Item;
+use sleeping_journal_core::data::totem::Totem;
+use sleeping_journal_core::journal::{Choice, Conflict, ConflictId, Difference, Journal, NoteId};
+use sleeping_journal_core::ser::csv::{MyError, Reason, Rejected};
+use std::collections::HashMap;
 use web_sys::{Event, HtmlElement, HtmlInputElement, HtmlSelectElement};
 use yew::{AttrValue, Context, Html, NodeRef, TargetCast, html};
 use yew_bootstrap::component::form::{FormControl, FormControlType, SelectOption};
@@ -23,13 +31,25 @@ pub(crate) enum MsgSettings {
     GameLanguage(String),
     MsgLanguage(String),
     Save,
+    SaveJson,
     ClickLoad,
     StartLoad,
     LoadFinished(Vec<u8>),
+    LoadMerge(bool),
+    LoadConflict(ConflictId, Choice),
+    LoadApply,
+    LoadCancel,
     CloseAlert,
     DarkMode(bool),
+    SpoilerSafe(bool),
     NewCampaign,
     Clear,
+    CampaignAdd,
+    CampaignRename(CampaignId, String),
+    CampaignPack(CampaignId, PackId, bool),
+    CampaignDuplicate(CampaignId),
+    CampaignAskDelete(CampaignId),
+    CampaignDelete,
 }
 
 impl From<MsgSettings> for MsgApp {
@@ -43,11 +63,64 @@ pub(crate) struct PaneSettings {
     save_element: NodeRef,
     load_element: NodeRef,
     file_reader: Option<FileReader>,
-    alert: Option<(Color, &'static str, Option<String>)>,
+    alert: Option<LoadAlert>,
+    preview: Option<Preview>,
     dark_mode: bool,
+    campaign_to_delete: Option<CampaignId>,
 }
 
-#[derive(Default, Serialize, Deserialize)]
+// a loaded file, it replaces the campaign after confirmation
+struct Preview {
+    journal: Journal,
+    quest_locale: QuestLocale,
+    // merged into the campaign instead of replacing it
+    merge: bool,
+    // the kept values of the merge conflicts, see `Conflict::default_choice` for the others
+    choices: HashMap<ConflictId, Choice>,
+}
+
+impl Preview {
+    fn new(journal: Journal, quest_locale: QuestLocale) -> Self {
+        Self {
+            journal,
+            quest_locale,
+            merge: false,
+            choices: HashMap::new(),
+        }
+    }
+
+    // the campaign after applying the file and the conflicts
+    fn result(&self, data: &Data) -> (Journal, Vec<Conflict>) {
+        if !self.merge {
+            // a CSV file has no sessions, thus the ones of the campaign are kept
+            let mut journal = self.journal.clone();
+            journal.session.clone_from(&data.journal.session);
+            journal
+                .session_start
+                .clone_from(&data.journal.session_start);
+            return (journal, Vec::new());
+        }
+        let mut journal = data.journal.clone();
+        let conflicts = journal.merge(&self.journal);
+        for conflict in &conflicts {
+            let choice = self
+                .choices
+                .get(&conflict.id())
+                .copied()
+                .unwrap_or_else(|| conflict.default_choice());
+            journal.resolve(conflict.clone(), choice);
+        }
+        (journal, conflicts)
+    }
+}
+
+// the result of loading a file
+enum LoadAlert {
+    Failed(MyError),
+    Rejected(Vec<Rejected>),
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
 pub(crate) struct PaneSettingsSer {
     dark_mode: bool,
 }
@@ -62,10 +135,17 @@ impl SubComponent for PaneSettings {
             load_element: NodeRef::default(),
             file_reader: None,
             alert: None,
+            preview: None,
             dark_mode: false,
+            campaign_to_delete: None,
         }
     }
 
+    fn reset_to_new(&mut self) {
+        // the file belongs to another campaign or was applied
+        self.preview = None;
+    }
+
     fn update(&mut self, data: &mut Data, ctx: &Context<App>, msg: Self::Message) -> UpdateResults {
         match msg {
             MsgSettings::GameLanguage(language) => {
@@ -87,21 +167,16 @@ impl SubComponent for PaneSettings {
                 UpdateResult::Render | UpdateResult::SaveSettings
             }
             MsgSettings::Save => {
-                let csv = data.save_csv();
-                let mut file = String::with_capacity((50 + csv.len()) * 6 / 8);
-                file.push_str("data:text/csv;charset=UTF-8;base64,");
-                base64::engine::general_purpose::STANDARD.encode_string(csv, &mut file);
-                let link: HtmlElement = self.save_element.cast::<HtmlElement>().unwrap();
-                let _ = link.set_attribute("href", &file);
-                let _ = link.set_attribute(
-                    "download",
-                    &format!(
-                        "sgh_{}.csv",
-                        chrono::offset::Local::now().format("%Y-%m-%d_%H:%M")
-                    ),
-                );
-                link.click();
-
+                self.download("text/csv", "csv", data.journal.save_csv(&data.quest_locale));
+                UpdateResult::empty()
+            }
+            MsgSettings::SaveJson => {
+                // the game data is stored on every change, thus the stored JSON is up to date
+                if let Ok(Some(json)) =
+                    LocalStorage::raw().get_item(&data.campaign.active_id().storage_key())
+                {
+                    self.download("application/json", "json", json);
+                }
                 UpdateResult::empty()
             }
             MsgSettings::ClickLoad => {
@@ -129,31 +204,58 @@ impl SubComponent for PaneSettings {
                 UpdateResult::empty()
             }
             MsgSettings::LoadFinished(file) => {
-                match data.load_csv(&file) {
-                    Ok(err) => {
-                        if !err.is_empty() {
-                            self.alert = Some((
-                                Color::Info,
-                                "The following rows have errors and are skipped:",
-                                Some(err),
-                            ));
-                        }
+                // loaded separately, the campaign is only replaced after confirmation
+                let mut journal = Journal::default();
+                let mut quest_locale = data.quest_locale.clone();
+                match journal.load_csv(&file, &data.campaign.active().packs, &mut quest_locale) {
+                    Ok(rejected) => {
+                        self.alert =
+                            (!rejected.is_empty()).then_some(LoadAlert::Rejected(rejected));
+                        self.preview = Some(Preview::new(journal, quest_locale));
                     }
                     Err(err) => {
-                        self.alert = Some((
-                            Color::Danger,
-                            match err {
-                                MyError::CsvError => "CsvError",
-                                MyError::Header => "Header",
-                                MyError::Language => "Language",
-                            },
-                            None,
-                        ));
+                        if matches!(err, MyError::CsvError) {
+                            // the file was partially loaded
+                            self.preview = Some(Preview::new(journal, quest_locale));
+                        }
+                        self.alert = Some(LoadAlert::Failed(err));
                     }
                 }
                 self.file_reader = None;
-                data.chain_msg.push_back(MsgApp::ResetToNew);
-                UpdateResult::SaveGameData.into()
+                UpdateResult::Render.into()
+            }
+            MsgSettings::LoadApply => {
+                if let Some(preview) = self.preview.take() {
+                    data.journal = preview.result(data).0;
+                    if preview.merge {
+                        data.change = Some(data.msg.str_change_merge().to_string());
+                    } else {
+                        // the quests of the file are in its language
+                        data.quest_locale = preview.quest_locale;
+                        data.change = Some(data.msg.str_change_load().to_string());
+                    }
+                    data.chain_msg.push_back(MsgApp::ResetToNew);
+                    UpdateResult::SaveGameData.into()
+                } else {
+                    UpdateResult::empty()
+                }
+            }
+            MsgSettings::LoadMerge(merge) => {
+                if let Some(preview) = &mut self.preview {
+                    preview.merge = merge;
+                }
+                UpdateResult::Render.into()
+            }
+            MsgSettings::LoadConflict(conflict_id, choice) => {
+                if let Some(preview) = &mut self.preview {
+                    preview.choices.insert(conflict_id, choice);
+                }
+                UpdateResult::Render.into()
+            }
+            MsgSettings::LoadCancel => {
+                self.preview = None;
+                self.alert = None;
+                UpdateResult::Render.into()
             }
             MsgSettings::CloseAlert => {
                 self.alert = None;
@@ -164,32 +266,78 @@ impl SubComponent for PaneSettings {
                 self.set_dark_mode();
                 UpdateResult::SaveSettings.into()
             }
+            MsgSettings::SpoilerSafe(spoiler_safe) => {
+                data.spoiler_safe = spoiler_safe;
+                UpdateResult::Render | UpdateResult::SaveSettings
+            }
             MsgSettings::NewCampaign => {
-                for (quest_id, quest) in &mut data.quest {
-                    if *quest_id == QuestId::raid() || *quest_id == QuestId::cottage() {
-                        quest.state = QuestState::InGame;
-                    } else {
-                        quest.state = QuestState::NotFound;
-                    }
-                    if quest.vis == Vis::HiddenThisCampaign {
-                        quest.vis = Vis::Visible;
-                    }
-                    for quest_location in quest.encounter.values_mut() {
-                        for (_, e) in quest_location {
-                            if e.vis == Vis::HiddenThisCampaign {
-                                e.vis = Vis::Visible;
-                            }
-                        }
-                    }
-                }
+                data.journal.new_campaign();
+                data.change = Some(data.msg.str_change_new_campaign().to_string());
                 data.chain_msg.push_back(MsgApp::ResetToNew);
                 UpdateResult::SaveGameData.into()
             }
             MsgSettings::Clear => {
                 data.reset();
+                data.change = Some(data.msg.str_change_clear().to_string());
                 data.chain_msg.push_back(MsgApp::ResetToNew);
                 UpdateResult::SaveGameData.into()
             }
+            MsgSettings::CampaignAdd => {
+                let campaign_id = data.campaign.add(
+                    String::new(),
+                    vec![PackId::base()],
+                    SerdeCampaignPanes::default(),
+                );
+                data.chain_msg
+                    .push_back(MsgApp::SwitchCampaign(campaign_id));
+                UpdateResult::SaveSettings.into()
+            }
+            MsgSettings::CampaignRename(campaign_id, name) => {
+                if let Some(campaign) = data.campaign.get_mut(campaign_id) {
+                    campaign.name = name.trim().to_string();
+                }
+                UpdateResult::Render | UpdateResult::SaveSettings
+            }
+            MsgSettings::CampaignPack(campaign_id, pack, enabled) => {
+                let changed = data
+                    .campaign
+                    .get_mut(campaign_id)
+                    .is_some_and(|campaign| campaign.set_pack(pack, enabled));
+                if !changed {
+                    UpdateResult::Render.into()
+                } else if campaign_id == data.campaign.active_id() {
+                    data.packs_changed();
+                    UpdateResult::Render | UpdateResult::SaveSettings | UpdateResult::SaveGameData
+                } else {
+                    UpdateResult::Render | UpdateResult::SaveSettings
+                }
+            }
+            MsgSettings::CampaignDuplicate(campaign_id) => {
+                if let Some(campaign) = data.campaign.get(campaign_id) {
+                    let name = data
+                        .msg
+                        .str_sett_camp_copy_name(&campaign.display_name(data.msg));
+                    let packs = campaign.packs.clone();
+                    let panes = campaign.panes.clone();
+                    let new_campaign_id = data.campaign.add(name, packs, panes);
+                    Campaigns::copy_stored_game_data(campaign_id, new_campaign_id);
+                    UpdateResult::Render | UpdateResult::SaveSettings
+                } else {
+                    UpdateResult::empty()
+                }
+            }
+            MsgSettings::CampaignAskDelete(campaign_id) => {
+                self.campaign_to_delete = Some(campaign_id);
+                UpdateResult::Render.into()
+            }
+            MsgSettings::CampaignDelete => {
+                if let Some(campaign_id) = self.campaign_to_delete.take() {
+                    if data.campaign.remove(campaign_id) {
+                        return UpdateResult::Render | UpdateResult::SaveSettings;
+                    }
+                }
+                UpdateResult::empty()
+            }
         }
     }
 
@@ -212,18 +360,86 @@ impl SubComponent for PaneSettings {
             data.msg.str_sett_lang_msg(),
             MsgSettings::MsgLanguage,
         );
+        let campaigns = data.campaign.iter().map(|campaign| {
+            let campaign_id = campaign.id;
+            let is_active = campaign_id == data.campaign.active_id();
+            let packs = PackId::all().map(|pack| {
+                let id = format!("campaign-pack-{}-{}", campaign_id.number(), pack.pack().key);
+                let enabled = campaign.packs.contains(&pack);
+                let onchange = ctx.link().callback(move |event: Event| {
+                    let enabled = event.target_unchecked_into::<HtmlInputElement>().checked();
+                    MsgSettings::CampaignPack(campaign_id, pack, enabled)
+                });
+                html! {
+                    <div class="form-check form-check-inline">
+                        <input
+                            class="form-check-input"
+                            type="checkbox"
+                            id={id.clone()}
+                            checked={enabled}
+                            disabled={enabled && campaign.packs.len() == 1}
+                            {onchange}
+                        />
+                        <label class="form-check-label" for={id}>{pack.pack().name}</label>
+                    </div>
+                }
+            });
+            html! {
+                <tr>
+                    <td>
+                        <input
+                            type="text"
+                            class="form-control"
+                            value={campaign.name.clone()}
+                            placeholder={data.msg.str_sett_camp_default_name(&campaign_id.number().to_string())}
+                            onchange={callback_input_value(ctx, move |name| MsgSettings::CampaignRename(campaign_id, name))}
+                        />
+                        <small class="me-2">{data.msg.sett_camp_packs()}</small>
+                        {for packs}
+                    </td>
+                    <td class="text-end">
+                        if is_active {
+                            <span class="badge text-bg-success">{data.msg.sett_camp_active()}</span>
+                        } else {
+                            <Button
+                                text={data.msg.str_sett_camp_switch()}
+                                onclick={ctx.link().callback(move |_|MsgApp::SwitchCampaign(campaign_id))}
+                            />
+                        }
+                        {" "}
+                        <Button
+                            style={Color::Secondary}
+                            text={data.msg.str_sett_camp_duplicate()}
+                            onclick={ctx.link().callback(move |_|MsgSettings::CampaignDuplicate(campaign_id))}
+                        />
+                        {" "}
+                        <Button
+                            style={Color::Danger}
+                            text={data.msg.str_sett_camp_delete()}
+                            disabled={is_active}
+                            modal_target="DeleteCampaignModal"
+                            onclick={ctx.link().callback(move |_|MsgSettings::CampaignAskDelete(campaign_id))}
+                        />
+                    </td>
+                </tr>
+            }
+        });
+        let campaign_to_delete = self
+            .campaign_to_delete
+            .and_then(|campaign_id| data.campaign.get(campaign_id))
+            .map(|campaign| campaign.display_name(data.msg).into_owned())
+            .unwrap_or_default();
         html! {
             <>
             if let Some(alert) = &self.alert {
-                <Alert style={alert.0.clone()}>
-                    {text(alert.1)}
-                    if let Some(extra) = &alert.2 {
-                        <br/>
-                        {extra}
-                    }
+                <Alert style={if matches!(alert, LoadAlert::Failed(_)) { Color::Danger } else { Color::Info }}>
+                    {Self::view_alert(alert, data)}
                     <button type="button" class="btn-close" data-bs-dismiss="alert" aria-label={data.msg.str_close()} onclick={ctx.link().callback(|_|MsgSettings::CloseAlert)}></button>
                 </Alert>
             }
+            if let Some(preview) = &self.preview {
+                {Self::view_preview(preview, data, ctx)}
+            }
             <ul class="list-group">
                 <li class="list-group-item">
                     <ButtonGroup>
@@ -250,6 +466,33 @@ impl SubComponent for PaneSettings {
                         <label class="btn btn-outline-primary" for="dark_mode1">{data.msg.sett_dark_mode()}</label>
                     </ButtonGroup>
                 </li>
+                <li class="list-group-item">
+                    <ButtonGroup>
+                        <input
+                            type="radio"
+                            class="btn-check"
+                            name="spoiler_safe"
+                            id="spoiler_safe0"
+                            autocomplete="off"
+                            checked={!data.spoiler_safe}
+                            onchange={ctx.link().callback(|_|MsgSettings::SpoilerSafe(false))}
+                        />
+                        <label class="btn btn-outline-primary" for="spoiler_safe0">{data.msg.sett_spoiler_off()}</label>
+
+                        <input
+                            type="radio"
+                            class="btn-check"
+                            name="spoiler_safe"
+                            id="spoiler_safe1"
+                            autocomplete="off"
+                            checked={data.spoiler_safe}
+                            onchange={ctx.link().callback(|_|MsgSettings::SpoilerSafe(true))}
+                        />
+                        <label class="btn btn-outline-primary" for="spoiler_safe1">{data.msg.sett_spoiler_on()}</label>
+                    </ButtonGroup>
+                    <br/>
+                    <small>{data.msg.sett_spoiler_help()}</small>
+                </li>
                 {ql}
                 {ml}
                 <li class="list-group-item">
@@ -264,6 +507,11 @@ impl SubComponent for PaneSettings {
                         onclick={ctx.link().callback(|_|MsgSettings::Save)}
                     />
                     {" "}
+                    <Button
+                        text={data.msg.str_sett_data_save_json()}
+                        onclick={ctx.link().callback(|_|MsgSettings::SaveJson)}
+                    />
+                    {" "}
                     <Button
                         style={Color::Warning}
                         text={data.msg.str_sett_data_new_campaign()}
@@ -276,6 +524,18 @@ impl SubComponent for PaneSettings {
                         modal_target="ClearModal"
                     /><br/>
                 </li>
+                <li class="list-group-item">
+                    {data.msg.sett_camp()}
+                    <table class="table align-middle">
+                        <tbody>
+                            {for campaigns}
+                        </tbody>
+                    </table>
+                    <Button
+                        text={data.msg.str_sett_camp_add()}
+                        onclick={ctx.link().callback(|_|MsgSettings::CampaignAdd)}
+                    />
+                </li>
             </ul>
             <input
                 ref={&self.load_element}
@@ -308,6 +568,16 @@ impl SubComponent for PaneSettings {
                     <Button style={Color::Danger} modal_dismiss={true} onclick={ctx.link().callback(|_|MsgSettings::Clear)}>{data.msg.sett_data_clear()}</Button>
                 </ModalFooter>
             </Modal>
+            <Modal id="DeleteCampaignModal">
+                <ModalHeader title={data.msg.str_sett_model_delete_camp_head()} />
+                <ModalBody>
+                    <p>{data.msg.sett_model_delete_camp_body(&campaign_to_delete)}</p>
+                </ModalBody>
+                <ModalFooter>
+                    <Button style={Color::Secondary} modal_dismiss={true}>{data.msg.close()}</Button>
+                    <Button style={Color::Danger} modal_dismiss={true} onclick={ctx.link().callback(|_|MsgSettings::CampaignDelete)}>{data.msg.sett_camp_delete()}</Button>
+                </ModalFooter>
+            </Modal>
             </>
         }
     }
@@ -325,6 +595,243 @@ impl SubComponent for PaneSettings {
 }
 
 impl PaneSettings {
+    fn view_preview(preview: &Preview, data: &Data, ctx: &Context<App>) -> Html {
+        // compared on every render, thus it's still correct after an undo
+        let (result, conflicts) = preview.result(data);
+        let differences = data.journal.diff(&result);
+        let language = data.quest_locale.language();
+        let items = differences.into_iter().map(|difference| {
+            let item = match difference {
+                Difference::QuestState(quest_id, before, after) => data.msg.sett_preview_state(
+                    data.quest_locale.get(quest_id),
+                    before.text(data),
+                    after.text(data),
+                ),
+                Difference::EncounterAdded(quest_id, location_id, encounter_type) => {
+                    data.msg.sett_preview_added(
+                        data.quest_locale.get(quest_id),
+                        location_id.name(language),
+                        encounter_type.icon_active(),
+                    )
+                }
+                Difference::EncounterRemoved(quest_id, location_id, encounter_type) => {
+                    data.msg.sett_preview_removed(
+                        data.quest_locale.get(quest_id),
+                        location_id.name(language),
+                        encounter_type.icon_active(),
+                    )
+                }
+                Difference::Note(NoteId::Quest(quest_id)) => data
+                    .msg
+                    .sett_preview_quest_note(data.quest_locale.get(quest_id)),
+                Difference::Note(NoteId::Location(location_id)) => data
+                    .msg
+                    .sett_preview_location_note(location_id.name(language)),
+                Difference::Crew => data.msg.sett_preview_crew(),
+                Difference::Ledger => data.msg.sett_preview_ledger(),
+                Difference::Totems => data.msg.sett_preview_totems(),
+                Difference::Items => data.msg.sett_preview_items(),
+                Difference::ScoreRates => data.msg.sett_preview_score_rates(),
+                Difference::Sessions => data.msg.sett_preview_sessions(),
+            };
+            html! {<li class="list-group-item">{item}</li>}
+        });
+        let conflicts = conflicts.into_iter().enumerate().map(|(index, conflict)| {
+            let conflict_id = conflict.id();
+            let chosen = preview
+                .choices
+                .get(&conflict_id)
+                .copied()
+                .unwrap_or_else(|| conflict.default_choice());
+            let choices = conflict.choices().iter().enumerate().map(|(pos, choice)| {
+                let choice = *choice;
+                let id = format!("conflict{index}_{pos}");
+                let label = match choice {
+                    Choice::Own => data.msg.sett_preview_own(),
+                    Choice::Other => data.msg.sett_preview_other(),
+                    Choice::Both => data.msg.sett_preview_both(),
+                };
+                let conflict_id = conflict_id.clone();
+                html! {
+                    <>
+                    <input
+                        type="radio"
+                        class="btn-check"
+                        name={format!("conflict{index}")}
+                        id={id.clone()}
+                        autocomplete="off"
+                        checked={chosen == choice}
+                        onchange={ctx.link().callback(move |_|MsgSettings::LoadConflict(conflict_id.clone(), choice))}
+                    />
+                    <label class="btn btn-sm btn-outline-primary" for={id}>{label}</label>
+                    </>
+                }
+            });
+            let (name, own, other) = conflict_values(data, &conflict);
+            html! {
+                <li class="list-group-item">
+                    <strong>{name}</strong>
+                    <div class="row my-2">
+                        <div class="col-sm-6" style="white-space: pre-wrap">
+                            <small class="text-body-secondary">{data.msg.sett_preview_own()}</small><br/>
+                            {own}
+                        </div>
+                        <div class="col-sm-6" style="white-space: pre-wrap">
+                            <small class="text-body-secondary">{data.msg.sett_preview_other()}</small><br/>
+                            {other}
+                        </div>
+                    </div>
+                    <ButtonGroup>
+                        {for choices}
+                    </ButtonGroup>
+                </li>
+            }
+        });
+        html! {
+            <div class="card mb-3">
+                <div class="card-header">
+                    <ButtonGroup>
+                        <input
+                            type="radio"
+                            class="btn-check"
+                            name="load_mode"
+                            id="load_mode0"
+                            autocomplete="off"
+                            checked={!preview.merge}
+                            onchange={ctx.link().callback(|_|MsgSettings::LoadMerge(false))}
+                        />
+                        <label class="btn btn-sm btn-outline-primary" for="load_mode0">{data.msg.sett_preview_replace()}</label>
+
+                        <input
+                            type="radio"
+                            class="btn-check"
+                            name="load_mode"
+                            id="load_mode1"
+                            autocomplete="off"
+                            checked={preview.merge}
+                            onchange={ctx.link().callback(|_|MsgSettings::LoadMerge(true))}
+                        />
+                        <label class="btn btn-sm btn-outline-primary" for="load_mode1">{data.msg.sett_preview_merge()}</label>
+                    </ButtonGroup>
+                </div>
+                <div class="card-body">
+                    if items.len() == 0 {
+                        {data.msg.sett_preview_none()}
+                    } else {
+                        if preview.merge {
+                            {data.msg.sett_preview_merge_head()}
+                        } else {
+                            {data.msg.sett_preview_head()}
+                        }
+                        <ul class="list-group list-group-flush mt-2 overflow-auto" style="max-height: 50vh">
+                            {for items}
+                        </ul>
+                    }
+                    if !preview.merge && !data.journal.session.is_empty() {
+                        <p class="mt-3 mb-0">{data.msg.sett_preview_sessions_kept()}</p>
+                    }
+                    if conflicts.len() > 0 {
+                        <p class="mt-3 mb-2">{data.msg.sett_preview_conflicts()}</p>
+                        <ul class="list-group">
+                            {for conflicts}
+                        </ul>
+                    }
+                </div>
+                <div class="card-footer">
+                    <Button
+                        style={Color::Warning}
+                        text={if preview.merge { data.msg.str_sett_preview_apply_merge() } else { data.msg.str_sett_preview_apply() }}
+                        onclick={ctx.link().callback(|_|MsgSettings::LoadApply)}
+                    />
+                    {" "}
+                    <Button
+                        style={Color::Secondary}
+                        text={data.msg.str_sett_preview_cancel()}
+                        onclick={ctx.link().callback(|_|MsgSettings::LoadCancel)}
+                    />
+                </div>
+            </div>
+        }
+    }
+
+    fn view_alert(alert: &LoadAlert, data: &Data) -> Html {
+        let rejected = match alert {
+            LoadAlert::Failed(err) => {
+                return match err {
+                    MyError::CsvError => data.msg.sett_load_error_csv(),
+                    MyError::Header => data.msg.sett_load_error_header(),
+                    MyError::Language => data.msg.sett_load_error_language(),
+                };
+            }
+            LoadAlert::Rejected(rejected) => rejected,
+        };
+        let rows = rejected.iter().map(|rejected| {
+            let reason = match rejected.reason {
+                Reason::Type => data.msg.sett_load_reason_type(),
+                Reason::Location => data.msg.sett_load_reason_location(),
+                Reason::Quest => data.msg.sett_load_reason_quest(),
+                Reason::Status => data.msg.sett_load_reason_status(),
+                Reason::Prerequisite => data.msg.sett_load_reason_prerequisite(),
+                Reason::PrerequisiteQuest => data.msg.sett_load_reason_prerequisite_quest(),
+                Reason::Visibility => data.msg.sett_load_reason_visibility(),
+                Reason::Time => data.msg.sett_load_reason_time(),
+                Reason::Session => data.msg.sett_load_reason_session(),
+                Reason::Health => data.msg.sett_load_reason_health(),
+                Reason::MaxHealth => data.msg.sett_load_reason_max_health(),
+                Reason::Fatigue => data.msg.sett_load_reason_fatigue(),
+                Reason::Level => data.msg.sett_load_reason_level(),
+                Reason::Amount => data.msg.sett_load_reason_amount(),
+                Reason::Card => data.msg.sett_load_reason_card(),
+                Reason::MissingLocation => data.msg.sett_load_reason_missing_location(),
+                Reason::MissingQuest => data.msg.sett_load_reason_missing_quest(),
+            };
+            html! {
+                <tr>
+                    <td>{rejected.row}</td>
+                    <td>{text(rejected.reason.column())}</td>
+                    <td>{reason}</td>
+                    <td>{&rejected.value}</td>
+                    <td>
+                        if let Some(suggestion) = &rejected.suggestion {
+                            {data.msg.sett_load_suggestion(suggestion)}
+                        }
+                    </td>
+                </tr>
+            }
+        });
+        html! {
+            <>
+            {data.msg.sett_load_rejected()}
+            <table class="table table-sm align-middle mt-2 mb-0" style="--bs-table-bg: transparent">
+                <thead>
+                    <tr>
+                        <th>{data.msg.sett_load_row()}</th>
+                        <th>{data.msg.sett_load_column()}</th>
+                        <th>{data.msg.sett_load_problem()}</th>
+                        <th>{data.msg.sett_load_content()}</th>
+                        <th></th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {for rows}
+                </tbody>
+            </table>
+            </>
+        }
+    }
+
+    fn download<T: AsRef<[u8]>>(&self, mime: &str, extension: &str, content: T) {
+        download(
+            &self.save_element,
+            mime,
+            &format!(
+                "sgh_{}.{extension}",
+                chrono::offset::Local::now().format("%Y-%m-%d_%H:%M")
+            ),
+            content,
+        );
+    }
+
     fn language_settings<T, I, M>(
         data: &Data,
         ctx: &Context<App>,
@@ -383,3 +890,121 @@ impl PaneSettings {
         );
     }
 }
+
+// the name of a merge conflict, the value of the campaign and the value of the file
+fn conflict_values(data: &Data, conflict: &Conflict) -> (Html, String, String) {
+    let language = data.quest_locale.language();
+    match conflict {
+        Conflict::Note(note_id, other) => {
+            let name = match note_id {
+                NoteId::Quest(quest_id) => text(data.quest_locale.get(*quest_id)),
+                NoteId::Location(location_id) => data
+                    .msg
+                    .sett_preview_conflict_location(location_id.name(language)),
+            };
+            (
+                name,
+                data.journal.note(*note_id).to_string(),
+                other.to_string(),
+            )
+        }
+        Conflict::QuestState(quest_id, other) => {
+            let own = data
+                .journal
+                .quest
+                .get(quest_id)
+                .map_or(QuestState::NotFound, |quest| quest.state);
+            (
+                data.msg
+                    .sett_preview_conflict_state(data.quest_locale.get(*quest_id)),
+                own.text(data).to_string(),
+                other.text(data).to_string(),
+            )
+        }
+        Conflict::Prerequisite(quest_id, location_id, encounter_type, other) => {
+            let own = data
+                .journal
+                .quest
+                .get(quest_id)
+                .and_then(|quest| quest.encounter.get(location_id))
+                .and_then(|quest_location| quest_location.get(encounter_type))
+                .and_then(|encounter| encounter.prerequisite.as_ref())
+                .map(|prerequisite| prerequisite.text(data))
+                .unwrap_or_default();
+            (
+                data.msg.sett_preview_conflict_prerequisite(
+                    data.quest_locale.get(*quest_id),
+                    location_id.name(language),
+                    encounter_type.icon_active(),
+                ),
+                own,
+                other.text(data),
+            )
+        }
+        Conflict::Totem(other) => {
+            let value = |totem: &Totem| {
+                let location = totem
+                    .location_id
+                    .map_or(data.msg.str_ledger_no_location(), |location_id| {
+                        location_id.name(language)
+                    });
+                format!(
+                    "{}: {}\n{}: {location}\n{}: {}",
+                    data.msg.str_totem_points(),
+                    totem.points,
+                    data.msg.str_totem_location(),
+                    data.msg.str_totem_session(),
+                    totem.session
+                )
+            };
+            let own = data
+                .journal
+                .totem
+                .iter()
+                .find(|totem| totem.name == other.name)
+                .map(value)
+                .unwrap_or_default();
+            (
+                data.msg.sett_preview_conflict_totem(&other.name),
+                own,
+                value(other),
+            )
+        }
+        Conflict::Item(other) => {
+            let value = |item: &Item| {
+                let holder = if item.holder.is_empty() {
+                    data.msg.str_inventory_ship()
+                } else {
+                    &item.holder
+                };
+                let origin = match (item.location_id, item.quest_id) {
+                    (Some(location_id), _) => location_id.name(language),
+                    (None, Some(quest_id)) => data.quest_locale.get(quest_id),
+                    (None, None) => "",
+                };
+                format!(
+                    "{}: {holder}\n{}: {origin}",
+                    data.msg.str_inventory_holder(),
+                    data.msg.str_inventory_origin()
+                )
+            };
+            let own = data
+                .journal
+                .item
+                .iter()
+                .find(|item| item.is_same(other))
+                .map(value)
+                .unwrap_or_default();
+            let number = other
+                .number
+                .map(|number| number.to_string())
+                .unwrap_or_default();
+            (
+                data.msg
+                    .sett_preview_conflict_item(other.kind.text(data), &number, &other.name),
+                own,
+                value(other),
+            )
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use crate::data::event::{Event, EventExt};
+use crate::data::sub_component::{SubComponent, UpdateResult, UpdateResults};
+use crate::game::{LocationId, QuestId};
+use crate::global::app::{App, MsgApp};
+use crate::global::data::Data;
+use crate::html::text;
+use crate::route::Route;
+use crate::ser::settings::EmptySer;
+use std::collections::BTreeSet;
+use std::str::FromStr;
+use web_sys::HtmlSelectElement;
+use yew::{AttrValue, Context, Html, TargetCast, html};
+use yew_bootstrap::component::form::{FormControl, FormControlType, SelectOption};
+use yew_bootstrap::component::{Button, ButtonSize};
+use yew_bootstrap::util::Color;
+
+#[derive(Clone)]
+pub(crate) enum MsgTimeline {
+    FilterQuest(String),
+    FilterLocation(String),
+}
+
+impl From<MsgTimeline> for MsgApp {
+    #[inline]
+    fn from(msg: MsgTimeline) -> Self {
+        MsgApp::MsgTimeline(msg)
+    }
+}
+
+pub(crate) struct PaneTimeline {
+    quest_id: Option<QuestId>,
+    location_id: Option<LocationId>,
+}
+
+impl SubComponent for PaneTimeline {
+    type Message = MsgTimeline;
+    type Ser = EmptySer;
+
+    fn create(_ctx: &Context<App>) -> Self {
+        Self {
+            quest_id: None,
+            location_id: None,
+        }
+    }
+
+    fn reset_to_new(&mut self) {
+        self.quest_id = None;
+        self.location_id = None;
+    }
+
+    fn update(
+        &mut self,
+        _data: &mut Data,
+        _ctx: &Context<App>,
+        msg: Self::Message,
+    ) -> UpdateResults {
+        match msg {
+            MsgTimeline::FilterQuest(quest_id) => {
+                self.quest_id = usize::from_str(&quest_id).ok().and_then(QuestId::from_raw);
+            }
+            MsgTimeline::FilterLocation(location_id) => {
+                self.location_id = usize::from_str(&location_id)
+                    .ok()
+                    .and_then(LocationId::from_raw);
+            }
+        }
+        UpdateResult::Render.into()
+    }
+
+    fn view(&self, data: &Data, ctx: &Context<App>) -> Html {
+        let events = data
+            .journal
+            .event
+            .iter()
+            .filter(|event| self.quest_id.is_none() || event.quest_id == self.quest_id)
+            .filter(|event| self.location_id.is_none() || event.location_id == self.location_id)
+            .collect::<Vec<_>>();
+
+        let sessions = events
+            .chunk_by(|a, b| a.session == b.session)
+            .map(|events| {
+                let session = events.first().map_or(0, |event| event.session);
+                let date = events
+                    .first()
+                    .and_then(|event| event.local_time())
+                    .map(|time| html! {<small class="ms-2 text-body-secondary">{time.format("%Y-%m-%d").to_string()}</small>});
+                let rows = events.iter().map(|event| Self::view_event(data, ctx, event));
+                html! {
+                    <>
+                    <h2 class="h5 mt-4">{data.msg.time_session_date(&session.to_string(), date.unwrap_or_default())}</h2>
+                    <table class="table table-hover align-middle">
+                        <tbody>
+                            {for rows}
+                        </tbody>
+                    </table>
+                    </>
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut quests = data
+            .journal
+            .event
+            .iter()
+            .filter_map(|event| event.quest_id)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(|quest_id| (quest_id.raw(), data.quest_locale.get(quest_id)))
+            .collect::<Vec<_>>();
+        quests.sort_by_key(|(_, name)| *name);
+        let locations = data
+            .journal
+            .event
+            .iter()
+            .filter_map(|event| event.location_id)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(|location_id| {
+                (
+                    location_id.raw(),
+                    location_id.name(data.quest_locale.language()),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        html! {
+            <>
+            <div class="row">
+                <div class="col-md-6">
+                    {Self::view_filter(
+                        data,
+                        ctx,
+                        "input-select-timeline-quest",
+                        data.msg.str_time_filter_quest(),
+                        self.quest_id.map(QuestId::raw),
+                        quests,
+                        MsgTimeline::FilterQuest,
+                    )}
+                </div>
+                <div class="col-md-6">
+                    {Self::view_filter(
+                        data,
+                        ctx,
+                        "input-select-timeline-location",
+                        data.msg.str_time_filter_location(),
+                        self.location_id.map(LocationId::raw),
+                        locations,
+                        MsgTimeline::FilterLocation,
+                    )}
+                </div>
+            </div>
+            if sessions.is_empty() {
+                <p class="mt-4">{data.msg.time_empty()}</p>
+            } else {
+                {for sessions.into_iter().rev()}
+            }
+            </>
+        }
+    }
+
+    fn save(&self) -> Self::Ser {
+        EmptySer {}
+    }
+
+    fn load(&mut self, _stored: Self::Ser) {}
+}
+
+impl PaneTimeline {
+    fn view_event(data: &Data, ctx: &Context<App>, event: &Event) -> Html {
+        let time = event
+            .local_time()
+            .map(|time| time.format("%H:%M").to_string())
+            .unwrap_or_default();
+        html! {
+            <tr>
+                <td><small>{time}</small></td>
+                <td>{event.icon()}{" "}{event.text(data)}</td>
+                <td class="text-end">
+                    if let Some(quest_id) = event.quest_id.filter(|quest_id| data.journal.quest.contains_key(quest_id)) {
+                        <Button
+                            size={ButtonSize::Small}
+                            style={Color::Secondary}
+                            onclick={ctx.link().callback(move |_|MsgApp::Go(Route::EditQuest(quest_id)))}
+                        >
+                            {text(data.quest_locale.get(quest_id))}
+                        </Button>
+                    }
+                    if let Some(location_id) = event.location_id {
+                        {" "}
+                        <Button
+                            size={ButtonSize::Small}
+                            onclick={ctx.link().callback(move |_|MsgApp::Go(Route::MapLocation(location_id)))}
+                        >
+                            {text(location_id.name(data.quest_locale.language()))}
+                        </Button>
+                    }
+                </td>
+            </tr>
+        }
+    }
+
+    fn view_filter<M>(
+        data: &Data,
+        ctx: &Context<App>,
+        id: &'static str,
+        label: &'static str,
+        current: Option<usize>,
+        options: Vec<(usize, &'static str)>,
+        msg: M,
+    ) -> Html
+    where
+        M: Fn(String) -> MsgTimeline + 'static,
+    {
+        let options = options.into_iter().map(|(raw, name)| {
+            html! {
+                <SelectOption
+                    value={AttrValue::from(raw.to_string())}
+                    label={AttrValue::Static(name)}
+                    selected={current == Some(raw)}
+                />
+            }
+        });
+        let onchange = ctx.link().callback(move |event: web_sys::Event| {
+            msg(event.target_unchecked_into::<HtmlSelectElement>().value())
+        });
+        html! {
+            <FormControl
+                id={AttrValue::Static(id)}
+                ctype={FormControlType::Select}
+                label={AttrValue::Static(label)}
+                onchange={onchange}
+            >
+                <SelectOption
+                    value={AttrValue::Static("")}
+                    label={AttrValue::Static(data.msg.str_time_filter_all())}
+                    selected={current.is_none()}
+                />
+                {for options}
+            </FormControl>
+        }
+    }
+}
@@ -1,5 +1,5 @@
-use crate::data::encounter_type::EncounterType;
-use crate::data::quest::{Quest, QuestState, QuestViewMode};
+use crate::data::encounter_type::{EncounterType, EncounterTypeExt};
+use crate::data::quest::{Quest, QuestExt, QuestState, QuestViewMode};
 use crate::data::sub_component::{SubComponent, UpdateResult, UpdateResults};
 use crate::game::QuestId;
 use crate::global::app::{App, MsgApp};
@@ -34,6 +34,7 @@ pub(crate) enum Typ {
     GainWithComplete = 1,
     GainWoComplete = 2,
     Unless = 3,
+    Done = 4,
 }
 
 impl From<MsgTodo> for MsgApp {
@@ -49,7 +50,7 @@ pub(crate) struct PaneTodo {
     modal: Modal,
 }
 
-#[derive(Default, Serialize, Deserialize)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
 pub(crate) struct PaneTodoSer {
     show_keywords: SettingsKeywords,
@@ -89,7 +90,7 @@ impl SubComponent for PaneTodo {
                 UpdateResult::SaveSettings | UpdateResult::Render
             }
             MsgTodo::ShowNote(quest_id) => {
-                if let Some(quest) = data.quest.get(&quest_id) {
+                if let Some(quest) = data.journal.quest.get(&quest_id) {
                     self.modal.open(
                         &data
                             .msg
@@ -112,7 +113,7 @@ impl SubComponent for PaneTodo {
             if (qk as u8) & (self.show_keywords as u8) == 0 {
                 return None;
             }
-            if q.state == QuestState::Removed {
+            if q.state.is_done() && self.typ != Typ::Done {
                 // nothing to do
                 return None;
             }
@@ -132,6 +133,7 @@ impl SubComponent for PaneTodo {
                     q.state == QuestState::NotFound
                         && q.contains_visible_encounter_type(EncounterType::Unless)
                 }
+                Typ::Done => q.state.is_done(),
             } {
                 return None;
             }
@@ -226,6 +228,17 @@ impl SubComponent for PaneTodo {
                         onchange={ctx.link().callback(|_|MsgTodo::SettingsTyp(Typ::Unless))}
                     />
                     <label class="btn btn-outline-primary" for="typX3">{EncounterType::Unless.icon_active()}</label>
+
+                    <input
+                        type="radio"
+                        class="btn-check"
+                        name="typX"
+                        id="typX4"
+                        autocomplete="off"
+                        checked={self.typ == Typ::Done}
+                        onchange={ctx.link().callback(|_|MsgTodo::SettingsTyp(Typ::Done))}
+                    />
+                    <label class="btn btn-outline-primary" for="typX4">{data.msg.todo_typ_done()}</label>
                 </div>
             </div>
             <table class="table table-hover align-middle mt-4">
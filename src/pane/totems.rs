@@ -0,0 +1,245 @@
+use crate::data::sub_component::{SubComponent, UpdateResult, UpdateResults};
+use crate::game::LocationId;
+use crate::global::app::{App, MsgApp};
+use crate::global::data::Data;
+use crate::html::callback_input_value;
+use crate::ser::settings::EmptySer;
+use sleeping_journal_core::data::totem::Totem;
+use std::str::FromStr;
+use web_sys::HtmlSelectElement;
+use yew::{Context, Html, TargetCast, html};
+use yew_bootstrap::component::Button;
+use yew_bootstrap::icons::BI;
+use yew_bootstrap::util::Color;
+
+#[derive(Clone)]
+pub(crate) enum MsgTotems {
+    NewName(String),
+    NewPoints(String),
+    Add,
+    Name(usize, String),
+    Points(usize, String),
+    Location(usize, String),
+    Session(usize, String),
+    Remove(usize),
+}
+
+impl From<MsgTotems> for MsgApp {
+    #[inline]
+    fn from(msg: MsgTotems) -> Self {
+        MsgApp::MsgTotems(msg)
+    }
+}
+
+pub(crate) struct PaneTotems {
+    name: String,
+    // as printed on the card
+    points: String,
+}
+
+impl SubComponent for PaneTotems {
+    type Message = MsgTotems;
+    type Ser = EmptySer;
+
+    fn create(_ctx: &Context<App>) -> Self {
+        Self {
+            name: String::new(),
+            points: String::new(),
+        }
+    }
+
+    fn reset_to_new(&mut self) {
+        self.name.clear();
+        self.points.clear();
+    }
+
+    fn update(
+        &mut self,
+        data: &mut Data,
+        _ctx: &Context<App>,
+        msg: Self::Message,
+    ) -> UpdateResults {
+        match msg {
+            MsgTotems::NewName(name) => {
+                self.name = name;
+                UpdateResult::Render.into()
+            }
+            MsgTotems::NewPoints(points) => {
+                self.points = points;
+                UpdateResult::Render.into()
+            }
+            MsgTotems::Add => {
+                let name = self.name.trim().to_string();
+                if name.is_empty() {
+                    return UpdateResult::empty();
+                }
+                data.change = Some(data.msg.str_change_totem_add(&name));
+                data.journal
+                    .acquire_totem(name, u8::from_str(&self.points).unwrap_or_default());
+                self.name.clear();
+                self.points.clear();
+                UpdateResult::Render | UpdateResult::SaveGameData
+            }
+            MsgTotems::Name(pos, name) => change(data, pos, |totem| totem.name = name),
+            MsgTotems::Points(pos, points) => match u8::from_str(&points) {
+                Ok(points) => change(data, pos, |totem| totem.points = points),
+                Err(_) => UpdateResult::Render.into(),
+            },
+            MsgTotems::Location(pos, location_id) => change(data, pos, |totem| {
+                totem.location_id = usize::from_str(&location_id)
+                    .ok()
+                    .and_then(LocationId::from_raw);
+            }),
+            MsgTotems::Session(pos, session) => match u32::from_str(&session) {
+                Ok(session) if session > 0 => change(data, pos, |totem| totem.session = session),
+                _ => UpdateResult::Render.into(),
+            },
+            MsgTotems::Remove(pos) => {
+                if pos < data.journal.totem.len() {
+                    let totem = data.journal.totem.remove(pos);
+                    data.change = Some(data.msg.str_change_totem_remove(&totem.name));
+                    UpdateResult::Render | UpdateResult::SaveGameData
+                } else {
+                    UpdateResult::empty()
+                }
+            }
+        }
+    }
+
+    fn view(&self, data: &Data, ctx: &Context<App>) -> Html {
+        let language = data.quest_locale.language();
+        let totems = data.journal.totem.iter().enumerate().map(|(pos, totem)| {
+            let locations = data.location_iter().map(|location_id| {
+                html! {
+                    <option
+                        value={location_id.raw().to_string()}
+                        selected={totem.location_id == Some(location_id)}
+                    >
+                        {location_id.name(language)}
+                    </option>
+                }
+            });
+            let on_location = ctx.link().callback(move |event: web_sys::Event| {
+                MsgTotems::Location(pos, event.target_unchecked_into::<HtmlSelectElement>().value())
+            });
+            html! {
+                <tr>
+                    <td>{pos + 1}</td>
+                    <td>
+                        <input
+                            type="text"
+                            class="form-control"
+                            value={totem.name.clone()}
+                            onchange={callback_input_value(ctx, move |name| MsgTotems::Name(pos, name))}
+                        />
+                    </td>
+                    <td>
+                        <input
+                            type="number"
+                            class="form-control"
+                            min="0"
+                            value={totem.points.to_string()}
+                            onchange={callback_input_value(ctx, move |points| MsgTotems::Points(pos, points))}
+                        />
+                    </td>
+                    <td>
+                        <select class="form-select" onchange={on_location}>
+                            <option value="" selected={totem.location_id.is_none()}>
+                                {data.msg.ledger_no_location()}
+                            </option>
+                            {for locations}
+                        </select>
+                    </td>
+                    <td>
+                        <input
+                            type="number"
+                            class="form-control"
+                            min="1"
+                            value={totem.session.to_string()}
+                            onchange={callback_input_value(ctx, move |session| MsgTotems::Session(pos, session))}
+                        />
+                    </td>
+                    <td class="text-end">
+                        <Button
+                            style={Color::Danger}
+                            outline={true}
+                            onclick={ctx.link().callback(move |_|MsgTotems::Remove(pos))}
+                        >
+                            {BI::TRASH}
+                        </Button>
+                    </td>
+                </tr>
+            }
+        });
+        html! {
+            <>
+            <h2 class="h4 mb-4">{data.msg.totem_header(&data.journal.totem.len().to_string())}</h2>
+
+            <div class="input-group mb-4">
+                <input
+                    type="text"
+                    class="form-control"
+                    placeholder={data.msg.str_totem_name_placeholder()}
+                    value={self.name.clone()}
+                    onchange={callback_input_value(ctx, MsgTotems::NewName)}
+                />
+                <input
+                    type="number"
+                    class="form-control"
+                    style="max-width: 8rem"
+                    min="0"
+                    placeholder={data.msg.str_totem_points()}
+                    value={self.points.clone()}
+                    onchange={callback_input_value(ctx, MsgTotems::NewPoints)}
+                />
+                <Button
+                    style={Color::Primary}
+                    onclick={ctx.link().callback(|_|MsgTotems::Add)}
+                >
+                    {BI::PLUS}{data.msg.totem_add()}
+                </Button>
+            </div>
+
+            if data.journal.totem.is_empty() {
+                <p>{data.msg.totem_empty()}</p>
+            } else {
+                <div class="table-responsive">
+                    <table class="table align-middle">
+                        <thead>
+                            <tr>
+                                <th></th>
+                                <th>{data.msg.totem_name()}</th>
+                                <th>{data.msg.totem_points()}</th>
+                                <th>{data.msg.totem_location()}</th>
+                                <th>{data.msg.totem_session()}</th>
+                                <th></th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            {for totems}
+                        </tbody>
+                    </table>
+                </div>
+            }
+            </>
+        }
+    }
+
+    fn save(&self) -> Self::Ser {
+        EmptySer {}
+    }
+
+    fn load(&mut self, _stored: Self::Ser) {}
+}
+
+fn change<F>(data: &mut Data, pos: usize, f: F) -> UpdateResults
+where
+    F: FnOnce(&mut Totem),
+{
+    let Some(totem) = data.journal.totem.get_mut(pos) else {
+        return UpdateResult::empty();
+    };
+    f(totem);
+    data.change = Some(data.msg.str_change_totem(&totem.name));
+    UpdateResult::Render | UpdateResult::SaveGameData
+}
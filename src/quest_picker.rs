@@ -0,0 +1,166 @@
+use crate::game::{GameLanguage, QuestId};
+use crate::global::app::{App, MsgApp};
+use crate::global::data::Data;
+use crate::html::text;
+use web_sys::HtmlInputElement;
+use yew::{AttrValue, Context, Html, InputEvent, TargetCast, html};
+
+// how well a name matches the query, lower is better
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+enum Score {
+    Exact,
+    Prefix(usize),
+    Contains(usize),
+    // all characters of the query in order, with that many characters skipped
+    Fuzzy(usize),
+}
+
+impl Score {
+    // both have to be lowercase
+    fn of(query: &str, name: &str) -> Option<Self> {
+        if name == query {
+            Some(Score::Exact)
+        } else if name.starts_with(query) {
+            Some(Score::Prefix(name.len() - query.len()))
+        } else if let Some(pos) = name.find(query) {
+            Some(Score::Contains(pos))
+        } else {
+            let mut name = name.chars();
+            let mut skipped = 0;
+            for q in query.chars() {
+                loop {
+                    let c = name.next()?;
+                    if c == q {
+                        break;
+                    }
+                    skipped += 1;
+                }
+            }
+            Some(Score::Fuzzy(skipped))
+        }
+    }
+}
+
+pub(crate) struct QuestMatch {
+    pub(crate) quest_id: QuestId,
+    // the matched name when it's not from the current game language
+    pub(crate) other: Option<&'static str>,
+}
+
+// search-as-you-type for quests, the names of all game languages are matched
+#[derive(Default)]
+pub(crate) struct QuestPicker {
+    query: String,
+}
+
+impl QuestPicker {
+    #[inline]
+    pub(crate) fn set_query(&mut self, query: String) {
+        self.query = query;
+    }
+
+    #[inline]
+    pub(crate) fn clear(&mut self) {
+        self.query.clear();
+    }
+
+    // with the spoiler protection a hidden quest is only found by its exact name
+    pub(crate) fn matches<F>(&self, data: &Data, filter: F) -> Vec<QuestMatch>
+    where
+        F: Fn(QuestId) -> bool,
+    {
+        let query = self.query.trim().to_lowercase();
+        let language = data.quest_locale.language();
+        let mut result = data
+            .quest_locale
+            .all_str()
+            .filter(|(quest_id, _)| filter(*quest_id))
+            .filter_map(|(quest_id, name)| {
+                let (score, other) = if query.is_empty() {
+                    (Score::Exact, None)
+                } else {
+                    GameLanguage::iter()
+                        .filter_map(|l| {
+                            let other = quest_id.name(l);
+                            Score::of(&query, &other.to_lowercase())
+                                .map(|score| (score, (l != language).then_some(other)))
+                        })
+                        .min_by_key(|(score, other)| (*score, other.is_some()))?
+                };
+                if data.is_revealed(quest_id) || (!query.is_empty() && score == Score::Exact) {
+                    Some((score, name, QuestMatch { quest_id, other }))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        result.sort_by_key(|(score, name, _)| (*score, *name));
+        result.into_iter().map(|(_, _, m)| m).collect()
+    }
+
+    pub(crate) fn view_input<F, M>(&self, data: &Data, ctx: &Context<App>, id: &str, f: F) -> Html
+    where
+        F: Fn(String) -> M + 'static,
+        M: Into<MsgApp>,
+    {
+        html! {
+            <input
+                type="search"
+                class="form-control"
+                id={AttrValue::from(id.to_string())}
+                value={self.query.clone()}
+                placeholder={data.msg.str_picker_placeholder()}
+                autocomplete="off"
+                oninput={ctx.link().callback(move |event: InputEvent| {
+                    f(event.target_unchecked_into::<HtmlInputElement>().value())
+                })}
+            />
+        }
+    }
+
+    // the input and a list of all matching quests
+    pub(crate) fn view<P, FQ, FS, M>(
+        &self,
+        data: &Data,
+        ctx: &Context<App>,
+        id: &str,
+        filter: P,
+        on_query: FQ,
+        on_select: FS,
+    ) -> Html
+    where
+        P: Fn(QuestId) -> bool,
+        FQ: Fn(String) -> M + 'static,
+        FS: Fn(QuestId) -> M + Clone + 'static,
+        M: Into<MsgApp>,
+    {
+        let matches = self.matches(data, filter);
+        let empty = matches.is_empty();
+        let matches = matches.into_iter().map(|QuestMatch { quest_id, other }| {
+            let on_select = on_select.clone();
+            html! {
+                <button
+                    type="button"
+                    class="list-group-item list-group-item-action"
+                    onclick={ctx.link().callback(move |_| on_select(quest_id))}
+                >
+                    {text(data.quest_locale.get(quest_id))}
+                    if let Some(other) = other {
+                        <small class="ms-2 text-body-secondary">{text(other)}</small>
+                    }
+                </button>
+            }
+        });
+        html! {
+            <>
+            {self.view_input(data, ctx, id, on_query)}
+            <div class="list-group mt-1">
+                {for matches}
+            </div>
+            if empty {
+                <div class="text-body-secondary">{data.msg.picker_none()}</div>
+            }
+            </>
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::game::{LocationId, QuestId};
+use crate::game::{GameLanguage, LocationId, PackId, QuestId};
 use crate::global::app::{App, MsgApp};
 use crate::global::data::Data;
 use gloo_history::{BrowserHistory, History, HistoryListener};
@@ -18,35 +18,45 @@ pub(crate) enum Route {
     MapNewQuest(LocationId),
     Edit,
     EditQuest(QuestId),
+    Timeline,
+    Graph,
+    Search(String),
+    Crew,
+    Ledger,
+    Totems,
+    Inventory,
+    Score,
+    Sessions,
     Settings,
 }
 
 impl Route {
-    fn path(&self, data: &Data, base: &str) -> String {
+    // quests and locations are encoded by their id, thus links work with every game language
+    fn path(&self, base: &str) -> String {
         match self {
             Route::Info => format!("{base}/#info"),
             Route::Todo => format!("{base}/#todo"),
-            Route::TodoAction(q, l) => format!(
-                "{base}/#todo/{}/{}",
-                encode(data.quest_locale.get(*q)),
-                encode(l.name(data.quest_locale.language())),
-            ),
+            Route::TodoAction(q, l) => {
+                format!("{base}/#todo/{}/{}", quest_path(*q), location_path(*l))
+            }
             Route::Map => format!("{base}/#map"),
-            Route::MapLocation(l) => format!(
-                "{base}/#map/{}",
-                encode(l.name(data.quest_locale.language()))
-            ),
-            Route::MapAction(l, q) => format!(
-                "{base}/#map/{}/{}",
-                encode(l.name(data.quest_locale.language())),
-                encode(data.quest_locale.get(*q)),
-            ),
-            Route::MapNewQuest(l) => format!(
-                "{base}/#map/{}/new",
-                encode(l.name(data.quest_locale.language()))
-            ),
+            Route::MapLocation(l) => format!("{base}/#map/{}", location_path(*l)),
+            Route::MapAction(l, q) => {
+                format!("{base}/#map/{}/{}", location_path(*l), quest_path(*q))
+            }
+            Route::MapNewQuest(l) => format!("{base}/#map/{}/new", location_path(*l)),
             Route::Edit => format!("{base}/#edit"),
-            Route::EditQuest(q) => format!("{base}/#edit/{}", encode(data.quest_locale.get(*q)),),
+            Route::EditQuest(q) => format!("{base}/#edit/{}", quest_path(*q)),
+            Route::Timeline => format!("{base}/#timeline"),
+            Route::Graph => format!("{base}/#graph"),
+            Route::Search(query) if query.is_empty() => format!("{base}/#search"),
+            Route::Search(query) => format!("{base}/#search/{}", encode(query)),
+            Route::Crew => format!("{base}/#crew"),
+            Route::Ledger => format!("{base}/#ledger"),
+            Route::Totems => format!("{base}/#totems"),
+            Route::Inventory => format!("{base}/#inventory"),
+            Route::Score => format!("{base}/#score"),
+            Route::Sessions => format!("{base}/#sessions"),
             Route::Settings => format!("{base}/#settings"),
         }
     }
@@ -61,8 +71,8 @@ impl Route {
         let mut path = path.iter().map(Cow::as_ref);
         match path.next() {
             Some("todo") => {
-                let q = path.next().and_then(|q| data.quest_locale.try_get(q));
-                let l = path.next().and_then(LocationId::try_from_name);
+                let q = path.next().and_then(|q| parse_quest(data, q));
+                let l = path.next().and_then(parse_location);
                 if let Some((q, l)) = q.zip(l) {
                     Route::TodoAction(q, l)
                 } else {
@@ -70,10 +80,10 @@ impl Route {
                 }
             }
             Some("map") => {
-                let l = path.next().and_then(LocationId::try_from_name);
+                let l = path.next().and_then(parse_location);
                 if let Some(l) = l {
                     let snd = path.next();
-                    let q = snd.and_then(|q| data.quest_locale.try_get(q));
+                    let q = snd.and_then(|q| parse_quest(data, q));
                     if let Some(q) = q {
                         Route::MapAction(l, q)
                     } else if snd == Some("new") {
@@ -86,19 +96,58 @@ impl Route {
                 }
             }
             Some("edit") => {
-                let q = path.next().and_then(|q| data.quest_locale.try_get(q));
+                let q = path.next().and_then(|q| parse_quest(data, q));
                 if let Some(q) = q {
                     Route::EditQuest(q)
                 } else {
                     Route::Edit
                 }
             }
+            Some("timeline") => Route::Timeline,
+            Some("graph") => Route::Graph,
+            Some("search") => Route::Search(path.next().unwrap_or_default().to_string()),
+            Some("crew") => Route::Crew,
+            Some("ledger") => Route::Ledger,
+            Some("totems") => Route::Totems,
+            Some("inventory") => Route::Inventory,
+            Some("score") => Route::Score,
+            Some("sessions") => Route::Sessions,
             Some("settings") => Route::Settings,
             _ => Route::Info,
         }
     }
 }
 
+#[inline]
+fn quest_path(q: QuestId) -> String {
+    format!("q{}", q.raw())
+}
+
+#[inline]
+fn location_path(l: LocationId) -> String {
+    format!("l{}", l.raw())
+}
+
+// older links contain the (localized) name, they are redirected to the id,
+// the link may have been shared from another game language
+fn parse_quest(data: &Data, s: &str) -> Option<QuestId> {
+    match s.strip_prefix('q').map(str::parse) {
+        Some(Ok(raw)) => QuestId::from_raw(raw),
+        _ => data.quest_locale.try_get(s).or_else(|| {
+            PackId::all()
+                .flat_map(QuestId::all_of)
+                .find(|q| GameLanguage::iter().any(|language| q.name(language) == s))
+        }),
+    }
+}
+
+fn parse_location(s: &str) -> Option<LocationId> {
+    match s.strip_prefix('l').map(str::parse) {
+        Some(Ok(raw)) => LocationId::from_raw(raw),
+        _ => LocationId::try_from_name(s),
+    }
+}
+
 pub(crate) struct Router {
     browser_history: BrowserHistory,
     _history_listener: HistoryListener,
@@ -123,8 +172,8 @@ impl Router {
 
     #[inline]
     #[allow(clippy::needless_pass_by_value)]
-    pub(crate) fn go(&mut self, data: &Data, route: Route) {
-        let path = route.path(data, &self.base);
+    pub(crate) fn go(&mut self, route: Route) {
+        let path = route.path(&self.base);
         #[cfg(feature = "debug")]
         web_sys::console::log_2(&"goto".into(), &path.as_str().into());
         self.browser_history.push(path);
@@ -133,8 +182,8 @@ impl Router {
 
     #[inline]
     #[allow(clippy::needless_pass_by_value)]
-    pub(crate) fn go_replace(&mut self, data: &Data, route: Route) {
-        let path = route.path(data, &self.base);
+    pub(crate) fn go_replace(&mut self, route: Route) {
+        let path = route.path(&self.base);
         #[cfg(feature = "debug")]
         web_sys::console::log_2(&"goto, replace".into(), &path.as_str().into());
         self.browser_history.replace(path);
@@ -150,7 +199,7 @@ impl Router {
         let loc = self.browser_history.location();
         let route = Route::parse(data, loc.hash());
 
-        let new_path = route.path(data, &self.base);
+        let new_path = route.path(&self.base);
         #[cfg(feature = "debug")]
         web_sys::console::log_1(
             &format!(
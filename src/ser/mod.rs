@@ -1,5 +1 @@
-pub(crate) mod csv;
-pub(crate) mod game_data_1;
-pub(crate) mod game_data_2;
-pub(crate) mod game_data_3;
 pub(crate) mod settings;
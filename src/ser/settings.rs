@@ -1,14 +1,23 @@
 use crate::data::sub_component::SubComponent;
-use crate::game::{GameLanguage, MsgLanguage};
+use crate::game::{GameLanguage, MsgLanguage, PackId};
 use crate::global::app::App;
+use crate::global::campaign::{Campaign, CampaignId};
 use crate::pane::action::PaneAction;
+use crate::pane::crew::PaneCrew;
 use crate::pane::edit::PaneEdit;
 use crate::pane::edit_quest::PaneEditQuest;
-use crate::pane::map::PaneMap;
+use crate::pane::inventory::PaneInventory;
+use crate::pane::ledger::PaneLedger;
+use crate::pane::map::{PaneMap, PaneMapSer};
 use crate::pane::map_location::PaneMapLocation;
 use crate::pane::map_new_quest::PaneMapNewQuest;
+use crate::pane::score::PaneScore;
+use crate::pane::search::PaneSearch;
+use crate::pane::sessions::PaneSessions;
 use crate::pane::settings::PaneSettings;
-use crate::pane::todo::PaneTodo;
+use crate::pane::timeline::PaneTimeline;
+use crate::pane::todo::{PaneTodo, PaneTodoSer};
+use crate::pane::totems::PaneTotems;
 use serde::{Deserialize, Serialize};
 
 #[derive(Default, Serialize, Deserialize)]
@@ -18,23 +27,64 @@ pub(crate) struct SerdeSettings {
     msg_language: MsgLanguage,
     game_language: GameLanguage,
     quests_is_map: bool,
+    spoiler_safe: bool,
+    // campaigns
+    campaign: CampaignId,
+    campaigns: Vec<SerdeCampaign>,
     // panes
+    pane_settings: <PaneSettings as SubComponent>::Ser,
+    // panes, only read from settings stored before campaigns existed
+    #[serde(skip_serializing)]
     pane_todo: <PaneTodo as SubComponent>::Ser,
+    #[serde(skip_serializing)]
     pane_map: <PaneMap as SubComponent>::Ser,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerdeCampaign {
+    id: CampaignId,
+    name: String,
+    // keys of the enabled content packs, campaigns from before packs existed only know the base game
+    #[serde(default)]
+    packs: Vec<String>,
+    #[serde(default)]
+    panes: SerdeCampaignPanes,
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct SerdeCampaignPanes {
+    todo: <PaneTodo as SubComponent>::Ser,
+    map: <PaneMap as SubComponent>::Ser,
     #[serde(skip_serializing)]
-    pane_map_location: <PaneMapLocation as SubComponent>::Ser,
+    map_location: <PaneMapLocation as SubComponent>::Ser,
     #[serde(skip_serializing)]
-    pane_map_new_quest: <PaneMapNewQuest as SubComponent>::Ser,
+    map_new_quest: <PaneMapNewQuest as SubComponent>::Ser,
     #[serde(skip_serializing)]
-    pane_action: <PaneAction as SubComponent>::Ser,
+    action: <PaneAction as SubComponent>::Ser,
     #[serde(skip_serializing)]
-    pane_edit: <PaneEdit as SubComponent>::Ser,
+    edit: <PaneEdit as SubComponent>::Ser,
     #[serde(skip_serializing)]
-    pane_edit_quest: <PaneEditQuest as SubComponent>::Ser,
-    pane_settings: <PaneSettings as SubComponent>::Ser,
+    edit_quest: <PaneEditQuest as SubComponent>::Ser,
+    #[serde(skip_serializing)]
+    timeline: <PaneTimeline as SubComponent>::Ser,
+    #[serde(skip_serializing)]
+    search: <PaneSearch as SubComponent>::Ser,
+    #[serde(skip_serializing)]
+    crew: <PaneCrew as SubComponent>::Ser,
+    #[serde(skip_serializing)]
+    ledger: <PaneLedger as SubComponent>::Ser,
+    #[serde(skip_serializing)]
+    totems: <PaneTotems as SubComponent>::Ser,
+    #[serde(skip_serializing)]
+    inventory: <PaneInventory as SubComponent>::Ser,
+    #[serde(skip_serializing)]
+    score: <PaneScore as SubComponent>::Ser,
+    #[serde(skip_serializing)]
+    sessions: <PaneSessions as SubComponent>::Ser,
 }
 
-#[derive(Default, Serialize, Deserialize)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 pub(crate) struct EmptySer {}
 
 impl App {
@@ -44,32 +94,114 @@ impl App {
             msg_language: self.data.msg,
             game_language: self.data.quest_locale.language(),
             quests_is_map: self.quests_is_map,
+            spoiler_safe: self.data.spoiler_safe,
+            // campaigns
+            campaign: self.data.campaign.active_id(),
+            campaigns: self
+                .data
+                .campaign
+                .iter()
+                .map(|campaign| SerdeCampaign {
+                    id: campaign.id,
+                    name: campaign.name.clone(),
+                    packs: campaign
+                        .packs
+                        .iter()
+                        .map(|pack| pack.pack().key.to_string())
+                        .collect(),
+                    panes: campaign.panes.clone(),
+                })
+                .collect(),
             // panes
-            pane_todo: self.pane_todo.save(),
-            pane_map: self.pane_map.save(),
-            pane_map_location: self.pane_map_location.save(),
-            pane_map_new_quest: self.pane_map_new_quest.save(),
-            pane_action: self.pane_action.save(),
-            pane_edit: self.pane_edit.save(),
-            pane_edit_quest: self.pane_edit_quest.save(),
             pane_settings: self.pane_settings.save(),
+            pane_todo: PaneTodoSer::default(),
+            pane_map: PaneMapSer::default(),
         }
     }
 
-    #[allow(clippy::needless_pass_by_value)]
     pub(crate) fn load_settings(&mut self, settings: SerdeSettings) {
         // global settings
         self.data.msg = settings.msg_language;
         self.data.quest_locale.set_language(settings.game_language);
         self.quests_is_map = settings.quests_is_map;
+        self.data.spoiler_safe = settings.spoiler_safe;
+        // campaigns
+        if settings.campaigns.is_empty() {
+            // settings from before campaigns existed
+            self.data.campaign.active_mut().panes = SerdeCampaignPanes {
+                todo: settings.pane_todo,
+                map: settings.pane_map,
+                ..SerdeCampaignPanes::default()
+            };
+        } else {
+            self.data.campaign.load(
+                settings.campaign,
+                settings
+                    .campaigns
+                    .into_iter()
+                    .map(|campaign| {
+                        // unknown packs (e.g. from a newer version) are ignored
+                        let mut packs = campaign
+                            .packs
+                            .iter()
+                            .filter_map(|key| PackId::try_from_key(key))
+                            .collect::<Vec<_>>();
+                        packs.sort();
+                        packs.dedup();
+                        if packs.is_empty() {
+                            packs.push(PackId::base());
+                        }
+                        Campaign {
+                            id: campaign.id,
+                            name: campaign.name,
+                            packs,
+                            panes: campaign.panes,
+                        }
+                    })
+                    .collect(),
+            );
+        }
+        self.data.packs_changed();
         // panes
-        self.pane_todo.load(settings.pane_todo);
-        self.pane_map.load(settings.pane_map);
-        self.pane_map_location.load(settings.pane_map_location);
-        self.pane_map_new_quest.load(settings.pane_map_new_quest);
-        self.pane_action.load(settings.pane_action);
-        self.pane_edit.load(settings.pane_edit);
-        self.pane_edit_quest.load(settings.pane_edit_quest);
+        self.load_campaign_panes(self.data.campaign.active().panes.clone());
         self.pane_settings.load(settings.pane_settings);
     }
+
+    pub(crate) fn save_campaign_panes(&self) -> SerdeCampaignPanes {
+        SerdeCampaignPanes {
+            todo: self.pane_todo.save(),
+            map: self.pane_map.save(),
+            map_location: self.pane_map_location.save(),
+            map_new_quest: self.pane_map_new_quest.save(),
+            action: self.pane_action.save(),
+            edit: self.pane_edit.save(),
+            edit_quest: self.pane_edit_quest.save(),
+            timeline: self.pane_timeline.save(),
+            search: self.pane_search.save(),
+            crew: self.pane_crew.save(),
+            ledger: self.pane_ledger.save(),
+            totems: self.pane_totems.save(),
+            inventory: self.pane_inventory.save(),
+            score: self.pane_score.save(),
+            sessions: self.pane_sessions.save(),
+        }
+    }
+
+    pub(crate) fn load_campaign_panes(&mut self, panes: SerdeCampaignPanes) {
+        self.pane_todo.load(panes.todo);
+        self.pane_map.load(panes.map);
+        self.pane_map_location.load(panes.map_location);
+        self.pane_map_new_quest.load(panes.map_new_quest);
+        self.pane_action.load(panes.action);
+        self.pane_edit.load(panes.edit);
+        self.pane_edit_quest.load(panes.edit_quest);
+        self.pane_timeline.load(panes.timeline);
+        self.pane_search.load(panes.search);
+        self.pane_crew.load(panes.crew);
+        self.pane_ledger.load(panes.ledger);
+        self.pane_totems.load(panes.totems);
+        self.pane_inventory.load(panes.inventory);
+        self.pane_score.load(panes.score);
+        self.pane_sessions.load(panes.sessions);
+    }
 }
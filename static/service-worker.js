@@ -0,0 +1,42 @@
+// This is a template, `copy-and-link` fills in the version and the files to precache.
+
+const CACHE_PREFIX = 'sleeping-journal-';
+const CACHE = CACHE_PREFIX + '<!version>';
+const PRECACHE = [/* <!precache> */];
+
+self.addEventListener('install', (event) => {
+    event.waitUntil(caches.open(CACHE).then((cache) => cache.addAll(PRECACHE)));
+});
+
+// remove the caches of all other versions
+self.addEventListener('activate', (event) => {
+    event.waitUntil(
+        caches.keys()
+            .then((keys) => Promise.all(
+                keys
+                    .filter((key) => key.startsWith(CACHE_PREFIX) && key !== CACHE)
+                    .map((key) => caches.delete(key))
+            ))
+            .then(() => self.clients.claim())
+    );
+});
+
+// a new version waits until the user wants to reload
+self.addEventListener('message', (event) => {
+    if (event.data === 'skip-waiting') {
+        self.skipWaiting();
+    }
+});
+
+// the app only uses the hash of the url, thus every page is the index
+self.addEventListener('fetch', (event) => {
+    const request = event.request;
+    if (request.method !== 'GET') {
+        return;
+    }
+    event.respondWith(
+        caches.open(CACHE)
+            .then((cache) => cache.match(request.mode === 'navigate' ? './' : request, {ignoreSearch: true}))
+            .then((response) => response || fetch(request))
+    );
+});